@@ -6,6 +6,7 @@ extern crate alloc;
 use alloc::{
     string::{String, ToString},
     vec,
+    vec::Vec,
 };
 
 use casper_contract::{
@@ -18,7 +19,11 @@ use casper_contract::{
 };
 
 use casper_types::{
-    bytesrepr::ToBytes, runtime_args, CLTyped, ContractHash, EntryPoint, EntryPointAccess,
+    bytesrepr::{Bytes, ToBytes},
+    contracts::NamedKeys,
+    runtime_args,
+    system::CallStackElement,
+    CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPoint, EntryPointAccess,
     EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
 };
 
@@ -28,10 +33,41 @@ const TRANSFER_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_as_stored_c
 const APPROVE_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "approve_as_stored_contract";
 const TRANSFER_FROM_AS_STORED_CONTRACT_ENTRY_POINT_NAME: &str = "transfer_from_as_stored_contract";
 const CHECK_ALLOWANCE_OF_ENTRY_POINT_NAME: &str = "check_allowance_of";
+const CHECK_TRANSFER_RESTRICTION_ENTRY_POINT_NAME: &str = "check_transfer_restriction";
+const CHECK_RESTRICTION_MESSAGE_ENTRY_POINT_NAME: &str = "check_restriction_message";
+const CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "check_balance_of_at";
+const CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "check_total_supply_at";
+const CHECK_PAST_BALANCE_ENTRY_POINT_NAME: &str = "check_past_balance";
+const CHECK_PAST_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "check_past_total_supply";
+const CHECK_HOLDERS_ENTRY_POINT_NAME: &str = "check_holders";
+const CHECK_SPENDERS_OF_ENTRY_POINT_NAME: &str = "check_spenders_of";
+const ON_FLASH_LOAN_ENTRY_POINT_NAME: &str = "on_flash_loan";
 const TOKEN_CONTRACT_RUNTIME_ARG_NAME: &str = "token_contract";
 const ADDRESS_RUNTIME_ARG_NAME: &str = "address";
 const OWNER_RUNTIME_ARG_NAME: &str = "owner";
 const SPENDER_RUNTIME_ARG_NAME: &str = "spender";
+const FROM_RUNTIME_ARG_NAME: &str = "from";
+const TO_RUNTIME_ARG_NAME: &str = "to";
+const CODE_RUNTIME_ARG_NAME: &str = "code";
+const SNAPSHOT_ID_RUNTIME_ARG_NAME: &str = "snapshot_id";
+const TIMESTAMP_RUNTIME_ARG_NAME: &str = "timestamp";
+const INITIATOR_RUNTIME_ARG_NAME: &str = "initiator";
+const TOKEN_RUNTIME_ARG_NAME: &str = "token";
+const FEE_RUNTIME_ARG_NAME: &str = "fee";
+const DATA_RUNTIME_ARG_NAME: &str = "data";
+const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+/// `data` passed to `on_flash_loan` asking the borrower to not repay the loan.
+const FLASH_LOAN_DEFAULT: u8 = 1;
+/// `data` passed to `on_flash_loan` asking the borrower to not return the success value.
+const FLASH_LOAN_FAIL_CALLBACK: u8 = 2;
+/// Value `on_flash_loan` returns on success, `keccak256("ERC3156FlashBorrower.onFlashLoan")`.
+const FLASH_LOAN_CALLBACK_SUCCESS: [u8; 32] = [
+    0x43, 0x91, 0x48, 0xf0, 0xbb, 0xc6, 0x82, 0xca, 0x07, 0x9e, 0x46, 0xd6, 0xe2, 0xc2, 0xf0, 0xc1,
+    0xe3, 0xb8, 0x20, 0xf1, 0xa2, 0x91, 0xb0, 0x69, 0xd8, 0x88, 0x2a, 0xbf, 0x8c, 0xf1, 0x8d, 0xd9,
+];
+/// Account the sample borrower accepts flash loans from, the one that installed it.
+const TRUSTED_INITIATOR_KEY: &str = "trusted_initiator";
 const RESULT_KEY: &str = "result";
 const CSPR_USD_TEST_CALL_KEY: &str = "csprusd_test_contract_package_hash";
 
@@ -43,6 +79,14 @@ const TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "total_supply";
 const BALANCE_OF_ENTRY_POINT_NAME: &str = "balance_of";
 const TRANSFER_ENTRY_POINT_NAME: &str = "transfer";
 const TRANSFER_FROM_ENTRY_POINT_NAME: &str = "transfer_from";
+const DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME: &str = "detect_transfer_restriction";
+const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+const GET_PAST_BALANCE_ENTRY_POINT_NAME: &str = "get_past_balance";
+const GET_PAST_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "get_past_total_supply";
+const HOLDERS_ENTRY_POINT_NAME: &str = "holders";
+const SPENDERS_OF_ENTRY_POINT_NAME: &str = "spenders_of";
+const MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME: &str = "message_for_transfer_restriction";
 const ADDRESS: &str = "address";
 
 fn store_result<T: CLTyped + ToBytes>(result: T) {
@@ -110,6 +154,250 @@ extern "C" fn check_allowance_of() {
     store_result(result);
 }
 
+#[no_mangle]
+extern "C" fn check_transfer_restriction() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let from: Key = runtime::get_named_arg(FROM_RUNTIME_ARG_NAME);
+    let to: Key = runtime::get_named_arg(TO_RUNTIME_ARG_NAME);
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    let restriction_args = runtime_args! {
+        FROM_RUNTIME_ARG_NAME => from,
+        TO_RUNTIME_ARG_NAME => to,
+        AMOUNT_RUNTIME_ARG_NAME => amount,
+    };
+    let result: u8 = runtime::call_contract(
+        token_contract,
+        DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME,
+        restriction_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_restriction_message() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let code: u8 = runtime::get_named_arg(CODE_RUNTIME_ARG_NAME);
+
+    let message_args = runtime_args! {
+        CODE_RUNTIME_ARG_NAME => code,
+    };
+    let result: String = runtime::call_contract(
+        token_contract,
+        MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME,
+        message_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_balance_of_at() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let balance_args = runtime_args! {
+        ADDRESS => address,
+        SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 =
+        runtime::call_contract(token_contract, BALANCE_OF_AT_ENTRY_POINT_NAME, balance_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_total_supply_at() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID_RUNTIME_ARG_NAME);
+
+    let total_supply_args = runtime_args! {
+        SNAPSHOT_ID_RUNTIME_ARG_NAME => snapshot_id,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+        total_supply_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_past_balance() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let timestamp: u64 = runtime::get_named_arg(TIMESTAMP_RUNTIME_ARG_NAME);
+
+    let balance_args = runtime_args! {
+        ADDRESS => address,
+        TIMESTAMP_RUNTIME_ARG_NAME => timestamp,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        GET_PAST_BALANCE_ENTRY_POINT_NAME,
+        balance_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_past_total_supply() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let timestamp: u64 = runtime::get_named_arg(TIMESTAMP_RUNTIME_ARG_NAME);
+
+    let total_supply_args = runtime_args! {
+        TIMESTAMP_RUNTIME_ARG_NAME => timestamp,
+    };
+    let result: U256 = runtime::call_contract(
+        token_contract,
+        GET_PAST_TOTAL_SUPPLY_ENTRY_POINT_NAME,
+        total_supply_args,
+    );
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_holders() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let offset: u32 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u32 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let holders_args = runtime_args! {
+        OFFSET_RUNTIME_ARG_NAME => offset,
+        LIMIT_RUNTIME_ARG_NAME => limit,
+    };
+    let result: Vec<Key> =
+        runtime::call_contract(token_contract, HOLDERS_ENTRY_POINT_NAME, holders_args);
+
+    store_result(result);
+}
+
+#[no_mangle]
+extern "C" fn check_spenders_of() {
+    let token_contract: ContractHash = ContractHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_CONTRACT_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let owner: Key = runtime::get_named_arg(OWNER_RUNTIME_ARG_NAME);
+    let offset: u32 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u32 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let spenders_of_args = runtime_args! {
+        OWNER_RUNTIME_ARG_NAME => owner,
+        OFFSET_RUNTIME_ARG_NAME => offset,
+        LIMIT_RUNTIME_ARG_NAME => limit,
+    };
+    let result: Vec<(Key, U256)> = runtime::call_contract(
+        token_contract,
+        SPENDERS_OF_ENTRY_POINT_NAME,
+        spenders_of_args,
+    );
+
+    store_result(result);
+}
+
+/// Sample flash loan borrower.
+///
+/// Records its balance during the loan and, if the loan was initiated by its installer, approves
+/// the token to pull the loan plus fee. It can be asked to default, in which case it sends the loan
+/// on to the initiator, or to fail its callback.
+#[no_mangle]
+extern "C" fn on_flash_loan() {
+    let initiator: Key = runtime::get_named_arg(INITIATOR_RUNTIME_ARG_NAME);
+    let token_package: ContractPackageHash = ContractPackageHash::new(
+        runtime::get_named_arg::<Key>(TOKEN_RUNTIME_ARG_NAME)
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    let amount: U256 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let fee: U256 = runtime::get_named_arg(FEE_RUNTIME_ARG_NAME);
+    let data: Bytes = runtime::get_named_arg(DATA_RUNTIME_ARG_NAME);
+
+    let self_key: Key = match runtime::get_call_stack().last() {
+        Some(CallStackElement::StoredContract {
+            contract_package_hash,
+            ..
+        }) => Some(Key::from(*contract_package_hash)),
+        _ => None,
+    }
+    .unwrap_or_revert();
+    let balance: U256 = runtime::call_versioned_contract(
+        token_package,
+        None,
+        BALANCE_OF_ENTRY_POINT_NAME,
+        runtime_args! {
+            ADDRESS => self_key,
+        },
+    );
+    store_result(balance);
+
+    if data.first() == Some(&FLASH_LOAN_DEFAULT) {
+        runtime::call_versioned_contract::<()>(
+            token_package,
+            None,
+            TRANSFER_ENTRY_POINT_NAME,
+            runtime_args! {
+                RECIPIENT_RUNTIME_ARG_NAME => initiator,
+                AMOUNT_RUNTIME_ARG_NAME => amount,
+            },
+        );
+    }
+
+    let trusted_initiator: Key = runtime::get_key(TRUSTED_INITIATOR_KEY).unwrap_or_revert();
+    if initiator == trusted_initiator {
+        runtime::call_versioned_contract::<()>(
+            token_package,
+            None,
+            APPROVE_ENTRY_POINT_NAME,
+            runtime_args! {
+                SPENDER_RUNTIME_ARG_NAME => Key::from(token_package),
+                AMOUNT_RUNTIME_ARG_NAME => amount + fee,
+            },
+        );
+    }
+
+    let result = if data.first() == Some(&FLASH_LOAN_FAIL_CALLBACK) {
+        [0u8; 32]
+    } else {
+        FLASH_LOAN_CALLBACK_SUCCESS
+    };
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
 #[no_mangle]
 extern "C" fn transfer_as_stored_contract() {
     let token_contract: ContractHash = ContractHash::new(
@@ -242,16 +530,140 @@ pub extern "C" fn call() {
         EntryPointType::Contract,
     );
 
+    let check_transfer_restriction_entrypoint = EntryPoint::new(
+        String::from(CHECK_TRANSFER_RESTRICTION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(FROM_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(TO_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_restriction_message_entrypoint = EntryPoint::new(
+        String::from(CHECK_RESTRICTION_MESSAGE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(CODE_RUNTIME_ARG_NAME, u8::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_balance_of_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_total_supply_at_entrypoint = EntryPoint::new(
+        String::from(CHECK_TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(SNAPSHOT_ID_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_past_balance_entrypoint = EntryPoint::new(
+        String::from(CHECK_PAST_BALANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(ADDRESS_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(TIMESTAMP_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_past_total_supply_entrypoint = EntryPoint::new(
+        String::from(CHECK_PAST_TOTAL_SUPPLY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(TIMESTAMP_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_holders_entrypoint = EntryPoint::new(
+        String::from(CHECK_HOLDERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u32::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u32::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let check_spenders_of_entrypoint = EntryPoint::new(
+        String::from(CHECK_SPENDERS_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TOKEN_CONTRACT_RUNTIME_ARG_NAME, ContractHash::cl_type()),
+            Parameter::new(OWNER_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, u32::cl_type()),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, u32::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
+    let on_flash_loan_entrypoint = EntryPoint::new(
+        String::from(ON_FLASH_LOAN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(INITIATOR_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(TOKEN_RUNTIME_ARG_NAME, Key::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(FEE_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DATA_RUNTIME_ARG_NAME, Bytes::cl_type()),
+        ],
+        <[u8; 32]>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+
     entry_points.add_entry_point(check_total_supply_entrypoint);
     entry_points.add_entry_point(check_balance_of_entrypoint);
     entry_points.add_entry_point(check_allowance_of_entrypoint);
     entry_points.add_entry_point(transfer_as_stored_contract_entrypoint);
     entry_points.add_entry_point(approve_as_stored_contract_entrypoint);
     entry_points.add_entry_point(transfer_from_as_stored_contract_entrypoint);
+    entry_points.add_entry_point(check_transfer_restriction_entrypoint);
+    entry_points.add_entry_point(check_restriction_message_entrypoint);
+    entry_points.add_entry_point(check_balance_of_at_entrypoint);
+    entry_points.add_entry_point(check_total_supply_at_entrypoint);
+    entry_points.add_entry_point(check_past_balance_entrypoint);
+    entry_points.add_entry_point(check_past_total_supply_entrypoint);
+    entry_points.add_entry_point(check_holders_entrypoint);
+    entry_points.add_entry_point(check_spenders_of_entrypoint);
+    entry_points.add_entry_point(on_flash_loan_entrypoint);
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        TRUSTED_INITIATOR_KEY.to_string(),
+        Key::Account(runtime::get_caller()),
+    );
 
     let (_contract_hash, _version) = storage::new_contract(
         entry_points,
-        None,
+        Some(named_keys),
         Some(CSPR_USD_TEST_CALL_KEY.to_string()),
         None,
     );
@@ -1,4 +1,4 @@
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 
 use casper_contract::{
     contract_api::{runtime, storage},
@@ -6,7 +6,24 @@ use casper_contract::{
 };
 use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
 
-use crate::{constants::ALLOWANCES, utils};
+use crate::{
+    balances,
+    constants::{
+        ALLOWANCES, ALLOWANCE_EXPIRIES, ALLOWANCE_SPENDERS, ALLOWANCE_SPENDER_COUNTS,
+        ALLOWANCE_SPENDER_INDICES,
+    },
+    utils,
+};
+
+/// Maximum number of spenders returned by a single `spenders_of` call or revoked by a single
+/// `revoke_all_allowances` call.
+pub(crate) const MAX_SPENDERS_PAGE: u32 = 100;
+
+/// Allowance that `transfer_from` treats as unlimited and never decrements.
+///
+/// `increase_allowance` saturates at this value, so increasing a large allowance past it also
+/// makes the allowance unlimited.
+pub(crate) const UNLIMITED_ALLOWANCE: U256 = U256::MAX;
 
 #[inline]
 pub(crate) fn get_allowances_uref() -> URef {
@@ -24,15 +41,193 @@ pub(crate) fn make_dictionary_item_key(owner: Key, spender: Key) -> String {
 }
 
 /// Writes an allowance for owner and spender for a specific amount.
-pub(crate) fn write_allowance_to(allowance_uref: URef, owner: Key, spender: Key, amount: U256) {
+///
+/// `previous` is the stored amount being replaced, as returned by `read_allowance_amount_from`.
+/// The spender index is only touched when the allowance goes from zero to non-zero or back.
+pub(crate) fn write_allowance_to(
+    allowance_uref: URef,
+    owner: Key,
+    spender: Key,
+    previous: U256,
+    amount: U256,
+) {
     let dictionary_item_key = make_dictionary_item_key(owner, spender);
-    storage::dictionary_put(allowance_uref, &dictionary_item_key, amount)
+    storage::dictionary_put(allowance_uref, &dictionary_item_key, amount);
+    if previous.is_zero() != amount.is_zero() {
+        index_spender(owner, spender, &dictionary_item_key, !amount.is_zero());
+    }
 }
 
-/// Reads an allowance for a owner and spender
-pub(crate) fn read_allowance_from(allowances_uref: URef, owner: Key, spender: Key) -> U256 {
+/// Reads the stored allowance amount for a owner and spender, ignoring its expiry.
+pub(crate) fn read_allowance_amount_from(allowances_uref: URef, owner: Key, spender: Key) -> U256 {
     let dictionary_item_key = make_dictionary_item_key(owner, spender);
     storage::dictionary_get(allowances_uref, &dictionary_item_key)
         .unwrap_or_revert()
         .unwrap_or_default()
 }
+
+fn has_expired(expires_at: u64) -> bool {
+    expires_at != 0 && utils::now() >= expires_at
+}
+
+/// Reads the effective allowance for a owner and spender, which is zero once it expired.
+pub(crate) fn read_allowance_from(allowances_uref: URef, owner: Key, spender: Key) -> U256 {
+    read_stored_and_effective_allowance(allowances_uref, owner, spender).1
+}
+
+/// Reads the stored allowance amount for a owner and spender together with its effective value.
+pub(crate) fn read_stored_and_effective_allowance(
+    allowances_uref: URef,
+    owner: Key,
+    spender: Key,
+) -> (U256, U256) {
+    let stored = read_allowance_amount_from(allowances_uref, owner, spender);
+    if stored.is_zero() || has_expired(read_allowance_expiry(owner, spender)) {
+        return (stored, U256::zero());
+    }
+    (stored, stored)
+}
+
+/// Reads the block time at which an allowance expires, or zero if it never does.
+pub(crate) fn read_allowance_expiry(owner: Key, spender: Key) -> u64 {
+    let dictionary_item_key = make_dictionary_item_key(owner, spender);
+    storage::dictionary_get(utils::get_uref(ALLOWANCE_EXPIRIES), &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Writes the block time at which an allowance expires, zero meaning it never does.
+///
+/// Increasing or decreasing an allowance keeps its expiry, only a new approval replaces it.
+/// An expiry that already passed is cleared by `clear_passed_expiry` instead.
+pub(crate) fn write_allowance_expiry(owner: Key, spender: Key, expires_at: u64) {
+    let dictionary_item_key = make_dictionary_item_key(owner, spender);
+    storage::dictionary_put(
+        utils::get_uref(ALLOWANCE_EXPIRIES),
+        &dictionary_item_key,
+        expires_at,
+    )
+}
+
+/// Clears the expiry of an allowance that already expired.
+///
+/// Increasing or decreasing such an allowance starts over from zero, and the result must not
+/// inherit an expiry that makes it unusable.
+pub(crate) fn clear_passed_expiry(owner: Key, spender: Key) {
+    if has_expired(read_allowance_expiry(owner, spender)) {
+        write_allowance_expiry(owner, spender, 0);
+    }
+}
+
+fn spender_item_key(owner_id: &str, index: u32) -> String {
+    format!("{}_{}", owner_id, index)
+}
+
+fn read_spender_count(owner_id: &str) -> u32 {
+    storage::dictionary_get(utils::get_uref(ALLOWANCE_SPENDER_COUNTS), owner_id)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn read_spender_at(owner_id: &str, index: u32) -> Key {
+    storage::dictionary_get(
+        utils::get_uref(ALLOWANCE_SPENDERS),
+        &spender_item_key(owner_id, index),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_revert()
+}
+
+/// Adds `spender` to or removes it from the per-owner spender index.
+///
+/// Spenders with a non-zero stored allowance are kept under 1-based indices per owner, and a
+/// removed spender is replaced by the owner's last spender.
+fn index_spender(owner: Key, spender: Key, pair_key: &str, add: bool) {
+    let indices_uref = utils::get_uref(ALLOWANCE_SPENDER_INDICES);
+    let spenders_uref = utils::get_uref(ALLOWANCE_SPENDERS);
+    let owner_id = balances::make_dictionary_item_key(owner);
+    let spender_count = read_spender_count(&owner_id);
+
+    let new_spender_count = if add {
+        let new_index = spender_count + 1;
+        storage::dictionary_put(indices_uref, pair_key, new_index);
+        storage::dictionary_put(
+            spenders_uref,
+            &spender_item_key(&owner_id, new_index),
+            spender,
+        );
+        new_index
+    } else {
+        let index: u32 = storage::dictionary_get(indices_uref, pair_key)
+            .unwrap_or_revert()
+            .unwrap_or_revert();
+        if index < spender_count {
+            let last_spender = read_spender_at(&owner_id, spender_count);
+            storage::dictionary_put(
+                spenders_uref,
+                &spender_item_key(&owner_id, index),
+                last_spender,
+            );
+            storage::dictionary_put(
+                indices_uref,
+                &make_dictionary_item_key(owner, last_spender),
+                index,
+            );
+        }
+        storage::dictionary_put(indices_uref, pair_key, 0u32);
+        spender_count - 1
+    };
+    storage::dictionary_put(
+        utils::get_uref(ALLOWANCE_SPENDER_COUNTS),
+        &owner_id,
+        new_spender_count,
+    );
+}
+
+/// Returns the spenders of `owner` with their effective allowance among up to `limit` entries of
+/// the index, starting at the 0-based `offset`.
+///
+/// Expired allowances stay in the index until they are changed, but are left out of the result,
+/// so a page may hold fewer than `limit` spenders.
+pub(crate) fn spenders_of_util(owner: Key, offset: u32, limit: u32) -> Vec<(Key, U256)> {
+    let allowances_uref = get_allowances_uref();
+    let owner_id = balances::make_dictionary_item_key(owner);
+    let limit = limit.min(MAX_SPENDERS_PAGE);
+    let end = offset
+        .saturating_add(limit)
+        .min(read_spender_count(&owner_id));
+
+    (offset..end)
+        .map(|index| {
+            let spender = read_spender_at(&owner_id, index + 1);
+            (
+                spender,
+                read_allowance_from(allowances_uref, owner, spender),
+            )
+        })
+        .filter(|(_, allowance)| !allowance.is_zero())
+        .collect()
+}
+
+/// Zeroes up to `limit` allowances `owner` granted and returns the affected spenders along with
+/// the number of allowances left to revoke.
+pub(crate) fn revoke_all_allowances_util(owner: Key, limit: u32) -> (Vec<Key>, u32) {
+    let allowances_uref = get_allowances_uref();
+    let owner_id = balances::make_dictionary_item_key(owner);
+    let mut spender_count = read_spender_count(&owner_id);
+    let remaining = spender_count.saturating_sub(limit.min(MAX_SPENDERS_PAGE));
+
+    let mut spenders = Vec::new();
+    // every indexed spender has a non-zero stored allowance, and zeroing the last one removes it
+    // from the index, so the count shrinks every round
+    while spender_count > remaining {
+        let spender = read_spender_at(&owner_id, spender_count);
+        let dictionary_item_key = make_dictionary_item_key(owner, spender);
+        storage::dictionary_put(allowances_uref, &dictionary_item_key, U256::zero());
+        index_spender(owner, spender, &dictionary_item_key, false);
+        write_allowance_expiry(owner, spender, 0);
+        spenders.push(spender);
+        spender_count -= 1;
+    }
+    (spenders, remaining)
+}
@@ -1,7 +1,10 @@
 extern crate alloc;
 
 use crate::{
-    constants::{BLACKLISTER, IS_PAUSED, MASTER_MINTER, OWNER, PAUSER},
+    constants::{
+        ATTESTOR, BLACKLISTER, BRIDGE_OPERATOR, CONTROLLER, IS_PAUSED, MASTER_MINTER, OWNER,
+        PAUSER, SNAPSHOTTER, VESTING_ADMIN,
+    },
     error, minters, utils,
 };
 
@@ -64,3 +67,48 @@ pub(crate) fn only_blacklister() {
         revert(CsprUSDError::NotBlacklister);
     }
 }
+
+pub(crate) fn only_vesting_admin() {
+    let caller: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let current_vesting_admin: Key = read_from::<Key>(VESTING_ADMIN);
+
+    if caller != current_vesting_admin {
+        revert(CsprUSDError::NotVestingAdmin);
+    }
+}
+
+pub(crate) fn only_bridge_operator() {
+    let caller: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let current_bridge_operator: Key = read_from::<Key>(BRIDGE_OPERATOR);
+
+    if caller != current_bridge_operator {
+        revert(CsprUSDError::NotBridgeOperator);
+    }
+}
+
+pub(crate) fn only_attestor() {
+    let caller: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let current_attestor: Key = read_from::<Key>(ATTESTOR);
+
+    if caller != current_attestor {
+        revert(CsprUSDError::NotAttestor);
+    }
+}
+
+pub(crate) fn only_controller() {
+    let caller: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let current_controller: Key = read_from::<Key>(CONTROLLER);
+
+    if caller != current_controller {
+        revert(CsprUSDError::NotController);
+    }
+}
+
+pub(crate) fn only_snapshotter() {
+    let caller: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let current_snapshotter: Key = read_from::<Key>(SNAPSHOTTER);
+
+    if caller != current_snapshotter {
+        revert(CsprUSDError::NotSnapshotter);
+    }
+}
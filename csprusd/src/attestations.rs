@@ -0,0 +1,56 @@
+//! Append-only registry of reserve attestations published by the attestor.
+//!
+//! Attestations are stored under consecutive indices starting at 1, so the latest one is always
+//! at `attestation_count`. The `period` and `uri` are bounded in length so that the registry can't
+//! be bloated with arbitrarily large strings.
+use alloc::string::{String, ToString};
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{ATTESTATIONS, ATTESTATION_COUNT},
+    error::CsprUSDError,
+    utils::{get_uref, read_from, write_to},
+};
+
+/// Maximum length of an attestation's `period` in bytes.
+pub(crate) const MAX_ATTESTATION_PERIOD_LENGTH: usize = 64;
+
+/// Maximum length of an attestation's `uri` in bytes.
+pub(crate) const MAX_ATTESTATION_URI_LENGTH: usize = 2_048;
+
+/// Stored form of an attestation:
+/// `((period, reserves_amount, report_hash), (uri, attestor, published_at))`.
+pub(crate) type AttestationRecord = ((String, U256, [u8; 32]), (String, Key, u64));
+
+pub(crate) fn attestation_count_util() -> u64 {
+    read_from(ATTESTATION_COUNT)
+}
+
+pub(crate) fn read_attestation_record(index: u64) -> AttestationRecord {
+    storage::dictionary_get(get_uref(ATTESTATIONS), &index.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(CsprUSDError::AttestationNotFound)
+}
+
+/// Reverts if the attestation's `period` or `uri` is longer (in bytes) than its maximum.
+fn check_attestation_lengths(period: &str, uri: &str) {
+    if period.len() > MAX_ATTESTATION_PERIOD_LENGTH || uri.len() > MAX_ATTESTATION_URI_LENGTH {
+        revert(CsprUSDError::AttestationFieldTooLong);
+    }
+}
+
+/// Stores `record` under the next index and returns the index.
+pub(crate) fn publish_attestation_util(record: AttestationRecord) -> u64 {
+    let ((period, _, _), (uri, _, _)) = &record;
+    check_attestation_lengths(period, uri);
+
+    let index = attestation_count_util() + 1;
+    storage::dictionary_put(get_uref(ATTESTATIONS), &index.to_string(), record);
+    write_to(ATTESTATION_COUNT, index);
+    index
+}
@@ -3,11 +3,17 @@ use alloc::string::String;
 use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
 use casper_types::{bytesrepr::ToBytes, Key, URef, U256};
 
-use crate::{constants::BALANCES, error::CsprUSDError, utils};
+use crate::{
+    checkpoints,
+    constants::BALANCES,
+    error::CsprUSDError,
+    holders, snapshots,
+    utils::{self, get_total_supply_uref, read_total_supply_from, write_total_supply_to},
+};
 
 /// Creates a dictionary item key for (owner)
 #[inline]
-fn make_dictionary_item_key(owner: Key) -> String {
+pub(crate) fn make_dictionary_item_key(owner: Key) -> String {
     // let preimage = owner.to_bytes().unwrap_or_revert();
     // let key_bytes = runtime::blake2b(preimage);
     // hex::encode(key_bytes)
@@ -28,8 +34,13 @@ pub(crate) fn get_balances_uref() -> URef {
 
 /// Writes token balance of a specified account into a dictionary.
 pub(crate) fn write_balance_to(balances_uref: URef, address: Key, amount: U256) {
+    snapshots::checkpoint_balance(address);
+
     let dictionary_item_key = make_dictionary_item_key(address);
     storage::dictionary_put(balances_uref, &dictionary_item_key, amount);
+
+    checkpoints::write_balance_checkpoint(address, amount);
+    holders::track_holder(address, amount);
 }
 
 /// Reads token balance of a specified account.
@@ -76,3 +87,45 @@ pub(crate) fn transfer_balance(
 
     Ok(())
 }
+
+/// Creates `amount` new tokens in the `recipient`'s balance.
+///
+/// This function should not be used directly by contract's entrypoint as it does not check who is
+/// allowed to mint.
+pub(crate) fn mint_balance(recipient: Key, amount: U256) -> Result<(), CsprUSDError> {
+    let balances_uref = get_balances_uref();
+    let new_recipient_balance = read_balance_from(balances_uref, recipient)
+        .checked_add(amount)
+        .ok_or(CsprUSDError::Overflow)?;
+
+    let total_supply_uref = get_total_supply_uref();
+    let new_total_supply = read_total_supply_from(total_supply_uref)
+        .checked_add(amount)
+        .ok_or(CsprUSDError::Overflow)?;
+
+    write_balance_to(balances_uref, recipient, new_recipient_balance);
+    write_total_supply_to(total_supply_uref, new_total_supply);
+
+    Ok(())
+}
+
+/// Destroys `amount` tokens from the `owner`'s balance.
+///
+/// This function should not be used directly by contract's entrypoint as it does not check who is
+/// allowed to burn.
+pub(crate) fn burn_balance(owner: Key, amount: U256) -> Result<(), CsprUSDError> {
+    let balances_uref = get_balances_uref();
+    let new_owner_balance = read_balance_from(balances_uref, owner)
+        .checked_sub(amount)
+        .ok_or(CsprUSDError::BurnExceedsBalance)?;
+
+    let total_supply_uref = get_total_supply_uref();
+    let new_total_supply = read_total_supply_from(total_supply_uref)
+        .checked_sub(amount)
+        .ok_or(CsprUSDError::Overflow)?;
+
+    write_balance_to(balances_uref, owner, new_owner_balance);
+    write_total_supply_to(total_supply_uref, new_total_supply);
+
+    Ok(())
+}
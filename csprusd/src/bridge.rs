@@ -0,0 +1,105 @@
+//! Burn-and-mint bridge to other chains.
+//!
+//! `bridge_burn` destroys tokens here and tags the burn with a monotonically increasing nonce that
+//! the off-chain relayer uses to mint on the destination chain. In the other direction the bridge
+//! operator calls `bridge_mint` with the source chain's nonce; every (chain, nonce) pair can be
+//! minted only once.
+//!
+//! Each chain has a mint cap limiting how many tokens minted from that chain may be outstanding:
+//! minting from a chain adds to its outstanding amount and burning towards it subtracts from it. A
+//! cap of zero disables minting from that chain. Bridge mints are also subject to the
+//! proof-of-reserve check.
+use alloc::{format, string::String, string::ToString};
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    balances::{burn_balance, mint_balance},
+    constants::{BRIDGE_MINTED, BRIDGE_MINT_CAPS, BRIDGE_NONCE, CONSUMED_BRIDGE_NONCES},
+    error::CsprUSDError,
+    reserves::check_reserves,
+    utils::{get_uref, read_from, write_to},
+};
+
+fn consumed_nonce_item_key(chain_id: u64, nonce: u64) -> String {
+    format!("{}_{}", chain_id, nonce)
+}
+
+pub(crate) fn bridge_nonce_util() -> u64 {
+    read_from(BRIDGE_NONCE)
+}
+
+pub(crate) fn bridge_mint_cap_util(chain_id: u64) -> U256 {
+    storage::dictionary_get(get_uref(BRIDGE_MINT_CAPS), &chain_id.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+pub(crate) fn set_bridge_mint_cap_util(chain_id: u64, cap: U256) {
+    storage::dictionary_put(get_uref(BRIDGE_MINT_CAPS), &chain_id.to_string(), cap);
+}
+
+/// Returns the amount minted from `chain_id` that hasn't been burned back towards it.
+pub(crate) fn bridge_minted_util(chain_id: u64) -> U256 {
+    storage::dictionary_get(get_uref(BRIDGE_MINTED), &chain_id.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn write_bridge_minted(chain_id: u64, minted: U256) {
+    storage::dictionary_put(get_uref(BRIDGE_MINTED), &chain_id.to_string(), minted);
+}
+
+pub(crate) fn is_bridge_nonce_consumed_util(chain_id: u64, nonce: u64) -> bool {
+    storage::dictionary_get(
+        get_uref(CONSUMED_BRIDGE_NONCES),
+        &consumed_nonce_item_key(chain_id, nonce),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+/// Burns `amount` from `sender` for delivery on `destination_chain_id` and returns the burn nonce.
+pub(crate) fn bridge_burn_util(sender: Key, amount: U256, destination_chain_id: u64) -> u64 {
+    burn_balance(sender, amount).unwrap_or_revert();
+
+    let minted = bridge_minted_util(destination_chain_id);
+    write_bridge_minted(destination_chain_id, minted.saturating_sub(amount));
+
+    let nonce = bridge_nonce_util() + 1;
+    write_to(BRIDGE_NONCE, nonce);
+    nonce
+}
+
+/// Mints `amount` to `recipient` for the burn `source_nonce` on `source_chain_id`.
+pub(crate) fn bridge_mint_util(
+    source_chain_id: u64,
+    source_nonce: u64,
+    recipient: Key,
+    amount: U256,
+) {
+    if is_bridge_nonce_consumed_util(source_chain_id, source_nonce) {
+        revert(CsprUSDError::BridgeNonceConsumed);
+    }
+
+    let minted = bridge_minted_util(source_chain_id)
+        .checked_add(amount)
+        .ok_or(CsprUSDError::Overflow)
+        .unwrap_or_revert();
+    if minted > bridge_mint_cap_util(source_chain_id) {
+        revert(CsprUSDError::ExceedsBridgeMintCap);
+    }
+
+    storage::dictionary_put(
+        get_uref(CONSUMED_BRIDGE_NONCES),
+        &consumed_nonce_item_key(source_chain_id, source_nonce),
+        true,
+    );
+    write_bridge_minted(source_chain_id, minted);
+    check_reserves(amount);
+    mint_balance(recipient, amount).unwrap_or_revert();
+}
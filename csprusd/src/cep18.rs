@@ -0,0 +1,172 @@
+//! Compatibility with the CEP-18 token standard, selected at install.
+//!
+//! csprUSD shares most entry points and events with CEP-18. A token installed with
+//! `enable_cep18_compatibility` also covers the differences: `mint` and `burn` take CEP-18's
+//! `owner` argument, and mints and burns emit CEP-18's `Mint` and `Burn` events, with csprUSD's
+//! extra fields moving to `MintDetails` and `BurnDetails` since event schemas are keyed by name.
+//!
+//! CEP-18 indexers rebuild balances from `Transfer`, `TransferFrom`, `Mint` and `Burn`, so every
+//! other balance change (fees, flash loans, bridging, controller transfers, custody moves and
+//! rescues) also emits the matching CEP-18 event through `emit_cep18_balance_change`. Transfers
+//! report the amount the recipient received, the fee is reported as its own `Transfer`.
+//!
+//! CEP-18's install arguments and `change_security` are accepted as far as they map onto csprUSD:
+//! `total_supply` is minted to the installer and `minter_list` members become minters with an
+//! unlimited allowance, like CEP-18 minters. Minting and burning can't be disabled, events always
+//! follow the Casper Event Standard, and CEP-18 admins have no counterpart since csprUSD splits
+//! their powers between the owner and the master minter, so `enable_mint_burn` must be 1,
+//! `events_mode` must be 1 and `admin_list` must be empty. `change_security` is called by the
+//! master minter, who manages minters here.
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use casper_contract::contract_api::runtime::revert;
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{ADMIN_LIST, CEP18_COMPATIBILITY_ENABLED, ENABLE_MINT_BURN, EVENTS_MODE},
+    error::CsprUSDError,
+    event_schema,
+    events::{
+        self, cep18, BurnDetails, Event, MintDetails, MinterConfigured, MinterRemoved, Transfer,
+    },
+    minters::{add_minter, remove_minter_util, set_minter_allowed},
+    utils::{get_optional_named_arg, read_from},
+};
+
+/// Value of CEP-18's `events_mode` named key meaning events follow the Casper Event Standard.
+pub(crate) const CES_EVENTS_MODE: u8 = 1;
+/// Value of CEP-18's `enable_mint_burn` install argument turning minting and burning on.
+const MINT_BURN_ENABLED: u8 = 1;
+/// CEP-18 `SecurityBadge` values reported in `ChangeSecurity`.
+const MINTER_BADGE: u8 = 1;
+const NONE_BADGE: u8 = 2;
+
+pub(crate) fn cep18_compatibility_enabled() -> bool {
+    read_from(CEP18_COMPATIBILITY_ENABLED)
+}
+
+pub(crate) fn emit_mint(minter: Key, recipient: Key, amount: U256, reference: Option<String>) {
+    if cep18_compatibility_enabled() {
+        events::emit_event(Event::Cep18Mint(cep18::Mint { recipient, amount }));
+        events::emit_event(Event::MintDetails(MintDetails {
+            minter,
+            recipient,
+            amount,
+            reference,
+        }));
+    } else {
+        event_schema::emit_mint(minter, recipient, amount, reference);
+    }
+}
+
+pub(crate) fn emit_burn(minter: Key, amount: U256) {
+    if cep18_compatibility_enabled() {
+        events::emit_event(Event::Cep18Burn(cep18::Burn {
+            owner: minter,
+            amount,
+        }));
+        events::emit_event(Event::BurnDetails(BurnDetails { minter, amount }));
+    } else {
+        event_schema::emit_burn(minter, amount);
+    }
+}
+
+/// A balance change made outside of `transfer`, `transfer_from`, `mint` and `burn`, whose own
+/// events already are the CEP-18 ones in compatibility mode.
+pub(crate) enum BalanceChange {
+    Mint {
+        recipient: Key,
+        amount: U256,
+    },
+    Burn {
+        owner: Key,
+        amount: U256,
+    },
+    Transfer {
+        sender: Key,
+        recipient: Key,
+        amount: U256,
+    },
+}
+
+/// Emits the CEP-18 event for `change` if compatibility is enabled. Nothing is emitted when no
+/// tokens moved.
+pub(crate) fn emit_cep18_balance_change(change: BalanceChange) {
+    if !cep18_compatibility_enabled() {
+        return;
+    }
+
+    let event = match change {
+        BalanceChange::Mint { recipient, amount } if !amount.is_zero() => {
+            Event::Cep18Mint(cep18::Mint { recipient, amount })
+        }
+        BalanceChange::Burn { owner, amount } if !amount.is_zero() => {
+            Event::Cep18Burn(cep18::Burn { owner, amount })
+        }
+        BalanceChange::Transfer {
+            sender,
+            recipient,
+            amount,
+        } if !amount.is_zero() => Event::Transfer(Transfer {
+            sender,
+            recipient,
+            amount,
+        }),
+        _ => return,
+    };
+    events::emit_event(event);
+}
+
+/// Reverts on CEP-18 install arguments that csprUSD can't honour.
+pub(crate) fn assert_supported_cep18_install_args() {
+    if get_optional_named_arg::<u8>(ENABLE_MINT_BURN)
+        .map_or(false, |flag| flag != MINT_BURN_ENABLED)
+    {
+        revert(CsprUSDError::InvalidEnableMBFlag);
+    }
+    if get_optional_named_arg::<u8>(EVENTS_MODE).map_or(false, |mode| mode != CES_EVENTS_MODE) {
+        revert(CsprUSDError::UnsupportedEventsMode);
+    }
+    let admin_list: Vec<Key> = get_optional_named_arg(ADMIN_LIST).unwrap_or_default();
+    if !admin_list.is_empty() {
+        revert(CsprUSDError::UnsupportedSecurityBadge);
+    }
+}
+
+/// Makes `minter` a minter with an unlimited allowance, as CEP-18 minters are.
+pub(crate) fn add_unlimited_minter(minter: Key) {
+    add_minter(minter);
+    set_minter_allowed(minter, U256::MAX);
+    events::emit_event(Event::MinterConfigured(MinterConfigured {
+        minter,
+        minter_allowance: U256::MAX,
+    }));
+}
+
+/// Applies CEP-18's `change_security` for `caller` and returns the badge each key ended up with.
+pub(crate) fn change_security_util(
+    caller: Key,
+    admin_list: Vec<Key>,
+    minter_list: Vec<Key>,
+    none_list: Vec<Key>,
+) -> Result<BTreeMap<Key, u8>, CsprUSDError> {
+    if !admin_list.is_empty() {
+        return Err(CsprUSDError::UnsupportedSecurityBadge);
+    }
+    if minter_list.contains(&caller) || none_list.contains(&caller) {
+        return Err(CsprUSDError::CannotTargetSelfUser);
+    }
+
+    let mut sec_change_map = BTreeMap::new();
+    for minter in minter_list {
+        add_unlimited_minter(minter);
+        sec_change_map.insert(minter, MINTER_BADGE);
+    }
+    for minter in none_list {
+        remove_minter_util(minter);
+        set_minter_allowed(minter, U256::zero());
+        events::emit_event(Event::MinterRemoved(MinterRemoved { minter }));
+        sec_change_map.insert(minter, NONE_BADGE);
+    }
+    Ok(sec_change_map)
+}
@@ -0,0 +1,106 @@
+//! Block-time indexed balance checkpoints, usable as voting weight.
+//!
+//! Every balance (and total supply) change appends the new value together with the current block
+//! time, or overwrites the last checkpoint if it was written in the same block. Historical values
+//! are looked up with a binary search over the checkpoints of an account. The checkpoint of the
+//! current block can still change, so lookups must be strictly in the past.
+use alloc::{format, string::String};
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    balances::make_dictionary_item_key,
+    constants::{CHECKPOINTS, CHECKPOINT_COUNTS, TOTAL_SUPPLY},
+    error::CsprUSDError,
+    utils::{get_uref, now},
+};
+
+/// Checkpoint owner id used for the total supply.
+const TOTAL_SUPPLY_CHECKPOINT_ID: &str = TOTAL_SUPPLY;
+
+fn checkpoint_item_key(owner_id: &str, index: u32) -> String {
+    format!("{}_{}", owner_id, index)
+}
+
+fn read_checkpoint_count(owner_id: &str) -> u32 {
+    storage::dictionary_get(get_uref(CHECKPOINT_COUNTS), owner_id)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn read_checkpoint(owner_id: &str, index: u32) -> (u64, U256) {
+    storage::dictionary_get(get_uref(CHECKPOINTS), &checkpoint_item_key(owner_id, index))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Records `value` as the value of `owner_id` from the current block time onwards.
+fn write_checkpoint(owner_id: &str, value: U256) {
+    let blocktime = now();
+    let checkpoints_uref = get_uref(CHECKPOINTS);
+    let count = read_checkpoint_count(owner_id);
+
+    if count > 0 && read_checkpoint(owner_id, count - 1).0 == blocktime {
+        storage::dictionary_put(
+            checkpoints_uref,
+            &checkpoint_item_key(owner_id, count - 1),
+            (blocktime, value),
+        );
+        return;
+    }
+
+    storage::dictionary_put(
+        checkpoints_uref,
+        &checkpoint_item_key(owner_id, count),
+        (blocktime, value),
+    );
+    storage::dictionary_put(get_uref(CHECKPOINT_COUNTS), owner_id, count + 1);
+}
+
+/// Returns the value of the last checkpoint of `owner_id` written at or before `timestamp`, which
+/// must be before the current block time.
+fn value_at(owner_id: &str, timestamp: u64) -> U256 {
+    if timestamp >= now() {
+        revert(CsprUSDError::LookupNotInPast);
+    }
+
+    let count = read_checkpoint_count(owner_id);
+
+    // number of checkpoints written at or before the timestamp
+    let mut low = 0u32;
+    let mut high = count;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if read_checkpoint(owner_id, mid).0 <= timestamp {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low == 0 {
+        U256::zero()
+    } else {
+        read_checkpoint(owner_id, low - 1).1
+    }
+}
+
+pub(crate) fn write_balance_checkpoint(address: Key, balance: U256) {
+    write_checkpoint(&make_dictionary_item_key(address), balance);
+}
+
+pub(crate) fn write_total_supply_checkpoint(total_supply: U256) {
+    write_checkpoint(TOTAL_SUPPLY_CHECKPOINT_ID, total_supply);
+}
+
+pub(crate) fn get_past_balance_util(address: Key, timestamp: u64) -> U256 {
+    value_at(&make_dictionary_item_key(address), timestamp)
+}
+
+pub(crate) fn get_past_total_supply_util(timestamp: u64) -> U256 {
+    value_at(TOTAL_SUPPLY_CHECKPOINT_ID, timestamp)
+}
@@ -96,3 +96,231 @@ pub const MASTER_MINTER_ENTRY_POINT_NAME: &str = "master_minter";
 pub const BLACKLISTER_ENTRY_POINT_NAME: &str = "blacklister";
 pub const NEW: &str = "new";
 pub const KEY: &str = "key";
+pub const DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME: &str = "detect_transfer_restriction";
+pub const MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME: &str =
+    "message_for_transfer_restriction";
+pub const FROM: &str = "from";
+pub const TO: &str = "to";
+pub const CODE: &str = "code";
+pub const TRANSFER_WITH_MEMO_ENTRY_POINT_NAME: &str = "transfer_with_memo";
+pub const TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME: &str = "transfer_from_with_memo";
+pub const MAX_MEMO_LENGTH_ENTRY_POINT_NAME: &str = "max_memo_length";
+pub const SET_MAX_MEMO_LENGTH_ENTRY_POINT_NAME: &str = "set_max_memo_length";
+pub const MEMO: &str = "memo";
+pub const MAX_MEMO_LENGTH: &str = "max_memo_length";
+/// Maximum memo length in bytes set on install.
+pub const DEFAULT_MAX_MEMO_LENGTH: u32 = 256;
+pub const FEE_BPS: &str = "fee_bps";
+pub const MAX_FEE: &str = "max_fee";
+pub const FEE_RECIPIENT: &str = "fee_recipient";
+pub const FEE_EXEMPT: &str = "fee_exempt";
+pub const EXEMPT: &str = "exempt";
+pub const FEE_BPS_ENTRY_POINT_NAME: &str = "fee_bps";
+pub const MAX_FEE_ENTRY_POINT_NAME: &str = "max_fee";
+pub const FEE_RECIPIENT_ENTRY_POINT_NAME: &str = "fee_recipient";
+pub const IS_FEE_EXEMPT_ENTRY_POINT_NAME: &str = "is_fee_exempt";
+pub const QUOTE_FEE_ENTRY_POINT_NAME: &str = "quote_fee";
+pub const SET_FEE_ENTRY_POINT_NAME: &str = "set_fee";
+pub const SET_FEE_RECIPIENT_ENTRY_POINT_NAME: &str = "set_fee_recipient";
+pub const SET_FEE_EXEMPT_ENTRY_POINT_NAME: &str = "set_fee_exempt";
+pub const SNAPSHOTTER: &str = "snapshotter";
+pub const CURRENT_SNAPSHOT_ID: &str = "current_snapshot_id";
+pub const SNAPSHOTS: &str = "snapshots";
+pub const SNAPSHOT_COUNTS: &str = "snapshot_counts";
+pub const SNAPSHOT_ID: &str = "snapshot_id";
+pub const SNAPSHOTTER_ENTRY_POINT_NAME: &str = "snapshotter";
+pub const UPDATE_SNAPSHOTTER_ENTRY_POINT_NAME: &str = "update_snapshotter";
+pub const SNAPSHOT_ENTRY_POINT_NAME: &str = "snapshot";
+pub const CURRENT_SNAPSHOT_ID_ENTRY_POINT_NAME: &str = "current_snapshot_id";
+pub const BALANCE_OF_AT_ENTRY_POINT_NAME: &str = "balance_of_at";
+pub const TOTAL_SUPPLY_AT_ENTRY_POINT_NAME: &str = "total_supply_at";
+pub const CHECKPOINTS: &str = "checkpoints";
+pub const CHECKPOINT_COUNTS: &str = "checkpoint_counts";
+pub const TIMESTAMP: &str = "timestamp";
+pub const GET_PAST_BALANCE_ENTRY_POINT_NAME: &str = "get_past_balance";
+pub const GET_PAST_TOTAL_SUPPLY_ENTRY_POINT_NAME: &str = "get_past_total_supply";
+pub const FLASH_FEE_BPS: &str = "flash_fee_bps";
+pub const MAX_FLASH_LOAN: &str = "max_flash_loan";
+pub const RECEIVER: &str = "receiver";
+pub const DATA: &str = "data";
+pub const INITIATOR: &str = "initiator";
+pub const TOKEN: &str = "token";
+pub const FEE: &str = "fee";
+pub const FLASH_LOAN_ENTRY_POINT_NAME: &str = "flash_loan";
+pub const FLASH_FEE_ENTRY_POINT_NAME: &str = "flash_fee";
+pub const MAX_FLASH_LOAN_ENTRY_POINT_NAME: &str = "max_flash_loan";
+pub const SET_FLASH_LOAN_CONFIG_ENTRY_POINT_NAME: &str = "set_flash_loan_config";
+/// Name of the entry point flash loan receivers must implement.
+pub const ON_FLASH_LOAN_ENTRY_POINT_NAME: &str = "on_flash_loan";
+pub const CUSTODY_LOCKED: &str = "custody_locked";
+pub const ESCROWS: &str = "escrows";
+pub const ESCROW_COUNT: &str = "escrow_count";
+pub const ESCROW_ID: &str = "escrow_id";
+pub const RELEASE_TIME: &str = "release_time";
+pub const ARBITER: &str = "arbiter";
+pub const TO_RECIPIENT: &str = "to_recipient";
+pub const CREATE_ESCROW_ENTRY_POINT_NAME: &str = "create_escrow";
+pub const RELEASE_ESCROW_ENTRY_POINT_NAME: &str = "release_escrow";
+pub const REFUND_ESCROW_ENTRY_POINT_NAME: &str = "refund_escrow";
+pub const RESOLVE_ESCROW_ENTRY_POINT_NAME: &str = "resolve_escrow";
+pub const ESCROW_ENTRY_POINT_NAME: &str = "escrow";
+pub const ESCROW_STATUS_ENTRY_POINT_NAME: &str = "escrow_status";
+pub const ESCROW_COUNT_ENTRY_POINT_NAME: &str = "escrow_count";
+pub const VESTING_ADMIN: &str = "vesting_admin";
+pub const VESTING_SCHEDULES: &str = "vesting_schedules";
+pub const VESTING_SCHEDULE_COUNT: &str = "vesting_schedule_count";
+pub const SCHEDULE_ID: &str = "schedule_id";
+pub const BENEFICIARY: &str = "beneficiary";
+pub const START: &str = "start";
+pub const CLIFF: &str = "cliff";
+pub const DURATION: &str = "duration";
+pub const REVOCABLE: &str = "revocable";
+pub const VESTING_ADMIN_ENTRY_POINT_NAME: &str = "vesting_admin";
+pub const UPDATE_VESTING_ADMIN_ENTRY_POINT_NAME: &str = "update_vesting_admin";
+pub const CREATE_VESTING_SCHEDULE_ENTRY_POINT_NAME: &str = "create_vesting_schedule";
+pub const RELEASE_VESTED_ENTRY_POINT_NAME: &str = "release_vested";
+pub const REVOKE_VESTING_ENTRY_POINT_NAME: &str = "revoke_vesting";
+pub const VESTING_SCHEDULE_ENTRY_POINT_NAME: &str = "vesting_schedule";
+pub const VESTING_SCHEDULE_COUNT_ENTRY_POINT_NAME: &str = "vesting_schedule_count";
+pub const VESTED_AMOUNT_ENTRY_POINT_NAME: &str = "vested_amount";
+pub const RELEASED_AMOUNT_ENTRY_POINT_NAME: &str = "released_amount";
+pub const RELEASABLE_AMOUNT_ENTRY_POINT_NAME: &str = "releasable_amount";
+pub const STREAMS: &str = "streams";
+pub const STREAM_COUNT: &str = "stream_count";
+pub const STREAM_ID: &str = "stream_id";
+pub const RATE_PER_SECOND: &str = "rate_per_second";
+pub const DEPOSIT: &str = "deposit";
+pub const STOP: &str = "stop";
+pub const CREATE_STREAM_ENTRY_POINT_NAME: &str = "create_stream";
+pub const WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME: &str = "withdraw_from_stream";
+pub const CANCEL_STREAM_ENTRY_POINT_NAME: &str = "cancel_stream";
+pub const STREAM_BALANCE_ENTRY_POINT_NAME: &str = "stream_balance";
+pub const STREAM_COUNT_ENTRY_POINT_NAME: &str = "stream_count";
+pub const ALLOWANCE_EXPIRIES: &str = "allowance_expiries";
+pub const EXPIRES_AT: &str = "expires_at";
+pub const APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME: &str = "approve_with_expiry";
+pub const ALLOWANCE_DETAILS_ENTRY_POINT_NAME: &str = "allowance_details";
+pub const EXPECTED_CURRENT: &str = "expected_current";
+pub const NEW_AMOUNT: &str = "new_amount";
+pub const APPROVE_IF_ENTRY_POINT_NAME: &str = "approve_if";
+pub const DICT_HOLDER_TO_INDEX: &str = "holder_to_index";
+pub const DICT_INDEX_TO_HOLDER: &str = "index_to_holder";
+pub const HOLDER_COUNT: &str = "holder_count";
+pub const HOLDER_REGISTRY_ENABLED: &str = "holder_registry_enabled";
+/// Optional install argument, the holder registry is enabled unless it is `false`.
+pub const ENABLE_HOLDER_REGISTRY: &str = "enable_holder_registry";
+pub const OFFSET: &str = "offset";
+pub const LIMIT: &str = "limit";
+pub const HOLDER_COUNT_ENTRY_POINT_NAME: &str = "holder_count";
+pub const HOLDERS_ENTRY_POINT_NAME: &str = "holders";
+pub const ALLOWANCE_SPENDERS: &str = "allowance_spenders";
+pub const ALLOWANCE_SPENDER_COUNTS: &str = "allowance_spender_counts";
+pub const ALLOWANCE_SPENDER_INDICES: &str = "allowance_spender_indices";
+pub const SPENDERS_OF_ENTRY_POINT_NAME: &str = "spenders_of";
+pub const REVOKE_ALL_ALLOWANCES_ENTRY_POINT_NAME: &str = "revoke_all_allowances";
+pub const BRIDGE_OPERATOR: &str = "bridge_operator";
+pub const BRIDGE_NONCE: &str = "bridge_nonce";
+pub const BRIDGE_MINT_CAPS: &str = "bridge_mint_caps";
+pub const BRIDGE_MINTED: &str = "bridge_minted";
+pub const CONSUMED_BRIDGE_NONCES: &str = "consumed_bridge_nonces";
+pub const CHAIN_ID: &str = "chain_id";
+pub const CAP: &str = "cap";
+pub const NONCE: &str = "nonce";
+pub const DESTINATION_CHAIN_ID: &str = "destination_chain_id";
+pub const DESTINATION_ADDRESS: &str = "destination_address";
+pub const SOURCE_CHAIN_ID: &str = "source_chain_id";
+pub const SOURCE_NONCE: &str = "source_nonce";
+pub const BRIDGE_OPERATOR_ENTRY_POINT_NAME: &str = "bridge_operator";
+pub const UPDATE_BRIDGE_OPERATOR_ENTRY_POINT_NAME: &str = "update_bridge_operator";
+pub const SET_BRIDGE_MINT_CAP_ENTRY_POINT_NAME: &str = "set_bridge_mint_cap";
+pub const BRIDGE_BURN_ENTRY_POINT_NAME: &str = "bridge_burn";
+pub const BRIDGE_MINT_ENTRY_POINT_NAME: &str = "bridge_mint";
+pub const BRIDGE_MINT_CAP_ENTRY_POINT_NAME: &str = "bridge_mint_cap";
+pub const BRIDGE_MINTED_ENTRY_POINT_NAME: &str = "bridge_minted";
+pub const BRIDGE_NONCE_ENTRY_POINT_NAME: &str = "bridge_nonce";
+pub const IS_BRIDGE_NONCE_CONSUMED_ENTRY_POINT_NAME: &str = "is_bridge_nonce_consumed";
+pub const MINT_REFERENCES: &str = "mint_references";
+pub const REFERENCE: &str = "reference";
+pub const MINT_WITH_REFERENCE_ENTRY_POINT_NAME: &str = "mint_with_reference";
+pub const IS_MINT_REFERENCE_CONSUMED_ENTRY_POINT_NAME: &str = "is_mint_reference_consumed";
+pub const RESERVE_ORACLE: &str = "reserve_oracle";
+pub const RESERVE_MAX_AGE: &str = "reserve_max_age";
+pub const ORACLE: &str = "oracle";
+pub const MAX_AGE: &str = "max_age";
+pub const RESERVE_ORACLE_ENTRY_POINT_NAME: &str = "reserve_oracle";
+pub const RESERVE_MAX_AGE_ENTRY_POINT_NAME: &str = "reserve_max_age";
+pub const SET_RESERVE_ORACLE_ENTRY_POINT_NAME: &str = "set_reserve_oracle";
+pub const ATTESTOR: &str = "attestor";
+pub const ATTESTATIONS: &str = "attestations";
+pub const ATTESTATION_COUNT: &str = "attestation_count";
+pub const PERIOD: &str = "period";
+pub const RESERVES_AMOUNT: &str = "reserves_amount";
+pub const REPORT_HASH: &str = "report_hash";
+pub const URI: &str = "uri";
+pub const INDEX: &str = "index";
+pub const ATTESTOR_ENTRY_POINT_NAME: &str = "attestor";
+pub const UPDATE_ATTESTOR_ENTRY_POINT_NAME: &str = "update_attestor";
+pub const PUBLISH_ATTESTATION_ENTRY_POINT_NAME: &str = "publish_attestation";
+pub const ATTESTATION_ENTRY_POINT_NAME: &str = "attestation";
+pub const LATEST_ATTESTATION_ENTRY_POINT_NAME: &str = "latest_attestation";
+pub const ATTESTATION_COUNT_ENTRY_POINT_NAME: &str = "attestation_count";
+pub const MULTISIG_SIGNERS: &str = "multisig_signers";
+pub const MULTISIG_THRESHOLD: &str = "multisig_threshold";
+pub const PROPOSALS: &str = "proposals";
+pub const PROPOSAL_APPROVALS: &str = "proposal_approvals";
+pub const PROPOSAL_COUNT: &str = "proposal_count";
+pub const SIGNERS: &str = "signers";
+pub const THRESHOLD: &str = "threshold";
+pub const ACTION: &str = "action";
+pub const PROPOSAL_ID: &str = "proposal_id";
+pub const MULTISIG_SIGNERS_ENTRY_POINT_NAME: &str = "multisig_signers";
+pub const MULTISIG_THRESHOLD_ENTRY_POINT_NAME: &str = "multisig_threshold";
+pub const CONFIGURE_MULTISIG_ENTRY_POINT_NAME: &str = "configure_multisig";
+pub const PROPOSE_ENTRY_POINT_NAME: &str = "propose";
+pub const APPROVE_PROPOSAL_ENTRY_POINT_NAME: &str = "approve_proposal";
+pub const REVOKE_APPROVAL_ENTRY_POINT_NAME: &str = "revoke_approval";
+pub const EXECUTE_PROPOSAL_ENTRY_POINT_NAME: &str = "execute_proposal";
+pub const PROPOSAL_ENTRY_POINT_NAME: &str = "proposal";
+pub const PROPOSAL_APPROVALS_ENTRY_POINT_NAME: &str = "proposal_approvals";
+pub const PROPOSAL_COUNT_ENTRY_POINT_NAME: &str = "proposal_count";
+pub const TIMELOCK_DELAY: &str = "timelock_delay";
+pub const QUEUED_ACTIONS: &str = "queued_actions";
+pub const QUEUED_ACTION_COUNT: &str = "queued_action_count";
+pub const DELAY: &str = "delay";
+pub const ETA: &str = "eta";
+pub const ACTION_ID: &str = "action_id";
+pub const TIMELOCK_DELAY_ENTRY_POINT_NAME: &str = "timelock_delay";
+pub const SET_TIMELOCK_DELAY_ENTRY_POINT_NAME: &str = "set_timelock_delay";
+pub const QUEUE_ACTION_ENTRY_POINT_NAME: &str = "queue_action";
+pub const EXECUTE_QUEUED_ACTION_ENTRY_POINT_NAME: &str = "execute_queued_action";
+pub const CANCEL_QUEUED_ACTION_ENTRY_POINT_NAME: &str = "cancel_queued_action";
+pub const QUEUED_ACTION_ENTRY_POINT_NAME: &str = "queued_action";
+pub const QUEUED_ACTIONS_ENTRY_POINT_NAME: &str = "queued_actions";
+pub const QUEUED_ACTION_COUNT_ENTRY_POINT_NAME: &str = "queued_action_count";
+pub const GUARDIANS: &str = "guardians";
+pub const GUARDIANS_ENTRY_POINT_NAME: &str = "guardians";
+pub const IS_GUARDIAN_ENTRY_POINT_NAME: &str = "is_guardian";
+pub const SET_GUARDIANS_ENTRY_POINT_NAME: &str = "set_guardians";
+pub const GUARDIAN_PAUSE_ENTRY_POINT_NAME: &str = "guardian_pause";
+pub const GUARDIAN_REVOKE_MINTER_ALLOWANCE_ENTRY_POINT_NAME: &str =
+    "guardian_revoke_minter_allowance";
+pub const GUARDIAN_REMOVE_MINTER_ENTRY_POINT_NAME: &str = "guardian_remove_minter";
+pub const CONTROLLER: &str = "controller";
+pub const CONTROLLER_DISABLED: &str = "controller_disabled";
+pub const LEGAL_REFERENCE: &str = "legal_reference";
+pub const CONTROLLER_ENTRY_POINT_NAME: &str = "controller";
+pub const UPDATE_CONTROLLER_ENTRY_POINT_NAME: &str = "update_controller";
+pub const IS_CONTROLLABLE_ENTRY_POINT_NAME: &str = "is_controllable";
+pub const DISABLE_CONTROLLER_ENTRY_POINT_NAME: &str = "disable_controller";
+pub const CONTROLLER_TRANSFER_ENTRY_POINT_NAME: &str = "controller_transfer";
+pub const RESCUE_TOKENS_ENTRY_POINT_NAME: &str = "rescue_tokens";
+pub const RESCUABLE_BALANCE_ENTRY_POINT_NAME: &str = "rescuable_balance";
+pub const ENABLE_CEP18_COMPATIBILITY: &str = "enable_cep18_compatibility";
+pub const CEP18_COMPATIBILITY_ENABLED: &str = "cep18_compatibility_enabled";
+pub const EVENTS_MODE: &str = "events_mode";
+pub const CHANGE_SECURITY_ENTRY_POINT_NAME: &str = "change_security";
+pub const UPGRADER: &str = "upgrader";
+pub const AUTHORIZE_UPGRADE_ENTRY_POINT_NAME: &str = "authorize_upgrade";
+pub const UPGRADE_ACCESS_ENTRY_POINT_NAME: &str = "upgrade_access";
+pub const MULTISIG_CONFIG_NONCE: &str = "multisig_config_nonce";
+pub const EVENTS_SCHEMA_VERSION: &str = "events_schema_version";
@@ -0,0 +1,84 @@
+//! Tokens held by the contract itself on behalf of escrows and similar arrangements.
+//!
+//! Custody balances live under the token's own package key. The amount that is owed to somebody
+//! is tracked separately so that it can be told apart from tokens sent to the package by mistake,
+//! which the owner can rescue.
+use casper_contract::{
+    contract_api::runtime::{self, revert},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    balances::{get_balances_uref, read_balance_from, transfer_balance},
+    cep18::{emit_cep18_balance_change, BalanceChange},
+    constants::{CUSTODY_LOCKED, PACKAGE_HASH},
+    error::CsprUSDError,
+    utils::{read_from, write_to},
+};
+
+/// Returns the key under which the contract holds tokens.
+pub(crate) fn custody_key() -> Key {
+    runtime::get_key(PACKAGE_HASH).unwrap_or_revert()
+}
+
+/// Returns the amount held in custody that is owed to somebody.
+pub(crate) fn custody_locked() -> U256 {
+    read_from(CUSTODY_LOCKED)
+}
+
+/// Moves `amount` from `owner` into custody.
+pub(crate) fn lock_in_custody(owner: Key, amount: U256) -> Result<(), CsprUSDError> {
+    transfer_balance(owner, custody_key(), amount)?;
+    emit_cep18_balance_change(BalanceChange::Transfer {
+        sender: owner,
+        recipient: custody_key(),
+        amount,
+    });
+    let locked = custody_locked()
+        .checked_add(amount)
+        .ok_or(CsprUSDError::Overflow)?;
+    write_to(CUSTODY_LOCKED, locked);
+    Ok(())
+}
+
+/// Pays `amount` out of custody to `recipient`.
+pub(crate) fn release_from_custody(recipient: Key, amount: U256) -> Result<(), CsprUSDError> {
+    let locked = custody_locked()
+        .checked_sub(amount)
+        .ok_or(CsprUSDError::InsufficientBalance)?;
+    transfer_balance(custody_key(), recipient, amount)?;
+    emit_cep18_balance_change(BalanceChange::Transfer {
+        sender: custody_key(),
+        recipient,
+        amount,
+    });
+    write_to(CUSTODY_LOCKED, locked);
+    Ok(())
+}
+
+/// Reverts if `recipient` is the contract's own package, where tokens would be stranded.
+pub(crate) fn assert_not_custody(recipient: Key) {
+    if recipient == custody_key() {
+        revert(CsprUSDError::CannotTransferToContract);
+    }
+}
+
+/// Returns the tokens held by the package that aren't owed to anybody.
+pub(crate) fn rescuable_balance_util() -> U256 {
+    read_balance_from(get_balances_uref(), custody_key()).saturating_sub(custody_locked())
+}
+
+/// Sends `amount` of the tokens stranded at the package to `recipient`.
+pub(crate) fn rescue_from_custody(recipient: Key, amount: U256) -> Result<(), CsprUSDError> {
+    if amount > rescuable_balance_util() {
+        return Err(CsprUSDError::ExceedsRescuableBalance);
+    }
+    transfer_balance(custody_key(), recipient, amount)?;
+    emit_cep18_balance_change(BalanceChange::Transfer {
+        sender: custody_key(),
+        recipient,
+        amount,
+    });
+    Ok(())
+}
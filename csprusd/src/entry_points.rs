@@ -2,24 +2,80 @@
 use alloc::{string::String, vec, vec::Vec};
 
 use casper_types::{
-    account::AccountHash, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType,
-    EntryPoints, Key, Parameter, PublicKey, U256,
+    account::AccountHash, bytesrepr::Bytes, CLType, CLTyped, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, PublicKey, URef, U256,
 };
 
 use crate::constants::{
-    ADDRESS, ALLOWANCE_ENTRY_POINT_NAME, AMOUNT, APPROVE_ENTRY_POINT_NAME,
-    BALANCE_OF_ENTRY_POINT_NAME, BLACKLISTER_ENTRY_POINT_NAME, BLACKLIST_ENTRY_POINT_NAME,
-    BURN_ENTRY_POINT_NAME, CONFIGURE_MINTER_ENTRY_POINT_NAME, DECIMALS_ENTRY_POINT_NAME,
-    DECREASE_ALLOWANCE_ENTRY_POINT_NAME, INCREASE_ALLOWANCE_ENTRY_POINT_NAME,
-    INIT_ENTRY_POINT_NAME, IS_BLACKLISTED_ENTRY_POINT_NAME, IS_MINTER_ENTRY_POINT_NAME,
-    IS_PAUSED_ENTRY_POINT_NAME, KEY, MASTER_MINTER, MASTER_MINTER_ENTRY_POINT_NAME, MINTER,
-    MINTER_ALLOWANCE_ENTRY_POINT_NAME, MINTER_ALLOWED, MINT_ENTRY_POINT_NAME,
-    NAME_ENTRY_POINT_NAME, NEW, OWNER, OWNER_ENTRY_POINT_NAME, PACKAGE_HASH,
-    PAUSER_ENTRY_POINT_NAME, PAUSE_ENTRY_POINT_NAME, RECIPIENT, REMOVE_MINTER_ENTRY_POINT_NAME,
-    SPENDER, SYMBOL_ENTRY_POINT_NAME, TOTAL_SUPPLY_ENTRY_POINT_NAME, TRANSFER_ENTRY_POINT_NAME,
-    TRANSFER_FROM_ENTRY_POINT_NAME, TRANSFER_OWNERSHIP_ENTRY_POINT_NAME, UNPAUSE_ENTRY_POINT_NAME,
-    UN_BLACKLIST_ENTRY_POINT_NAME, UPDATE_BLACKLISTER_ENTRY_POINT_NAME,
-    UPDATE_MASTER_MINTER_ENTRY_POINT_NAME, UPDATE_PAUSER_ENTRY_POINT_NAME,
+    ACTION, ACTION_ID, ADDRESS, ALLOWANCE_DETAILS_ENTRY_POINT_NAME, ALLOWANCE_ENTRY_POINT_NAME,
+    AMOUNT, APPROVE_ENTRY_POINT_NAME, APPROVE_IF_ENTRY_POINT_NAME,
+    APPROVE_PROPOSAL_ENTRY_POINT_NAME, APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME, ARBITER,
+    ATTESTATION_COUNT_ENTRY_POINT_NAME, ATTESTATION_ENTRY_POINT_NAME, ATTESTOR_ENTRY_POINT_NAME,
+    AUTHORIZE_UPGRADE_ENTRY_POINT_NAME, BALANCE_OF_AT_ENTRY_POINT_NAME,
+    BALANCE_OF_ENTRY_POINT_NAME, BENEFICIARY, BLACKLISTER_ENTRY_POINT_NAME,
+    BLACKLIST_ENTRY_POINT_NAME, BRIDGE_BURN_ENTRY_POINT_NAME, BRIDGE_MINTED_ENTRY_POINT_NAME,
+    BRIDGE_MINT_CAP_ENTRY_POINT_NAME, BRIDGE_MINT_ENTRY_POINT_NAME, BRIDGE_NONCE_ENTRY_POINT_NAME,
+    BRIDGE_OPERATOR_ENTRY_POINT_NAME, BURN_ENTRY_POINT_NAME, CANCEL_QUEUED_ACTION_ENTRY_POINT_NAME,
+    CANCEL_STREAM_ENTRY_POINT_NAME, CAP, CHAIN_ID, CHANGE_SECURITY_ENTRY_POINT_NAME, CLIFF, CODE,
+    CONFIGURE_MINTER_ENTRY_POINT_NAME, CONFIGURE_MULTISIG_ENTRY_POINT_NAME,
+    CONTROLLER_ENTRY_POINT_NAME, CONTROLLER_TRANSFER_ENTRY_POINT_NAME,
+    CREATE_ESCROW_ENTRY_POINT_NAME, CREATE_STREAM_ENTRY_POINT_NAME,
+    CREATE_VESTING_SCHEDULE_ENTRY_POINT_NAME, CURRENT_SNAPSHOT_ID_ENTRY_POINT_NAME, DATA,
+    DECIMALS_ENTRY_POINT_NAME, DECREASE_ALLOWANCE_ENTRY_POINT_NAME, DELAY, DEPOSIT,
+    DESTINATION_ADDRESS, DESTINATION_CHAIN_ID, DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME,
+    DISABLE_CONTROLLER_ENTRY_POINT_NAME, DURATION, ESCROW_COUNT_ENTRY_POINT_NAME,
+    ESCROW_ENTRY_POINT_NAME, ESCROW_ID, ESCROW_STATUS_ENTRY_POINT_NAME, ETA,
+    EXECUTE_PROPOSAL_ENTRY_POINT_NAME, EXECUTE_QUEUED_ACTION_ENTRY_POINT_NAME, EXEMPT,
+    EXPECTED_CURRENT, EXPIRES_AT, FEE_BPS, FEE_BPS_ENTRY_POINT_NAME,
+    FEE_RECIPIENT_ENTRY_POINT_NAME, FLASH_FEE_BPS, FLASH_FEE_ENTRY_POINT_NAME,
+    FLASH_LOAN_ENTRY_POINT_NAME, FROM, GET_PAST_BALANCE_ENTRY_POINT_NAME,
+    GET_PAST_TOTAL_SUPPLY_ENTRY_POINT_NAME, GUARDIANS, GUARDIANS_ENTRY_POINT_NAME,
+    GUARDIAN_PAUSE_ENTRY_POINT_NAME, GUARDIAN_REMOVE_MINTER_ENTRY_POINT_NAME,
+    GUARDIAN_REVOKE_MINTER_ALLOWANCE_ENTRY_POINT_NAME, HOLDERS_ENTRY_POINT_NAME,
+    HOLDER_COUNT_ENTRY_POINT_NAME, INCREASE_ALLOWANCE_ENTRY_POINT_NAME, INDEX,
+    INIT_ENTRY_POINT_NAME, IS_BLACKLISTED_ENTRY_POINT_NAME,
+    IS_BRIDGE_NONCE_CONSUMED_ENTRY_POINT_NAME, IS_CONTROLLABLE_ENTRY_POINT_NAME,
+    IS_FEE_EXEMPT_ENTRY_POINT_NAME, IS_GUARDIAN_ENTRY_POINT_NAME, IS_MINTER_ENTRY_POINT_NAME,
+    IS_MINT_REFERENCE_CONSUMED_ENTRY_POINT_NAME, IS_PAUSED_ENTRY_POINT_NAME, KEY,
+    LATEST_ATTESTATION_ENTRY_POINT_NAME, LEGAL_REFERENCE, LIMIT, MASTER_MINTER,
+    MASTER_MINTER_ENTRY_POINT_NAME, MAX_AGE, MAX_FEE, MAX_FEE_ENTRY_POINT_NAME, MAX_FLASH_LOAN,
+    MAX_FLASH_LOAN_ENTRY_POINT_NAME, MAX_MEMO_LENGTH_ENTRY_POINT_NAME, MEMO,
+    MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME, MINTER, MINTER_ALLOWANCE_ENTRY_POINT_NAME,
+    MINTER_ALLOWED, MINTER_LIST, MINT_ENTRY_POINT_NAME, MINT_WITH_REFERENCE_ENTRY_POINT_NAME,
+    MULTISIG_SIGNERS_ENTRY_POINT_NAME, MULTISIG_THRESHOLD_ENTRY_POINT_NAME, NAME_ENTRY_POINT_NAME,
+    NEW, NEW_AMOUNT, NONCE, OFFSET, ORACLE, OWNER, OWNER_ENTRY_POINT_NAME, PACKAGE_HASH,
+    PAUSER_ENTRY_POINT_NAME, PAUSE_ENTRY_POINT_NAME, PERIOD, PROPOSAL_APPROVALS_ENTRY_POINT_NAME,
+    PROPOSAL_COUNT_ENTRY_POINT_NAME, PROPOSAL_ENTRY_POINT_NAME, PROPOSAL_ID,
+    PROPOSE_ENTRY_POINT_NAME, PUBLISH_ATTESTATION_ENTRY_POINT_NAME,
+    QUEUED_ACTIONS_ENTRY_POINT_NAME, QUEUED_ACTION_COUNT_ENTRY_POINT_NAME,
+    QUEUED_ACTION_ENTRY_POINT_NAME, QUEUE_ACTION_ENTRY_POINT_NAME, QUOTE_FEE_ENTRY_POINT_NAME,
+    RATE_PER_SECOND, RECEIVER, RECIPIENT, REFERENCE, REFUND_ESCROW_ENTRY_POINT_NAME,
+    RELEASABLE_AMOUNT_ENTRY_POINT_NAME, RELEASED_AMOUNT_ENTRY_POINT_NAME,
+    RELEASE_ESCROW_ENTRY_POINT_NAME, RELEASE_TIME, RELEASE_VESTED_ENTRY_POINT_NAME,
+    REMOVE_MINTER_ENTRY_POINT_NAME, REPORT_HASH, RESCUABLE_BALANCE_ENTRY_POINT_NAME,
+    RESCUE_TOKENS_ENTRY_POINT_NAME, RESERVES_AMOUNT, RESERVE_MAX_AGE_ENTRY_POINT_NAME,
+    RESERVE_ORACLE_ENTRY_POINT_NAME, RESOLVE_ESCROW_ENTRY_POINT_NAME, REVOCABLE,
+    REVOKE_ALL_ALLOWANCES_ENTRY_POINT_NAME, REVOKE_APPROVAL_ENTRY_POINT_NAME,
+    REVOKE_VESTING_ENTRY_POINT_NAME, SCHEDULE_ID, SET_BRIDGE_MINT_CAP_ENTRY_POINT_NAME,
+    SET_FEE_ENTRY_POINT_NAME, SET_FEE_EXEMPT_ENTRY_POINT_NAME, SET_FEE_RECIPIENT_ENTRY_POINT_NAME,
+    SET_FLASH_LOAN_CONFIG_ENTRY_POINT_NAME, SET_GUARDIANS_ENTRY_POINT_NAME,
+    SET_MAX_MEMO_LENGTH_ENTRY_POINT_NAME, SET_RESERVE_ORACLE_ENTRY_POINT_NAME,
+    SET_TIMELOCK_DELAY_ENTRY_POINT_NAME, SIGNERS, SNAPSHOTTER_ENTRY_POINT_NAME,
+    SNAPSHOT_ENTRY_POINT_NAME, SNAPSHOT_ID, SOURCE_CHAIN_ID, SOURCE_NONCE, SPENDER,
+    SPENDERS_OF_ENTRY_POINT_NAME, START, STOP, STREAM_BALANCE_ENTRY_POINT_NAME,
+    STREAM_COUNT_ENTRY_POINT_NAME, STREAM_ID, SYMBOL_ENTRY_POINT_NAME, THRESHOLD,
+    TIMELOCK_DELAY_ENTRY_POINT_NAME, TIMESTAMP, TO, TOTAL_SUPPLY, TOTAL_SUPPLY_AT_ENTRY_POINT_NAME,
+    TOTAL_SUPPLY_ENTRY_POINT_NAME, TO_RECIPIENT, TRANSFER_ENTRY_POINT_NAME,
+    TRANSFER_FROM_ENTRY_POINT_NAME, TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME,
+    TRANSFER_OWNERSHIP_ENTRY_POINT_NAME, TRANSFER_WITH_MEMO_ENTRY_POINT_NAME,
+    UNPAUSE_ENTRY_POINT_NAME, UN_BLACKLIST_ENTRY_POINT_NAME, UPDATE_ATTESTOR_ENTRY_POINT_NAME,
+    UPDATE_BLACKLISTER_ENTRY_POINT_NAME, UPDATE_BRIDGE_OPERATOR_ENTRY_POINT_NAME,
+    UPDATE_CONTROLLER_ENTRY_POINT_NAME, UPDATE_MASTER_MINTER_ENTRY_POINT_NAME,
+    UPDATE_PAUSER_ENTRY_POINT_NAME, UPDATE_SNAPSHOTTER_ENTRY_POINT_NAME,
+    UPDATE_VESTING_ADMIN_ENTRY_POINT_NAME, UPGRADER, UPGRADE_ACCESS_ENTRY_POINT_NAME, URI,
+    VESTED_AMOUNT_ENTRY_POINT_NAME, VESTING_ADMIN_ENTRY_POINT_NAME,
+    VESTING_SCHEDULE_COUNT_ENTRY_POINT_NAME, VESTING_SCHEDULE_ENTRY_POINT_NAME,
+    WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME,
 };
 
 /// Returns the `name` entry point.
@@ -109,6 +165,22 @@ pub fn transfer_from() -> EntryPoint {
     )
 }
 
+/// Returns the `transfer_from_with_memo` entry point.
+pub fn transfer_from_with_memo() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TRANSFER_FROM_WITH_MEMO_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(MEMO, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `allowance` entry point.
 pub fn allowance() -> EntryPoint {
     EntryPoint::new(
@@ -137,6 +209,76 @@ pub fn approve() -> EntryPoint {
     )
 }
 
+/// Returns the `spenders_of` entry point.
+pub fn spenders_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SPENDERS_OF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<(Key, U256)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `revoke_all_allowances` entry point.
+pub fn revoke_all_allowances() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_ALL_ALLOWANCES_ENTRY_POINT_NAME),
+        vec![Parameter::new(LIMIT, u32::cl_type())],
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `approve_if` entry point.
+pub fn approve_if() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_IF_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER, Key::cl_type()),
+            Parameter::new(EXPECTED_CURRENT, U256::cl_type()),
+            Parameter::new(NEW_AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `approve_with_expiry` entry point.
+pub fn approve_with_expiry() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_WITH_EXPIRY_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SPENDER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(EXPIRES_AT, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `allowance_details` entry point.
+pub fn allowance_details() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ALLOWANCE_DETAILS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(SPENDER, Key::cl_type()),
+        ],
+        <(U256, u64)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `increase_allowance` entry point.
 pub fn increase_allowance() -> EntryPoint {
     EntryPoint::new(
@@ -179,57 +321,1325 @@ pub fn transfer() -> EntryPoint {
     )
 }
 
-/// Returns the `balance_of` entry point.
-pub fn balance_of() -> EntryPoint {
+/// Returns the `transfer_with_memo` entry point.
+pub fn transfer_with_memo() -> EntryPoint {
     EntryPoint::new(
-        String::from(BALANCE_OF_ENTRY_POINT_NAME),
-        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        String::from(TRANSFER_WITH_MEMO_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(MEMO, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `max_memo_length` entry point.
+pub fn max_memo_length() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_MEMO_LENGTH_ENTRY_POINT_NAME),
+        Vec::new(),
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_max_memo_length` entry point.
+pub fn set_max_memo_length() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MAX_MEMO_LENGTH_ENTRY_POINT_NAME),
+        vec![Parameter::new(NEW, u32::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `fee_bps` entry point.
+pub fn fee_bps() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FEE_BPS_ENTRY_POINT_NAME),
+        Vec::new(),
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `max_fee` entry point.
+pub fn max_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_FEE_ENTRY_POINT_NAME),
+        Vec::new(),
         U256::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
 }
 
-/// Returns the `total_supply` entry point.
-pub fn total_supply() -> EntryPoint {
+/// Returns the `fee_recipient` entry point.
+pub fn fee_recipient() -> EntryPoint {
     EntryPoint::new(
-        String::from(TOTAL_SUPPLY_ENTRY_POINT_NAME),
+        String::from(FEE_RECIPIENT_ENTRY_POINT_NAME),
         Vec::new(),
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_fee_exempt` entry point.
+pub fn is_fee_exempt() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_FEE_EXEMPT_ENTRY_POINT_NAME),
+        vec![Parameter::new(KEY, Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `quote_fee` entry point.
+pub fn quote_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(QUOTE_FEE_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT, U256::cl_type())],
         U256::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
 }
 
-/// Returns the `decimals` entry point.
-pub fn decimals() -> EntryPoint {
+/// Returns the `set_fee` entry point.
+pub fn set_fee() -> EntryPoint {
     EntryPoint::new(
-        String::from(DECIMALS_ENTRY_POINT_NAME),
+        String::from(SET_FEE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FEE_BPS, u32::cl_type()),
+            Parameter::new(MAX_FEE, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_fee_recipient` entry point.
+pub fn set_fee_recipient() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_FEE_RECIPIENT_ENTRY_POINT_NAME),
+        vec![Parameter::new(NEW, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_fee_exempt` entry point.
+pub fn set_fee_exempt() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_FEE_EXEMPT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(KEY, Key::cl_type()),
+            Parameter::new(EXEMPT, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `snapshotter` entry point.
+pub fn snapshotter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SNAPSHOTTER_ENTRY_POINT_NAME),
         Vec::new(),
-        u8::cl_type(),
+        Key::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
 }
 
-/// Returns the `burn` entry point.
-pub fn burn() -> EntryPoint {
+/// Returns the `update_snapshotter` entry point.
+pub fn update_snapshotter() -> EntryPoint {
     EntryPoint::new(
-        String::from(BURN_ENTRY_POINT_NAME),
+        String::from(UPDATE_SNAPSHOTTER_ENTRY_POINT_NAME),
+        vec![Parameter::new(NEW, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `snapshot` entry point.
+pub fn snapshot() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SNAPSHOT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `current_snapshot_id` entry point.
+pub fn current_snapshot_id() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CURRENT_SNAPSHOT_ID_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_of_at` entry point.
+pub fn balance_of_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_AT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS, Key::cl_type()),
+            Parameter::new(SNAPSHOT_ID, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_supply_at` entry point.
+pub fn total_supply_at() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_AT_ENTRY_POINT_NAME),
+        vec![Parameter::new(SNAPSHOT_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_past_balance` entry point.
+pub fn get_past_balance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_PAST_BALANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ADDRESS, Key::cl_type()),
+            Parameter::new(TIMESTAMP, u64::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_past_total_supply` entry point.
+pub fn get_past_total_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_PAST_TOTAL_SUPPLY_ENTRY_POINT_NAME),
+        vec![Parameter::new(TIMESTAMP, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `max_flash_loan` entry point.
+pub fn max_flash_loan() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MAX_FLASH_LOAN_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `flash_fee` entry point.
+pub fn flash_fee() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FLASH_FEE_ENTRY_POINT_NAME),
         vec![Parameter::new(AMOUNT, U256::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_flash_loan_config` entry point.
+pub fn set_flash_loan_config() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_FLASH_LOAN_CONFIG_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FLASH_FEE_BPS, u32::cl_type()),
+            Parameter::new(MAX_FLASH_LOAN, U256::cl_type()),
+        ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
 }
 
-/// Returns the `mint` entry point.
-pub fn mint() -> EntryPoint {
+/// Returns the `flash_loan` entry point.
+pub fn flash_loan() -> EntryPoint {
     EntryPoint::new(
-        String::from(MINT_ENTRY_POINT_NAME),
+        String::from(FLASH_LOAN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECEIVER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_escrow` entry point.
+pub fn create_escrow() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_ESCROW_ENTRY_POINT_NAME),
         vec![
             Parameter::new(RECIPIENT, Key::cl_type()),
             Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(RELEASE_TIME, u64::cl_type()),
+            Parameter::new(ARBITER, Option::<Key>::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `release_escrow` entry point.
+pub fn release_escrow() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASE_ESCROW_ENTRY_POINT_NAME),
+        vec![Parameter::new(ESCROW_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `refund_escrow` entry point.
+pub fn refund_escrow() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REFUND_ESCROW_ENTRY_POINT_NAME),
+        vec![Parameter::new(ESCROW_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `resolve_escrow` entry point.
+pub fn resolve_escrow() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RESOLVE_ESCROW_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ESCROW_ID, u64::cl_type()),
+            Parameter::new(TO_RECIPIENT, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `escrow` entry point.
+pub fn escrow() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ESCROW_ENTRY_POINT_NAME),
+        vec![Parameter::new(ESCROW_ID, u64::cl_type())],
+        <((Key, Key, Option<Key>), (U256, u64, u8))>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `escrow_status` entry point.
+pub fn escrow_status() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ESCROW_STATUS_ENTRY_POINT_NAME),
+        vec![Parameter::new(ESCROW_ID, u64::cl_type())],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `escrow_count` entry point.
+pub fn escrow_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ESCROW_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `vesting_admin` entry point.
+pub fn vesting_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(VESTING_ADMIN_ENTRY_POINT_NAME),
+        Vec::new(),
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `update_vesting_admin` entry point.
+pub fn update_vesting_admin() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UPDATE_VESTING_ADMIN_ENTRY_POINT_NAME),
+        vec![Parameter::new(NEW, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_vesting_schedule` entry point.
+pub fn create_vesting_schedule() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_VESTING_SCHEDULE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(BENEFICIARY, Key::cl_type()),
+            Parameter::new(START, u64::cl_type()),
+            Parameter::new(CLIFF, u64::cl_type()),
+            Parameter::new(DURATION, u64::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(REVOCABLE, bool::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `release_vested` entry point.
+pub fn release_vested() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASE_VESTED_ENTRY_POINT_NAME),
+        vec![Parameter::new(SCHEDULE_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `revoke_vesting` entry point.
+pub fn revoke_vesting() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_VESTING_ENTRY_POINT_NAME),
+        vec![Parameter::new(SCHEDULE_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `vesting_schedule` entry point.
+pub fn vesting_schedule() -> EntryPoint {
+    EntryPoint::new(
+        String::from(VESTING_SCHEDULE_ENTRY_POINT_NAME),
+        vec![Parameter::new(SCHEDULE_ID, u64::cl_type())],
+        <((Key, Key, bool), (u64, u64, u64), (U256, U256, bool))>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `vesting_schedule_count` entry point.
+pub fn vesting_schedule_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(VESTING_SCHEDULE_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `vested_amount` entry point.
+pub fn vested_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(VESTED_AMOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(SCHEDULE_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `released_amount` entry point.
+pub fn released_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASED_AMOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(SCHEDULE_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `releasable_amount` entry point.
+pub fn releasable_amount() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RELEASABLE_AMOUNT_ENTRY_POINT_NAME),
+        vec![Parameter::new(SCHEDULE_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_stream` entry point.
+pub fn create_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_STREAM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(RATE_PER_SECOND, U256::cl_type()),
+            Parameter::new(DEPOSIT, U256::cl_type()),
+            Parameter::new(START, u64::cl_type()),
+            Parameter::new(STOP, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `withdraw_from_stream` entry point.
+pub fn withdraw_from_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(WITHDRAW_FROM_STREAM_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(STREAM_ID, u64::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_stream` entry point.
+pub fn cancel_stream() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_STREAM_ENTRY_POINT_NAME),
+        vec![Parameter::new(STREAM_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `stream_balance` entry point.
+pub fn stream_balance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(STREAM_BALANCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(STREAM_ID, u64::cl_type()),
+            Parameter::new(ADDRESS, Key::cl_type()),
+        ],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `stream_count` entry point.
+pub fn stream_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(STREAM_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holder_count` entry point.
+pub fn holder_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDER_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `holders` entry point.
+pub fn holders() -> EntryPoint {
+    EntryPoint::new(
+        String::from(HOLDERS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_operator` entry point.
+pub fn bridge_operator() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_OPERATOR_ENTRY_POINT_NAME),
+        Vec::new(),
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `update_bridge_operator` entry point.
+pub fn update_bridge_operator() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UPDATE_BRIDGE_OPERATOR_ENTRY_POINT_NAME),
+        vec![Parameter::new(NEW, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_bridge_mint_cap` entry point.
+pub fn set_bridge_mint_cap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_BRIDGE_MINT_CAP_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(CHAIN_ID, u64::cl_type()),
+            Parameter::new(CAP, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_burn` entry point.
+pub fn bridge_burn() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_BURN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(DESTINATION_CHAIN_ID, u64::cl_type()),
+            Parameter::new(DESTINATION_ADDRESS, Bytes::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_mint` entry point.
+pub fn bridge_mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SOURCE_CHAIN_ID, u64::cl_type()),
+            Parameter::new(SOURCE_NONCE, u64::cl_type()),
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_mint_cap` entry point.
+pub fn bridge_mint_cap() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_MINT_CAP_ENTRY_POINT_NAME),
+        vec![Parameter::new(CHAIN_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_minted` entry point.
+pub fn bridge_minted() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_MINTED_ENTRY_POINT_NAME),
+        vec![Parameter::new(CHAIN_ID, u64::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `bridge_nonce` entry point.
+pub fn bridge_nonce() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BRIDGE_NONCE_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_bridge_nonce_consumed` entry point.
+pub fn is_bridge_nonce_consumed() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_BRIDGE_NONCE_CONSUMED_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(CHAIN_ID, u64::cl_type()),
+            Parameter::new(NONCE, u64::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `attestor` entry point.
+pub fn attestor() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ATTESTOR_ENTRY_POINT_NAME),
+        Vec::new(),
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `update_attestor` entry point.
+pub fn update_attestor() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UPDATE_ATTESTOR_ENTRY_POINT_NAME),
+        vec![Parameter::new(NEW, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `publish_attestation` entry point.
+pub fn publish_attestation() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PUBLISH_ATTESTATION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(PERIOD, String::cl_type()),
+            Parameter::new(RESERVES_AMOUNT, U256::cl_type()),
+            Parameter::new(REPORT_HASH, <[u8; 32]>::cl_type()),
+            Parameter::new(URI, String::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `attestation` entry point.
+pub fn attestation() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ATTESTATION_ENTRY_POINT_NAME),
+        vec![Parameter::new(INDEX, u64::cl_type())],
+        <((String, U256, [u8; 32]), (String, Key, u64))>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `latest_attestation` entry point.
+pub fn latest_attestation() -> EntryPoint {
+    EntryPoint::new(
+        String::from(LATEST_ATTESTATION_ENTRY_POINT_NAME),
+        Vec::new(),
+        <((String, U256, [u8; 32]), (String, Key, u64))>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `attestation_count` entry point.
+pub fn attestation_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ATTESTATION_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `multisig_signers` entry point.
+pub fn multisig_signers() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MULTISIG_SIGNERS_ENTRY_POINT_NAME),
+        Vec::new(),
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `multisig_threshold` entry point.
+pub fn multisig_threshold() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MULTISIG_THRESHOLD_ENTRY_POINT_NAME),
+        Vec::new(),
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `configure_multisig` entry point.
+pub fn configure_multisig() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONFIGURE_MULTISIG_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(SIGNERS, Vec::<Key>::cl_type()),
+            Parameter::new(THRESHOLD, u32::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `propose` entry point.
+pub fn propose() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACTION, u8::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+            Parameter::new(EXPIRES_AT, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `approve_proposal` entry point.
+pub fn approve_proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(APPROVE_PROPOSAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `revoke_approval` entry point.
+pub fn revoke_approval() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVOKE_APPROVAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `execute_proposal` entry point.
+pub fn execute_proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_PROPOSAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `proposal` entry point.
+pub fn proposal() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSAL_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        <((Key, u8, Bytes), (u64, bool))>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `proposal_approvals` entry point.
+pub fn proposal_approvals() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSAL_APPROVALS_ENTRY_POINT_NAME),
+        vec![Parameter::new(PROPOSAL_ID, u64::cl_type())],
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `proposal_count` entry point.
+pub fn proposal_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PROPOSAL_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `timelock_delay` entry point.
+pub fn timelock_delay() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TIMELOCK_DELAY_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_timelock_delay` entry point.
+pub fn set_timelock_delay() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_TIMELOCK_DELAY_ENTRY_POINT_NAME),
+        vec![Parameter::new(DELAY, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `queue_action` entry point.
+pub fn queue_action() -> EntryPoint {
+    EntryPoint::new(
+        String::from(QUEUE_ACTION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ACTION, u8::cl_type()),
+            Parameter::new(DATA, Bytes::cl_type()),
+            Parameter::new(ETA, u64::cl_type()),
+        ],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `execute_queued_action` entry point.
+pub fn execute_queued_action() -> EntryPoint {
+    EntryPoint::new(
+        String::from(EXECUTE_QUEUED_ACTION_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACTION_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_queued_action` entry point.
+pub fn cancel_queued_action() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_QUEUED_ACTION_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACTION_ID, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `queued_action` entry point.
+pub fn queued_action() -> EntryPoint {
+    EntryPoint::new(
+        String::from(QUEUED_ACTION_ENTRY_POINT_NAME),
+        vec![Parameter::new(ACTION_ID, u64::cl_type())],
+        <((u8, Bytes, u64), (u8, bool, Key))>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `queued_actions` entry point.
+pub fn queued_actions() -> EntryPoint {
+    EntryPoint::new(
+        String::from(QUEUED_ACTIONS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OFFSET, u32::cl_type()),
+            Parameter::new(LIMIT, u32::cl_type()),
+        ],
+        Vec::<(u64, ((u8, Bytes, u64), (u8, bool, Key)))>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `queued_action_count` entry point.
+pub fn queued_action_count() -> EntryPoint {
+    EntryPoint::new(
+        String::from(QUEUED_ACTION_COUNT_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `guardians` entry point.
+pub fn guardians() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GUARDIANS_ENTRY_POINT_NAME),
+        Vec::new(),
+        Vec::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_guardian` entry point.
+pub fn is_guardian() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_GUARDIAN_ENTRY_POINT_NAME),
+        vec![Parameter::new(KEY, Key::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_guardians` entry point.
+pub fn set_guardians() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_GUARDIANS_ENTRY_POINT_NAME),
+        vec![Parameter::new(GUARDIANS, Vec::<Key>::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `guardian_pause` entry point.
+pub fn guardian_pause() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GUARDIAN_PAUSE_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `guardian_revoke_minter_allowance` entry point.
+pub fn guardian_revoke_minter_allowance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GUARDIAN_REVOKE_MINTER_ALLOWANCE_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `guardian_remove_minter` entry point.
+pub fn guardian_remove_minter() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GUARDIAN_REMOVE_MINTER_ENTRY_POINT_NAME),
+        vec![Parameter::new(MINTER, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `controller` entry point.
+pub fn controller() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONTROLLER_ENTRY_POINT_NAME),
+        Vec::new(),
+        Key::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `update_controller` entry point.
+pub fn update_controller() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UPDATE_CONTROLLER_ENTRY_POINT_NAME),
+        vec![Parameter::new(NEW, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_controllable` entry point.
+pub fn is_controllable() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_CONTROLLABLE_ENTRY_POINT_NAME),
+        Vec::new(),
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `disable_controller` entry point.
+pub fn disable_controller() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DISABLE_CONTROLLER_ENTRY_POINT_NAME),
+        Vec::new(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `controller_transfer` entry point.
+pub fn controller_transfer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CONTROLLER_TRANSFER_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(TO, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(LEGAL_REFERENCE, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `rescuable_balance` entry point.
+pub fn rescuable_balance() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RESCUABLE_BALANCE_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `rescue_tokens` entry point.
+pub fn rescue_tokens() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RESCUE_TOKENS_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(TO, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `authorize_upgrade` entry point.
+pub fn authorize_upgrade() -> EntryPoint {
+    EntryPoint::new(
+        String::from(AUTHORIZE_UPGRADE_ENTRY_POINT_NAME),
+        vec![Parameter::new(UPGRADER, Key::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `upgrade_access` entry point.
+pub fn upgrade_access() -> EntryPoint {
+    EntryPoint::new(
+        String::from(UPGRADE_ACCESS_ENTRY_POINT_NAME),
+        Vec::new(),
+        <(URef, bool)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `detect_transfer_restriction` entry point.
+pub fn detect_transfer_restriction() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DETECT_TRANSFER_RESTRICTION_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(FROM, Key::cl_type()),
+            Parameter::new(TO, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `message_for_transfer_restriction` entry point.
+pub fn message_for_transfer_restriction() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MESSAGE_FOR_TRANSFER_RESTRICTION_ENTRY_POINT_NAME),
+        vec![Parameter::new(CODE, u8::cl_type())],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `balance_of` entry point.
+pub fn balance_of() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BALANCE_OF_ENTRY_POINT_NAME),
+        vec![Parameter::new(ADDRESS, Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `total_supply` entry point.
+pub fn total_supply() -> EntryPoint {
+    EntryPoint::new(
+        String::from(TOTAL_SUPPLY_ENTRY_POINT_NAME),
+        Vec::new(),
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `decimals` entry point.
+pub fn decimals() -> EntryPoint {
+    EntryPoint::new(
+        String::from(DECIMALS_ENTRY_POINT_NAME),
+        Vec::new(),
+        u8::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the CEP-18 `burn` entry point.
+pub fn cep18_burn() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BURN_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the CEP-18 `change_security` entry point, whose `admin_list`, `minter_list` and
+/// `none_list` arguments are all optional.
+pub fn cep18_change_security() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CHANGE_SECURITY_ENTRY_POINT_NAME),
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the CEP-18 `mint` entry point.
+pub fn cep18_mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(OWNER, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `burn` entry point.
+pub fn burn() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BURN_ENTRY_POINT_NAME),
+        vec![Parameter::new(AMOUNT, U256::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `mint` entry point.
+pub fn mint() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `mint_with_reference` entry point.
+pub fn mint_with_reference() -> EntryPoint {
+    EntryPoint::new(
+        String::from(MINT_WITH_REFERENCE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RECIPIENT, Key::cl_type()),
+            Parameter::new(AMOUNT, U256::cl_type()),
+            Parameter::new(REFERENCE, String::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `is_mint_reference_consumed` entry point.
+pub fn is_mint_reference_consumed() -> EntryPoint {
+    EntryPoint::new(
+        String::from(IS_MINT_REFERENCE_CONSUMED_ENTRY_POINT_NAME),
+        vec![Parameter::new(REFERENCE, String::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `reserve_oracle` entry point.
+pub fn reserve_oracle() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RESERVE_ORACLE_ENTRY_POINT_NAME),
+        Vec::new(),
+        Option::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `reserve_max_age` entry point.
+pub fn reserve_max_age() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RESERVE_MAX_AGE_ENTRY_POINT_NAME),
+        Vec::new(),
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_reserve_oracle` entry point.
+pub fn set_reserve_oracle() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_RESERVE_ORACLE_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(ORACLE, Option::<Key>::cl_type()),
+            Parameter::new(MAX_AGE, u64::cl_type()),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -244,6 +1654,8 @@ pub fn init() -> EntryPoint {
         vec![
             Parameter::new(PACKAGE_HASH, Key::cl_type()),
             Parameter::new(MASTER_MINTER, Key::cl_type()),
+            Parameter::new(MINTER_LIST, Vec::<Key>::cl_type()),
+            Parameter::new(TOTAL_SUPPLY, U256::cl_type()),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -385,7 +1797,9 @@ pub fn update_master_minter() -> EntryPoint {
 }
 
 /// Returns the default set of cspr USD token entry points.
-pub fn generate_entry_points() -> EntryPoints {
+/// Returns the contract's entry points, declaring `mint` and `burn` with CEP-18's arguments and
+/// adding `change_security` when `cep18_compatible`.
+pub fn generate_entry_points(cep18_compatible: bool) -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(init());
     entry_points.add_entry_point(name());
@@ -400,12 +1814,28 @@ pub fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(balance_of());
     entry_points.add_entry_point(transfer());
     entry_points.add_entry_point(approve());
+    entry_points.add_entry_point(approve_if());
+    entry_points.add_entry_point(approve_with_expiry());
+    entry_points.add_entry_point(allowance_details());
+    entry_points.add_entry_point(spenders_of());
+    entry_points.add_entry_point(revoke_all_allowances());
     entry_points.add_entry_point(allowance());
     entry_points.add_entry_point(decrease_allowance());
     entry_points.add_entry_point(increase_allowance());
     entry_points.add_entry_point(transfer_from());
-    entry_points.add_entry_point(burn());
-    entry_points.add_entry_point(mint());
+    if cep18_compatible {
+        entry_points.add_entry_point(cep18_burn());
+        entry_points.add_entry_point(cep18_mint());
+        entry_points.add_entry_point(cep18_change_security());
+    } else {
+        entry_points.add_entry_point(burn());
+        entry_points.add_entry_point(mint());
+    }
+    entry_points.add_entry_point(mint_with_reference());
+    entry_points.add_entry_point(is_mint_reference_consumed());
+    entry_points.add_entry_point(reserve_oracle());
+    entry_points.add_entry_point(reserve_max_age());
+    entry_points.add_entry_point(set_reserve_oracle());
     entry_points.add_entry_point(pause_contract());
     entry_points.add_entry_point(unpause_contract());
     entry_points.add_entry_point(update_pauser());
@@ -419,6 +1849,104 @@ pub fn generate_entry_points() -> EntryPoints {
     entry_points.add_entry_point(un_blacklist());
     entry_points.add_entry_point(update_blacklister());
     entry_points.add_entry_point(update_master_minter());
+    entry_points.add_entry_point(detect_transfer_restriction());
+    entry_points.add_entry_point(message_for_transfer_restriction());
+    entry_points.add_entry_point(transfer_with_memo());
+    entry_points.add_entry_point(transfer_from_with_memo());
+    entry_points.add_entry_point(max_memo_length());
+    entry_points.add_entry_point(set_max_memo_length());
+    entry_points.add_entry_point(fee_bps());
+    entry_points.add_entry_point(max_fee());
+    entry_points.add_entry_point(fee_recipient());
+    entry_points.add_entry_point(is_fee_exempt());
+    entry_points.add_entry_point(quote_fee());
+    entry_points.add_entry_point(set_fee());
+    entry_points.add_entry_point(set_fee_recipient());
+    entry_points.add_entry_point(set_fee_exempt());
+    entry_points.add_entry_point(snapshotter());
+    entry_points.add_entry_point(update_snapshotter());
+    entry_points.add_entry_point(snapshot());
+    entry_points.add_entry_point(current_snapshot_id());
+    entry_points.add_entry_point(balance_of_at());
+    entry_points.add_entry_point(total_supply_at());
+    entry_points.add_entry_point(get_past_balance());
+    entry_points.add_entry_point(get_past_total_supply());
+    entry_points.add_entry_point(max_flash_loan());
+    entry_points.add_entry_point(flash_fee());
+    entry_points.add_entry_point(set_flash_loan_config());
+    entry_points.add_entry_point(flash_loan());
+    entry_points.add_entry_point(create_escrow());
+    entry_points.add_entry_point(release_escrow());
+    entry_points.add_entry_point(refund_escrow());
+    entry_points.add_entry_point(resolve_escrow());
+    entry_points.add_entry_point(escrow());
+    entry_points.add_entry_point(escrow_status());
+    entry_points.add_entry_point(escrow_count());
+    entry_points.add_entry_point(vesting_admin());
+    entry_points.add_entry_point(update_vesting_admin());
+    entry_points.add_entry_point(create_vesting_schedule());
+    entry_points.add_entry_point(release_vested());
+    entry_points.add_entry_point(revoke_vesting());
+    entry_points.add_entry_point(vesting_schedule());
+    entry_points.add_entry_point(vesting_schedule_count());
+    entry_points.add_entry_point(vested_amount());
+    entry_points.add_entry_point(released_amount());
+    entry_points.add_entry_point(releasable_amount());
+    entry_points.add_entry_point(create_stream());
+    entry_points.add_entry_point(withdraw_from_stream());
+    entry_points.add_entry_point(cancel_stream());
+    entry_points.add_entry_point(stream_balance());
+    entry_points.add_entry_point(stream_count());
+    entry_points.add_entry_point(holder_count());
+    entry_points.add_entry_point(holders());
+    entry_points.add_entry_point(bridge_operator());
+    entry_points.add_entry_point(update_bridge_operator());
+    entry_points.add_entry_point(set_bridge_mint_cap());
+    entry_points.add_entry_point(bridge_burn());
+    entry_points.add_entry_point(bridge_mint());
+    entry_points.add_entry_point(bridge_mint_cap());
+    entry_points.add_entry_point(bridge_minted());
+    entry_points.add_entry_point(bridge_nonce());
+    entry_points.add_entry_point(is_bridge_nonce_consumed());
+    entry_points.add_entry_point(attestor());
+    entry_points.add_entry_point(update_attestor());
+    entry_points.add_entry_point(publish_attestation());
+    entry_points.add_entry_point(attestation());
+    entry_points.add_entry_point(latest_attestation());
+    entry_points.add_entry_point(attestation_count());
+    entry_points.add_entry_point(multisig_signers());
+    entry_points.add_entry_point(multisig_threshold());
+    entry_points.add_entry_point(configure_multisig());
+    entry_points.add_entry_point(propose());
+    entry_points.add_entry_point(approve_proposal());
+    entry_points.add_entry_point(revoke_approval());
+    entry_points.add_entry_point(execute_proposal());
+    entry_points.add_entry_point(proposal());
+    entry_points.add_entry_point(proposal_approvals());
+    entry_points.add_entry_point(proposal_count());
+    entry_points.add_entry_point(timelock_delay());
+    entry_points.add_entry_point(set_timelock_delay());
+    entry_points.add_entry_point(queue_action());
+    entry_points.add_entry_point(execute_queued_action());
+    entry_points.add_entry_point(cancel_queued_action());
+    entry_points.add_entry_point(queued_action());
+    entry_points.add_entry_point(queued_actions());
+    entry_points.add_entry_point(queued_action_count());
+    entry_points.add_entry_point(guardians());
+    entry_points.add_entry_point(is_guardian());
+    entry_points.add_entry_point(set_guardians());
+    entry_points.add_entry_point(guardian_pause());
+    entry_points.add_entry_point(guardian_revoke_minter_allowance());
+    entry_points.add_entry_point(guardian_remove_minter());
+    entry_points.add_entry_point(controller());
+    entry_points.add_entry_point(update_controller());
+    entry_points.add_entry_point(is_controllable());
+    entry_points.add_entry_point(disable_controller());
+    entry_points.add_entry_point(controller_transfer());
+    entry_points.add_entry_point(rescuable_balance());
+    entry_points.add_entry_point(rescue_tokens());
+    entry_points.add_entry_point(authorize_upgrade());
+    entry_points.add_entry_point(upgrade_access());
 
     entry_points
 }
@@ -67,6 +67,144 @@ pub enum CsprUSDError {
     AlreadyBlacklisted = 65018,
     /// Key is not blacklisted
     NotBlacklisted = 65019,
+    /// Memo exceeds the configured maximum length
+    MemoTooLong = 65020,
+    /// Fee can't exceed 10_000 basis points
+    InvalidFeeBps = 65021,
+    /// Operation disallowed because account is not the snapshotter
+    NotSnapshotter = 65022,
+    /// Snapshot id is zero or hasn't been taken yet
+    InvalidSnapshotId = 65023,
+    /// Flash loan amount exceeds the configured maximum
+    ExceedsMaxFlashLoan = 65024,
+    /// Flash loan receiver didn't hold the loan plus fee after its callback
+    FlashLoanNotRepaid = 65025,
+    /// No escrow exists with the given id
+    EscrowNotFound = 65026,
+    /// Escrow was already released or refunded
+    EscrowNotPending = 65027,
+    /// Operation disallowed because account is not a party to the escrow
+    NotEscrowParty = 65028,
+    /// Creator can't refund an escrow before its release time
+    EscrowNotExpired = 65029,
+    /// Operation disallowed because account is not the escrow's arbiter
+    NotArbiter = 65030,
+    /// Operation disallowed because account is not the vesting admin
+    NotVestingAdmin = 65031,
+    /// No vesting schedule exists with the given id
+    VestingScheduleNotFound = 65032,
+    /// Vesting duration is zero or shorter than the cliff
+    InvalidVestingSchedule = 65033,
+    /// Nothing has vested since the last release
+    NothingToRelease = 65034,
+    /// Operation disallowed because account is not the schedule's beneficiary
+    NotBeneficiary = 65035,
+    /// Operation disallowed because account is not the schedule's creator
+    NotVestingCreator = 65036,
+    /// Schedule is not revocable or was already revoked
+    VestingNotRevocable = 65037,
+    /// Stream times are invalid or the deposit doesn't match the rate
+    InvalidStream = 65038,
+    /// No stream exists with the given id
+    StreamNotFound = 65039,
+    /// Stream was already cancelled
+    StreamNotActive = 65040,
+    /// Operation disallowed because account is not a party to the stream
+    NotStreamParty = 65041,
+    /// Withdrawal exceeds the amount streamed to the recipient so far
+    ExceedsStreamBalance = 65042,
+    /// Allowance expiry must lie in the future
+    InvalidExpiry = 65043,
+    /// Current allowance differs from the one expected by `approve_if`
+    AllowanceMismatch = 65044,
+    /// Holder registry was disabled at install time
+    HolderRegistryDisabled = 65045,
+    /// Operation disallowed because account is not the bridge operator
+    NotBridgeOperator = 65046,
+    /// Bridge message from the source chain was already minted
+    BridgeNonceConsumed = 65047,
+    /// Mint would exceed the source chain's bridge mint cap
+    ExceedsBridgeMintCap = 65048,
+    /// Deposit reference was already used to mint
+    DuplicateMintReference = 65049,
+    /// Reserve oracle report is older than the configured maximum age
+    StaleReserves = 65050,
+    /// Mint would push the total supply above the reported reserves
+    InsufficientReserves = 65051,
+    /// Operation disallowed because account is not the attestor
+    NotAttestor = 65052,
+    /// No attestation exists at the given index
+    AttestationNotFound = 65053,
+    /// Operation disallowed because account is not a multisig signer
+    NotMultisigSigner = 65054,
+    /// Signers must be distinct and able to reach the threshold
+    InvalidMultisigConfig = 65055,
+    /// No proposal exists with the given id
+    ProposalNotFound = 65056,
+    /// Proposal expired or its expiry lies in the past
+    ProposalExpired = 65057,
+    /// Proposal was already executed
+    ProposalAlreadyExecuted = 65058,
+    /// Signer already approved the proposal
+    AlreadyApproved = 65059,
+    /// Signer has not approved the proposal
+    NotApproved = 65060,
+    /// Proposal lacks approvals from enough signers
+    ThresholdNotReached = 65061,
+    /// Unknown owner action or malformed action data
+    InvalidOwnerAction = 65062,
+    /// Owner action must be queued in the timelock
+    TimelockRequired = 65063,
+    /// Timelock delay exceeds the maximum
+    InvalidTimelockDelay = 65064,
+    /// Queued action's eta is earlier than the timelock delay allows
+    InvalidEta = 65065,
+    /// No queued action exists with the given id
+    QueuedActionNotFound = 65066,
+    /// Queued action was already executed or cancelled
+    QueuedActionNotPending = 65067,
+    /// Queued action's eta has not passed yet
+    QueuedActionNotReady = 65068,
+    /// Operation disallowed because account is not a guardian
+    NotGuardian = 65069,
+    /// Guardian set has duplicates or too many guardians
+    InvalidGuardians = 65070,
+    /// Operation disallowed because account is not the controller
+    NotController = 65071,
+    /// Controller transfers were permanently disabled
+    ControllerDisabled = 65072,
+    /// Tokens held in custody can't be moved this way
+    CustodyFundsLocked = 65073,
+    /// Amount exceeds the tokens stranded at the contract's own package
+    ExceedsRescuableBalance = 65074,
+    /// Tokens can't be sent to the contract's own package
+    CannotTransferToContract = 65075,
+    /// CEP-18 `burn` can only burn the caller's own tokens
+    InvalidBurnTarget = 65076,
+    /// Events schema version is unknown or can't be combined with CEP-18 compatibility
+    UnsupportedEventsSchema = 65077,
+    /// Owner actions must go through a multisig proposal once a multisig is configured
+    MultisigRequired = 65078,
+    /// Actions queued by a multisig proposal can only be cancelled by another proposal
+    QueuedByMultisig = 65079,
+    /// Past values can only be looked up for timestamps before the current block
+    LookupNotInPast = 65080,
+    /// CEP-18 admin badges have no counterpart in csprUSD's roles
+    UnsupportedSecurityBadge = 65081,
+    /// CEP-18 events mode other than the Casper Event Standard
+    UnsupportedEventsMode = 65082,
+    /// Upgrades need an `authorize_upgrade` naming the upgrading account
+    UpgradeNotAuthorized = 65083,
+    /// Queued action not executed within the grace period after its eta
+    QueuedActionExpired = 65084,
+    /// Flash loan receiver's `on_flash_loan` didn't return the ERC-3156 success value
+    FlashLoanCallbackFailed = 65085,
+    /// Escrow release time must be in the future
+    EscrowReleaseTimeNotInFuture = 65086,
+    /// Escrow arbiter can't be the creator or the recipient
+    InvalidEscrowArbiter = 65087,
+    /// Attestation period or uri exceeds its maximum length
+    AttestationFieldTooLong = 65088,
 }
 
 impl From<CsprUSDError> for ApiError {
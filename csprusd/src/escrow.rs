@@ -0,0 +1,129 @@
+//! Time-locked escrow transfers held in contract custody.
+//!
+//! Before `release_time` the creator may release the funds to the recipient, and the recipient may
+//! send them back to the creator. Once `release_time` has passed without a release, the creator may
+//! take a refund. An optional arbiter can resolve the escrow either way at any time.
+//!
+//! `release_time` is therefore the creator's refund deadline rather than a vesting date: the
+//! recipient can never claim the funds on its own, a release always needs the creator or the
+//! arbiter. The release time must lie in the future and the arbiter must be a third party.
+use alloc::string::ToString;
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{ESCROWS, ESCROW_COUNT},
+    custody::{lock_in_custody, release_from_custody},
+    error::CsprUSDError,
+    utils::{get_uref, read_from, write_to},
+};
+
+/// Lifecycle of an escrow.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EscrowStatus {
+    Pending = 0,
+    Released = 1,
+    Refunded = 2,
+}
+
+impl EscrowStatus {
+    fn from_u8(status: u8) -> Self {
+        match status {
+            0 => EscrowStatus::Pending,
+            1 => EscrowStatus::Released,
+            2 => EscrowStatus::Refunded,
+            _ => revert(CsprUSDError::Phantom),
+        }
+    }
+}
+
+/// Stored form of an escrow: `((creator, recipient, arbiter), (amount, release_time, status))`.
+pub(crate) type EscrowRecord = ((Key, Key, Option<Key>), (U256, u64, u8));
+
+pub(crate) struct Escrow {
+    pub(crate) creator: Key,
+    pub(crate) recipient: Key,
+    pub(crate) arbiter: Option<Key>,
+    pub(crate) amount: U256,
+    pub(crate) release_time: u64,
+    pub(crate) status: EscrowStatus,
+}
+
+impl Escrow {
+    fn to_record(&self) -> EscrowRecord {
+        (
+            (self.creator, self.recipient, self.arbiter),
+            (self.amount, self.release_time, self.status as u8),
+        )
+    }
+
+    /// Returns who gets paid when the escrow is settled with `status`.
+    pub(crate) fn payee(&self, status: EscrowStatus) -> Key {
+        match status {
+            EscrowStatus::Released => self.recipient,
+            EscrowStatus::Refunded => self.creator,
+            EscrowStatus::Pending => revert(CsprUSDError::Phantom),
+        }
+    }
+
+    fn from_record(record: EscrowRecord) -> Self {
+        let ((creator, recipient, arbiter), (amount, release_time, status)) = record;
+        Escrow {
+            creator,
+            recipient,
+            arbiter,
+            amount,
+            release_time,
+            status: EscrowStatus::from_u8(status),
+        }
+    }
+}
+
+pub(crate) fn escrow_count_util() -> u64 {
+    read_from(ESCROW_COUNT)
+}
+
+pub(crate) fn read_escrow_record(escrow_id: u64) -> EscrowRecord {
+    storage::dictionary_get(get_uref(ESCROWS), &escrow_id.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(CsprUSDError::EscrowNotFound)
+}
+
+pub(crate) fn read_escrow(escrow_id: u64) -> Escrow {
+    Escrow::from_record(read_escrow_record(escrow_id))
+}
+
+fn write_escrow(escrow_id: u64, escrow: &Escrow) {
+    storage::dictionary_put(
+        get_uref(ESCROWS),
+        &escrow_id.to_string(),
+        escrow.to_record(),
+    );
+}
+
+/// Moves `escrow.amount` from the creator into custody and stores the escrow under a new id.
+pub(crate) fn create_escrow_util(escrow: Escrow) -> u64 {
+    lock_in_custody(escrow.creator, escrow.amount).unwrap_or_revert();
+
+    let escrow_id = escrow_count_util() + 1;
+    write_escrow(escrow_id, &escrow);
+    write_to(ESCROW_COUNT, escrow_id);
+    escrow_id
+}
+
+/// Pays a pending escrow out to its recipient (`Released`) or its creator (`Refunded`).
+pub(crate) fn settle_escrow(escrow_id: u64, mut escrow: Escrow, status: EscrowStatus) {
+    if escrow.status != EscrowStatus::Pending {
+        revert(CsprUSDError::EscrowNotPending);
+    }
+
+    let payee = escrow.payee(status);
+    escrow.status = status;
+    write_escrow(escrow_id, &escrow);
+    release_from_custody(payee, escrow.amount).unwrap_or_revert();
+}
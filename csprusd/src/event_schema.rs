@@ -0,0 +1,468 @@
+//! Versioning of the events schema, selected at install.
+//!
+//! Version 1 events carry only the new state of what changed. A token installed with
+//! `events_schema_version` 2 emits the `v2` layouts instead, which add the previous value or the
+//! resulting balances, the acting caller and the block time. Version 2 covers:
+//!
+//! - the role changes: pauser, blacklister, master minter, owner, snapshotter, vesting admin,
+//!   bridge operator, attestor, controller and fee recipient;
+//! - the fee and flash loan configuration changes;
+//! - the events of transfers, mints, burns, fees, flash loans and controller transfers.
+//!
+//! The caller of a configuration change is the account that authorized it: the owner, the signer
+//! executing a multisig proposal, or for an action executed through the timelock the account that
+//! queued it, rather than whoever triggered the permissionless execution.
+//!
+//! Other events, including those of escrows, vesting, streams, bridging and the multisig, keep
+//! their version 1 layout. Version 2 can't be combined with CEP-18 compatibility, which fixes the
+//! layout of `Transfer`, `TransferFrom`, `Mint` and `Burn`: installing with both reverts with
+//! `UnsupportedEventsSchema`.
+use alloc::string::String;
+
+use casper_contract::contract_api::runtime::revert;
+use casper_types::{Key, PublicKey, U256};
+
+use crate::{
+    allowances::{get_allowances_uref, read_allowance_from},
+    balances::{get_balances_uref, read_balance_from},
+    constants::EVENTS_SCHEMA_VERSION,
+    error::CsprUSDError,
+    events::{
+        self, v2, AttestorChanged, BlacklisterChanged, BridgeOperatorChanged, Burn,
+        ControllerChanged, ControllerTransfer, Event, FeeChanged, FeeCharged, FeeRecipientChanged,
+        FlashLoan, FlashLoanConfigChanged, MasterMinterChanged, Mint, NewPauser,
+        OwnershipTransferred, SnapshotterChanged, Transfer, TransferFrom, TransferWithMemo,
+        VestingAdminChanged,
+    },
+    utils::{get_total_supply_uref, now, read_from, read_total_supply_from},
+};
+
+pub(crate) const EVENTS_SCHEMA_V1: u8 = 1;
+pub(crate) const EVENTS_SCHEMA_V2: u8 = 2;
+
+pub(crate) fn events_schema_version() -> u8 {
+    read_from(EVENTS_SCHEMA_VERSION)
+}
+
+/// Reverts if a token can't be installed with `version` and the given CEP-18 compatibility.
+pub(crate) fn assert_supported_events_schema(version: u8, cep18_compatible: bool) {
+    let supported = match version {
+        EVENTS_SCHEMA_V1 => true,
+        EVENTS_SCHEMA_V2 => !cep18_compatible,
+        _ => false,
+    };
+    if !supported {
+        revert(CsprUSDError::UnsupportedEventsSchema);
+    }
+}
+
+fn v2_events_enabled() -> bool {
+    events_schema_version() == EVENTS_SCHEMA_V2
+}
+
+pub(crate) fn emit_pauser_changed(previous_pauser: PublicKey, new_pauser: PublicKey, caller: Key) {
+    if v2_events_enabled() {
+        events::emit_event(Event::PauserChangedV2(v2::NewPauser {
+            previous_pauser,
+            new_pauser,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::PauserChanged(NewPauser { new_pauser }));
+    }
+}
+
+pub(crate) fn emit_blacklister_changed(
+    previous_blacklister: PublicKey,
+    new_blacklister: PublicKey,
+    caller: Key,
+) {
+    if v2_events_enabled() {
+        events::emit_event(Event::BlacklisterChangedV2(v2::BlacklisterChanged {
+            previous_blacklister,
+            new_blacklister,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::BlacklisterChanged(BlacklisterChanged {
+            new_blacklister,
+        }));
+    }
+}
+
+pub(crate) fn emit_master_minter_changed(
+    previous_master_minter: Key,
+    new_master_minter: Key,
+    caller: Key,
+) {
+    if v2_events_enabled() {
+        events::emit_event(Event::MasterMinterChangedV2(v2::MasterMinterChanged {
+            previous_master_minter,
+            new_master_minter,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::MasterMinterChanged(MasterMinterChanged {
+            new_master_minter,
+        }));
+    }
+}
+
+pub(crate) fn emit_ownership_transferred(previous_owner: Key, new_owner: Key, caller: Key) {
+    if v2_events_enabled() {
+        events::emit_event(Event::OwnershipTransferredV2(v2::OwnershipTransferred {
+            previous_owner,
+            new_owner,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::OwnershipTransferred(OwnershipTransferred {
+            new_owner,
+        }));
+    }
+}
+
+/// Emits `Transfer`, which must follow the transfer so that v2 reports the resulting balances.
+pub(crate) fn emit_transfer(sender: Key, recipient: Key, amount: U256) {
+    if v2_events_enabled() {
+        let balances_uref = get_balances_uref();
+        events::emit_event(Event::TransferV2(v2::Transfer {
+            sender,
+            recipient,
+            amount,
+            sender_balance: read_balance_from(balances_uref, sender),
+            recipient_balance: read_balance_from(balances_uref, recipient),
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::Transfer(Transfer {
+            sender,
+            recipient,
+            amount,
+        }));
+    }
+}
+
+/// Emits `TransferFrom`, which must follow the transfer so that v2 reports the resulting balances
+/// and allowance.
+pub(crate) fn emit_transfer_from(spender: Key, owner: Key, recipient: Key, amount: U256) {
+    if v2_events_enabled() {
+        let balances_uref = get_balances_uref();
+        events::emit_event(Event::TransferFromV2(v2::TransferFrom {
+            spender,
+            owner,
+            recipient,
+            amount,
+            owner_balance: read_balance_from(balances_uref, owner),
+            recipient_balance: read_balance_from(balances_uref, recipient),
+            remaining_allowance: read_allowance_from(get_allowances_uref(), owner, spender),
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::TransferFrom(TransferFrom {
+            spender,
+            owner,
+            recipient,
+            amount,
+        }));
+    }
+}
+
+pub(crate) fn emit_snapshotter_changed(
+    previous_snapshotter: Key,
+    new_snapshotter: Key,
+    caller: Key,
+) {
+    if v2_events_enabled() {
+        events::emit_event(Event::SnapshotterChangedV2(v2::SnapshotterChanged {
+            previous_snapshotter,
+            new_snapshotter,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::SnapshotterChanged(SnapshotterChanged {
+            new_snapshotter,
+        }));
+    }
+}
+
+pub(crate) fn emit_fee_recipient_changed(
+    previous_fee_recipient: Key,
+    new_fee_recipient: Key,
+    caller: Key,
+) {
+    if v2_events_enabled() {
+        events::emit_event(Event::FeeRecipientChangedV2(v2::FeeRecipientChanged {
+            previous_fee_recipient,
+            new_fee_recipient,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::FeeRecipientChanged(FeeRecipientChanged {
+            new_fee_recipient,
+        }));
+    }
+}
+
+pub(crate) fn emit_vesting_admin_changed(
+    previous_vesting_admin: Key,
+    new_vesting_admin: Key,
+    caller: Key,
+) {
+    if v2_events_enabled() {
+        events::emit_event(Event::VestingAdminChangedV2(v2::VestingAdminChanged {
+            previous_vesting_admin,
+            new_vesting_admin,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::VestingAdminChanged(VestingAdminChanged {
+            new_vesting_admin,
+        }));
+    }
+}
+
+pub(crate) fn emit_bridge_operator_changed(
+    previous_bridge_operator: Key,
+    new_bridge_operator: Key,
+    caller: Key,
+) {
+    if v2_events_enabled() {
+        events::emit_event(Event::BridgeOperatorChangedV2(v2::BridgeOperatorChanged {
+            previous_bridge_operator,
+            new_bridge_operator,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::BridgeOperatorChanged(BridgeOperatorChanged {
+            new_bridge_operator,
+        }));
+    }
+}
+
+pub(crate) fn emit_attestor_changed(previous_attestor: Key, new_attestor: Key, caller: Key) {
+    if v2_events_enabled() {
+        events::emit_event(Event::AttestorChangedV2(v2::AttestorChanged {
+            previous_attestor,
+            new_attestor,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::AttestorChanged(AttestorChanged { new_attestor }));
+    }
+}
+
+pub(crate) fn emit_controller_changed(previous_controller: Key, new_controller: Key, caller: Key) {
+    if v2_events_enabled() {
+        events::emit_event(Event::ControllerChangedV2(v2::ControllerChanged {
+            previous_controller,
+            new_controller,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::ControllerChanged(ControllerChanged {
+            new_controller,
+        }));
+    }
+}
+
+/// Emits `FeeChanged`, where `previous` is the `(fee_bps, max_fee)` that was replaced.
+pub(crate) fn emit_fee_changed(previous: (u32, U256), fee_bps: u32, max_fee: U256, caller: Key) {
+    if v2_events_enabled() {
+        let (previous_fee_bps, previous_max_fee) = previous;
+        events::emit_event(Event::FeeChangedV2(v2::FeeChanged {
+            previous_fee_bps,
+            previous_max_fee,
+            fee_bps,
+            max_fee,
+            caller,
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::FeeChanged(FeeChanged { fee_bps, max_fee }));
+    }
+}
+
+/// Emits `FlashLoanConfigChanged`, where `previous` is the `(flash_fee_bps, max_flash_loan)` that
+/// was replaced.
+pub(crate) fn emit_flash_loan_config_changed(
+    previous: (u32, U256),
+    flash_fee_bps: u32,
+    max_flash_loan: U256,
+    caller: Key,
+) {
+    if v2_events_enabled() {
+        let (previous_flash_fee_bps, previous_max_flash_loan) = previous;
+        events::emit_event(Event::FlashLoanConfigChangedV2(
+            v2::FlashLoanConfigChanged {
+                previous_flash_fee_bps,
+                previous_max_flash_loan,
+                flash_fee_bps,
+                max_flash_loan,
+                caller,
+                timestamp: now(),
+            },
+        ));
+    } else {
+        events::emit_event(Event::FlashLoanConfigChanged(FlashLoanConfigChanged {
+            flash_fee_bps,
+            max_flash_loan,
+        }));
+    }
+}
+
+/// Emits csprUSD's own `Mint`, which must follow the mint so that v2 reports the resulting balance
+/// and supply.
+pub(crate) fn emit_mint(minter: Key, recipient: Key, amount: U256, reference: Option<String>) {
+    if v2_events_enabled() {
+        events::emit_event(Event::MintV2(v2::Mint {
+            minter,
+            recipient,
+            amount,
+            reference,
+            recipient_balance: read_balance_from(get_balances_uref(), recipient),
+            total_supply: read_total_supply_from(get_total_supply_uref()),
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::Mint(Mint {
+            minter,
+            recipient,
+            amount,
+            reference,
+        }));
+    }
+}
+
+/// Emits csprUSD's own `Burn`, which must follow the burn so that v2 reports the resulting balance
+/// and supply.
+pub(crate) fn emit_burn(minter: Key, amount: U256) {
+    if v2_events_enabled() {
+        events::emit_event(Event::BurnV2(v2::Burn {
+            minter,
+            amount,
+            minter_balance: read_balance_from(get_balances_uref(), minter),
+            total_supply: read_total_supply_from(get_total_supply_uref()),
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::Burn(Burn { minter, amount }));
+    }
+}
+
+/// Emits `TransferWithMemo`, which must follow the transfer so that v2 reports the resulting
+/// balances.
+pub(crate) fn emit_transfer_with_memo(
+    spender: Option<Key>,
+    sender: Key,
+    recipient: Key,
+    amount: U256,
+    memo: String,
+) {
+    if v2_events_enabled() {
+        let balances_uref = get_balances_uref();
+        events::emit_event(Event::TransferWithMemoV2(v2::TransferWithMemo {
+            spender,
+            sender,
+            recipient,
+            amount,
+            memo,
+            sender_balance: read_balance_from(balances_uref, sender),
+            recipient_balance: read_balance_from(balances_uref, recipient),
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::TransferWithMemo(TransferWithMemo {
+            spender,
+            sender,
+            recipient,
+            amount,
+            memo,
+        }));
+    }
+}
+
+/// Emits `FeeCharged`, which must follow the fee transfer so that v2 reports the resulting
+/// balances.
+pub(crate) fn emit_fee_charged(payer: Key, fee_recipient: Key, fee: U256) {
+    if v2_events_enabled() {
+        let balances_uref = get_balances_uref();
+        events::emit_event(Event::FeeChargedV2(v2::FeeCharged {
+            payer,
+            fee_recipient,
+            fee,
+            payer_balance: read_balance_from(balances_uref, payer),
+            fee_recipient_balance: read_balance_from(balances_uref, fee_recipient),
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::FeeCharged(FeeCharged {
+            payer,
+            fee_recipient,
+            fee,
+        }));
+    }
+}
+
+/// Emits `FlashLoan`, which must follow the repayment so that v2 reports the resulting balance.
+pub(crate) fn emit_flash_loan(initiator: Key, receiver: Key, amount: U256, fee: U256) {
+    if v2_events_enabled() {
+        events::emit_event(Event::FlashLoanV2(v2::FlashLoan {
+            initiator,
+            receiver,
+            amount,
+            fee,
+            receiver_balance: read_balance_from(get_balances_uref(), receiver),
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::FlashLoan(FlashLoan {
+            initiator,
+            receiver,
+            amount,
+            fee,
+        }));
+    }
+}
+
+/// Emits `ControllerTransfer`, which must follow the transfer so that v2 reports the resulting
+/// balances.
+pub(crate) fn emit_controller_transfer(
+    controller: Key,
+    from: Key,
+    to: Key,
+    amount: U256,
+    legal_reference: String,
+) {
+    if v2_events_enabled() {
+        let balances_uref = get_balances_uref();
+        events::emit_event(Event::ControllerTransferV2(v2::ControllerTransfer {
+            controller,
+            from,
+            to,
+            amount,
+            legal_reference,
+            from_balance: read_balance_from(balances_uref, from),
+            to_balance: read_balance_from(balances_uref, to),
+            timestamp: now(),
+        }));
+    } else {
+        events::emit_event(Event::ControllerTransfer(ControllerTransfer {
+            controller,
+            from,
+            to,
+            amount,
+            legal_reference,
+        }));
+    }
+}
@@ -1,4 +1,6 @@
-use casper_types::{Key, PublicKey, U256};
+use alloc::{string::String, vec::Vec};
+
+use casper_types::{bytesrepr::Bytes, Key, PublicKey, U256};
 
 use casper_event_standard::{emit, Event, Schemas};
 
@@ -16,10 +18,81 @@ pub enum Event {
     MinterConfigured(MinterConfigured),
     MinterRemoved(MinterRemoved),
     SetAllowance(SetAllowance),
+    SetAllowanceWithExpiry(SetAllowanceWithExpiry),
     IncreaseAllowance(IncreaseAllowance),
     DecreaseAllowance(DecreaseAllowance),
     Transfer(Transfer),
     TransferFrom(TransferFrom),
+    TransferWithMemo(TransferWithMemo),
+    MaxMemoLengthChanged(MaxMemoLengthChanged),
+    FeeCharged(FeeCharged),
+    FeeChanged(FeeChanged),
+    FeeRecipientChanged(FeeRecipientChanged),
+    FeeExemptionChanged(FeeExemptionChanged),
+    Snapshot(Snapshot),
+    SnapshotterChanged(SnapshotterChanged),
+    FlashLoan(FlashLoan),
+    FlashLoanConfigChanged(FlashLoanConfigChanged),
+    EscrowCreated(EscrowCreated),
+    EscrowReleased(EscrowReleased),
+    EscrowRefunded(EscrowRefunded),
+    VestingAdminChanged(VestingAdminChanged),
+    VestingScheduleCreated(VestingScheduleCreated),
+    VestedReleased(VestedReleased),
+    VestingRevoked(VestingRevoked),
+    StreamCreated(StreamCreated),
+    StreamWithdrawn(StreamWithdrawn),
+    StreamCancelled(StreamCancelled),
+    BridgeOperatorChanged(BridgeOperatorChanged),
+    BridgeMintCapChanged(BridgeMintCapChanged),
+    BridgeBurn(BridgeBurn),
+    BridgeMint(BridgeMint),
+    ReserveOracleChanged(ReserveOracleChanged),
+    AttestorChanged(AttestorChanged),
+    AttestationPublished(AttestationPublished),
+    MultisigConfigured(MultisigConfigured),
+    ProposalCreated(ProposalCreated),
+    ProposalApproved(ProposalApproved),
+    ProposalApprovalRevoked(ProposalApprovalRevoked),
+    ProposalExecuted(ProposalExecuted),
+    TimelockDelayChanged(TimelockDelayChanged),
+    ActionQueued(ActionQueued),
+    QueuedActionExecuted(QueuedActionExecuted),
+    QueuedActionCancelled(QueuedActionCancelled),
+    GuardiansChanged(GuardiansChanged),
+    GuardianPause(GuardianPause),
+    GuardianMinterAllowanceRevoked(GuardianMinterAllowanceRevoked),
+    GuardianMinterRemoved(GuardianMinterRemoved),
+    ControllerChanged(ControllerChanged),
+    ControllerDisabled(ControllerDisabled),
+    ControllerTransfer(ControllerTransfer),
+    TokensRescued(TokensRescued),
+    UpgradeAuthorized(UpgradeAuthorized),
+    Cep18Mint(cep18::Mint),
+    Cep18Burn(cep18::Burn),
+    Cep18ChangeSecurity(cep18::ChangeSecurity),
+    MintDetails(MintDetails),
+    BurnDetails(BurnDetails),
+    PauserChangedV2(v2::NewPauser),
+    BlacklisterChangedV2(v2::BlacklisterChanged),
+    MasterMinterChangedV2(v2::MasterMinterChanged),
+    OwnershipTransferredV2(v2::OwnershipTransferred),
+    TransferV2(v2::Transfer),
+    TransferFromV2(v2::TransferFrom),
+    MintV2(v2::Mint),
+    BurnV2(v2::Burn),
+    TransferWithMemoV2(v2::TransferWithMemo),
+    FeeChargedV2(v2::FeeCharged),
+    FeeChangedV2(v2::FeeChanged),
+    FeeRecipientChangedV2(v2::FeeRecipientChanged),
+    SnapshotterChangedV2(v2::SnapshotterChanged),
+    FlashLoanV2(v2::FlashLoan),
+    FlashLoanConfigChangedV2(v2::FlashLoanConfigChanged),
+    VestingAdminChangedV2(v2::VestingAdminChanged),
+    BridgeOperatorChangedV2(v2::BridgeOperatorChanged),
+    AttestorChangedV2(v2::AttestorChanged),
+    ControllerChangedV2(v2::ControllerChanged),
+    ControllerTransferV2(v2::ControllerTransfer),
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
@@ -27,6 +100,7 @@ pub struct Mint {
     pub minter: Key,
     pub recipient: Key,
     pub amount: U256,
+    pub reference: Option<String>,
 }
 
 #[derive(Event, Debug, PartialEq, Eq)]
@@ -35,6 +109,255 @@ pub struct Burn {
     pub amount: U256,
 }
 
+/// Replaces `Mint` when CEP-18 compatibility is enabled.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MintDetails {
+    pub minter: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub reference: Option<String>,
+}
+
+/// Replaces `Burn` when CEP-18 compatibility is enabled.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BurnDetails {
+    pub minter: Key,
+    pub amount: U256,
+}
+
+/// Events of the CEP-18 standard that csprUSD has no event for, or whose schema differs from
+/// csprUSD's event of the same name.
+pub mod cep18 {
+    use alloc::collections::BTreeMap;
+
+    use casper_event_standard::Event;
+    use casper_types::{Key, U256};
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct Mint {
+        pub recipient: Key,
+        pub amount: U256,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct Burn {
+        pub owner: Key,
+        pub amount: U256,
+    }
+
+    /// `sec_change_map` holds CEP-18 `SecurityBadge` values.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct ChangeSecurity {
+        pub admin: Key,
+        pub sec_change_map: BTreeMap<Key, u8>,
+    }
+}
+
+/// Version 2 of the role change, configuration change and balance changing events, whose schema
+/// gained previous values, resulting balances, the acting caller and the block time. They keep
+/// their version 1 names, so a token registers one or the other depending on its
+/// `events_schema_version`.
+pub mod v2 {
+    use alloc::string::String;
+
+    use casper_event_standard::Event;
+    use casper_types::{Key, PublicKey, U256};
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct NewPauser {
+        pub previous_pauser: PublicKey,
+        pub new_pauser: PublicKey,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct BlacklisterChanged {
+        pub previous_blacklister: PublicKey,
+        pub new_blacklister: PublicKey,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct MasterMinterChanged {
+        pub previous_master_minter: Key,
+        pub new_master_minter: Key,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct OwnershipTransferred {
+        pub previous_owner: Key,
+        pub new_owner: Key,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    /// The sender is the caller.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct Transfer {
+        pub sender: Key,
+        pub recipient: Key,
+        pub amount: U256,
+        pub sender_balance: U256,
+        pub recipient_balance: U256,
+        pub timestamp: u64,
+    }
+
+    /// The spender is the caller.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct TransferFrom {
+        pub spender: Key,
+        pub owner: Key,
+        pub recipient: Key,
+        pub amount: U256,
+        pub owner_balance: U256,
+        pub recipient_balance: U256,
+        pub remaining_allowance: U256,
+        pub timestamp: u64,
+    }
+
+    /// The minter is the caller.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct Mint {
+        pub minter: Key,
+        pub recipient: Key,
+        pub amount: U256,
+        pub reference: Option<String>,
+        pub recipient_balance: U256,
+        pub total_supply: U256,
+        pub timestamp: u64,
+    }
+
+    /// The minter is the caller.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct Burn {
+        pub minter: Key,
+        pub amount: U256,
+        pub minter_balance: U256,
+        pub total_supply: U256,
+        pub timestamp: u64,
+    }
+
+    /// The spender, or the sender when there is none, is the caller.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct TransferWithMemo {
+        pub spender: Option<Key>,
+        pub sender: Key,
+        pub recipient: Key,
+        pub amount: U256,
+        pub memo: String,
+        pub sender_balance: U256,
+        pub recipient_balance: U256,
+        pub timestamp: u64,
+    }
+
+    /// Balances are the ones right after the fee was moved.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct FeeCharged {
+        pub payer: Key,
+        pub fee_recipient: Key,
+        pub fee: U256,
+        pub payer_balance: U256,
+        pub fee_recipient_balance: U256,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct FeeChanged {
+        pub previous_fee_bps: u32,
+        pub previous_max_fee: U256,
+        pub fee_bps: u32,
+        pub max_fee: U256,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct FeeRecipientChanged {
+        pub previous_fee_recipient: Key,
+        pub new_fee_recipient: Key,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct SnapshotterChanged {
+        pub previous_snapshotter: Key,
+        pub new_snapshotter: Key,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    /// The initiator is the caller.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct FlashLoan {
+        pub initiator: Key,
+        pub receiver: Key,
+        pub amount: U256,
+        pub fee: U256,
+        pub receiver_balance: U256,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct FlashLoanConfigChanged {
+        pub previous_flash_fee_bps: u32,
+        pub previous_max_flash_loan: U256,
+        pub flash_fee_bps: u32,
+        pub max_flash_loan: U256,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct VestingAdminChanged {
+        pub previous_vesting_admin: Key,
+        pub new_vesting_admin: Key,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct BridgeOperatorChanged {
+        pub previous_bridge_operator: Key,
+        pub new_bridge_operator: Key,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct AttestorChanged {
+        pub previous_attestor: Key,
+        pub new_attestor: Key,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct ControllerChanged {
+        pub previous_controller: Key,
+        pub new_controller: Key,
+        pub caller: Key,
+        pub timestamp: u64,
+    }
+
+    /// The controller is the caller.
+    #[derive(Event, Debug, PartialEq, Eq)]
+    pub struct ControllerTransfer {
+        pub controller: Key,
+        pub from: Key,
+        pub to: Key,
+        pub amount: U256,
+        pub legal_reference: String,
+        pub from_balance: U256,
+        pub to_balance: U256,
+        pub timestamp: u64,
+    }
+}
+
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct Pause {}
 
@@ -89,6 +412,14 @@ pub struct SetAllowance {
     pub allowance: U256,
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SetAllowanceWithExpiry {
+    pub owner: Key,
+    pub spender: Key,
+    pub allowance: U256,
+    pub expires_at: u64,
+}
+
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct IncreaseAllowance {
     pub owner: Key,
@@ -105,6 +436,7 @@ pub struct DecreaseAllowance {
     pub decr_by: U256,
 }
 
+/// `amount` is what the recipient received, after any fee.
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct Transfer {
     pub sender: Key,
@@ -112,6 +444,7 @@ pub struct Transfer {
     pub amount: U256,
 }
 
+/// `amount` is what the recipient received, after any fee.
 #[derive(Event, Debug, PartialEq, Eq)]
 pub struct TransferFrom {
     pub spender: Key,
@@ -120,6 +453,306 @@ pub struct TransferFrom {
     pub amount: U256,
 }
 
+/// Emitted after the `Transfer` or `TransferFrom` of a memo transfer.
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TransferWithMemo {
+    pub spender: Option<Key>,
+    pub sender: Key,
+    pub recipient: Key,
+    pub amount: U256,
+    pub memo: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MaxMemoLengthChanged {
+    pub new_max_memo_length: u32,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FeeCharged {
+    pub payer: Key,
+    pub fee_recipient: Key,
+    pub fee: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FeeChanged {
+    pub fee_bps: u32,
+    pub max_fee: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FeeRecipientChanged {
+    pub new_fee_recipient: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FeeExemptionChanged {
+    pub key: Key,
+    pub exempt: bool,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub snapshot_id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct SnapshotterChanged {
+    pub new_snapshotter: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FlashLoan {
+    pub initiator: Key,
+    pub receiver: Key,
+    pub amount: U256,
+    pub fee: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct FlashLoanConfigChanged {
+    pub flash_fee_bps: u32,
+    pub max_flash_loan: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct EscrowCreated {
+    pub escrow_id: u64,
+    pub creator: Key,
+    pub recipient: Key,
+    pub arbiter: Option<Key>,
+    pub amount: U256,
+    pub release_time: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct EscrowReleased {
+    pub escrow_id: u64,
+    pub caller: Key,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct EscrowRefunded {
+    pub escrow_id: u64,
+    pub caller: Key,
+    pub creator: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingAdminChanged {
+    pub new_vesting_admin: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingScheduleCreated {
+    pub schedule_id: u64,
+    pub creator: Key,
+    pub beneficiary: Key,
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub total: U256,
+    pub revocable: bool,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestedReleased {
+    pub schedule_id: u64,
+    pub beneficiary: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingRevoked {
+    pub schedule_id: u64,
+    pub creator: Key,
+    pub unvested: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamCreated {
+    pub stream_id: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub rate_per_second: U256,
+    pub deposit: U256,
+    pub start: u64,
+    pub stop: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamWithdrawn {
+    pub stream_id: u64,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StreamCancelled {
+    pub stream_id: u64,
+    pub sender: Key,
+    pub recipient: Key,
+    pub sender_balance: U256,
+    pub recipient_balance: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BridgeOperatorChanged {
+    pub new_bridge_operator: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BridgeMintCapChanged {
+    pub chain_id: u64,
+    pub cap: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BridgeBurn {
+    pub nonce: u64,
+    pub sender: Key,
+    pub amount: U256,
+    pub destination_chain_id: u64,
+    pub destination_address: Bytes,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct BridgeMint {
+    pub source_chain_id: u64,
+    pub source_nonce: u64,
+    pub recipient: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ReserveOracleChanged {
+    pub oracle: Option<Key>,
+    pub max_age: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AttestorChanged {
+    pub new_attestor: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AttestationPublished {
+    pub index: u64,
+    pub attestor: Key,
+    pub period: String,
+    pub reserves_amount: U256,
+    pub report_hash: [u8; 32],
+    pub uri: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MultisigConfigured {
+    pub signers: Vec<Key>,
+    pub threshold: u32,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Key,
+    pub action: u8,
+    pub expires_at: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalApproved {
+    pub proposal_id: u64,
+    pub signer: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalApprovalRevoked {
+    pub proposal_id: u64,
+    pub signer: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ProposalExecuted {
+    pub proposal_id: u64,
+    pub executor: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TimelockDelayChanged {
+    pub delay: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ActionQueued {
+    pub action_id: u64,
+    pub action: u8,
+    pub eta: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct QueuedActionExecuted {
+    pub action_id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct QueuedActionCancelled {
+    pub action_id: u64,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct GuardiansChanged {
+    pub guardians: Vec<Key>,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct GuardianPause {
+    pub guardian: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct GuardianMinterAllowanceRevoked {
+    pub guardian: Key,
+    pub minter: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct GuardianMinterRemoved {
+    pub guardian: Key,
+    pub minter: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ControllerChanged {
+    pub new_controller: Key,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ControllerDisabled {}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct ControllerTransfer {
+    pub controller: Key,
+    pub from: Key,
+    pub to: Key,
+    pub amount: U256,
+    pub legal_reference: String,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct TokensRescued {
+    pub to: Key,
+    pub amount: U256,
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct UpgradeAuthorized {
+    pub upgrader: Key,
+}
+
 pub fn emit_event(event: Event) {
     match event {
         Event::Mint(ev) => emit(ev),
@@ -135,31 +768,184 @@ pub fn emit_event(event: Event) {
         Event::MinterConfigured(ev) => emit(ev),
         Event::MinterRemoved(ev) => emit(ev),
         Event::SetAllowance(ev) => emit(ev),
+        Event::SetAllowanceWithExpiry(ev) => emit(ev),
         Event::IncreaseAllowance(ev) => emit(ev),
         Event::DecreaseAllowance(ev) => emit(ev),
         Event::Transfer(ev) => emit(ev),
         Event::TransferFrom(ev) => emit(ev),
+        Event::TransferWithMemo(ev) => emit(ev),
+        Event::MaxMemoLengthChanged(ev) => emit(ev),
+        Event::FeeCharged(ev) => emit(ev),
+        Event::FeeChanged(ev) => emit(ev),
+        Event::FeeRecipientChanged(ev) => emit(ev),
+        Event::FeeExemptionChanged(ev) => emit(ev),
+        Event::Snapshot(ev) => emit(ev),
+        Event::SnapshotterChanged(ev) => emit(ev),
+        Event::FlashLoan(ev) => emit(ev),
+        Event::FlashLoanConfigChanged(ev) => emit(ev),
+        Event::EscrowCreated(ev) => emit(ev),
+        Event::EscrowReleased(ev) => emit(ev),
+        Event::EscrowRefunded(ev) => emit(ev),
+        Event::VestingAdminChanged(ev) => emit(ev),
+        Event::VestingScheduleCreated(ev) => emit(ev),
+        Event::VestedReleased(ev) => emit(ev),
+        Event::VestingRevoked(ev) => emit(ev),
+        Event::StreamCreated(ev) => emit(ev),
+        Event::StreamWithdrawn(ev) => emit(ev),
+        Event::StreamCancelled(ev) => emit(ev),
+        Event::BridgeOperatorChanged(ev) => emit(ev),
+        Event::BridgeMintCapChanged(ev) => emit(ev),
+        Event::BridgeBurn(ev) => emit(ev),
+        Event::BridgeMint(ev) => emit(ev),
+        Event::ReserveOracleChanged(ev) => emit(ev),
+        Event::AttestorChanged(ev) => emit(ev),
+        Event::AttestationPublished(ev) => emit(ev),
+        Event::MultisigConfigured(ev) => emit(ev),
+        Event::ProposalCreated(ev) => emit(ev),
+        Event::ProposalApproved(ev) => emit(ev),
+        Event::ProposalApprovalRevoked(ev) => emit(ev),
+        Event::ProposalExecuted(ev) => emit(ev),
+        Event::TimelockDelayChanged(ev) => emit(ev),
+        Event::ActionQueued(ev) => emit(ev),
+        Event::QueuedActionExecuted(ev) => emit(ev),
+        Event::QueuedActionCancelled(ev) => emit(ev),
+        Event::GuardiansChanged(ev) => emit(ev),
+        Event::GuardianPause(ev) => emit(ev),
+        Event::GuardianMinterAllowanceRevoked(ev) => emit(ev),
+        Event::GuardianMinterRemoved(ev) => emit(ev),
+        Event::ControllerChanged(ev) => emit(ev),
+        Event::ControllerDisabled(ev) => emit(ev),
+        Event::ControllerTransfer(ev) => emit(ev),
+        Event::TokensRescued(ev) => emit(ev),
+        Event::UpgradeAuthorized(ev) => emit(ev),
+        Event::Cep18Mint(ev) => emit(ev),
+        Event::Cep18Burn(ev) => emit(ev),
+        Event::Cep18ChangeSecurity(ev) => emit(ev),
+        Event::MintDetails(ev) => emit(ev),
+        Event::BurnDetails(ev) => emit(ev),
+        Event::PauserChangedV2(ev) => emit(ev),
+        Event::BlacklisterChangedV2(ev) => emit(ev),
+        Event::MasterMinterChangedV2(ev) => emit(ev),
+        Event::OwnershipTransferredV2(ev) => emit(ev),
+        Event::TransferV2(ev) => emit(ev),
+        Event::TransferFromV2(ev) => emit(ev),
+        Event::MintV2(ev) => emit(ev),
+        Event::BurnV2(ev) => emit(ev),
+        Event::TransferWithMemoV2(ev) => emit(ev),
+        Event::FeeChargedV2(ev) => emit(ev),
+        Event::FeeChangedV2(ev) => emit(ev),
+        Event::FeeRecipientChangedV2(ev) => emit(ev),
+        Event::SnapshotterChangedV2(ev) => emit(ev),
+        Event::FlashLoanV2(ev) => emit(ev),
+        Event::FlashLoanConfigChangedV2(ev) => emit(ev),
+        Event::VestingAdminChangedV2(ev) => emit(ev),
+        Event::BridgeOperatorChangedV2(ev) => emit(ev),
+        Event::AttestorChangedV2(ev) => emit(ev),
+        Event::ControllerChangedV2(ev) => emit(ev),
+        Event::ControllerTransferV2(ev) => emit(ev),
     }
 }
 
-pub fn init_events() {
-    let schemas = Schemas::new()
-        .with::<Mint>()
-        .with::<Burn>()
+/// Registers the event schemas, using CEP-18's `Mint` and `Burn` when `cep18_compatible` and the
+/// `v2` layouts when `events_schema_version` is 2. The two can't be combined, which is checked at
+/// install.
+pub fn init_events(cep18_compatible: bool, events_schema_version: u8) {
+    let schemas = if cep18_compatible {
+        Schemas::new()
+            .with::<cep18::Mint>()
+            .with::<cep18::Burn>()
+            .with::<cep18::ChangeSecurity>()
+            .with::<MintDetails>()
+            .with::<BurnDetails>()
+    } else if events_schema_version == 2 {
+        Schemas::new().with::<v2::Mint>().with::<v2::Burn>()
+    } else {
+        Schemas::new().with::<Mint>().with::<Burn>()
+    };
+    let schemas = if events_schema_version == 2 {
+        schemas
+            .with::<v2::NewPauser>()
+            .with::<v2::MasterMinterChanged>()
+            .with::<v2::BlacklisterChanged>()
+            .with::<v2::OwnershipTransferred>()
+            .with::<v2::Transfer>()
+            .with::<v2::TransferFrom>()
+            .with::<v2::TransferWithMemo>()
+            .with::<v2::FeeCharged>()
+            .with::<v2::FeeChanged>()
+            .with::<v2::FeeRecipientChanged>()
+            .with::<v2::SnapshotterChanged>()
+            .with::<v2::FlashLoan>()
+            .with::<v2::FlashLoanConfigChanged>()
+            .with::<v2::VestingAdminChanged>()
+            .with::<v2::BridgeOperatorChanged>()
+            .with::<v2::AttestorChanged>()
+            .with::<v2::ControllerChanged>()
+            .with::<v2::ControllerTransfer>()
+    } else {
+        schemas
+            .with::<NewPauser>()
+            .with::<MasterMinterChanged>()
+            .with::<BlacklisterChanged>()
+            .with::<OwnershipTransferred>()
+            .with::<Transfer>()
+            .with::<TransferFrom>()
+            .with::<TransferWithMemo>()
+            .with::<FeeCharged>()
+            .with::<FeeChanged>()
+            .with::<FeeRecipientChanged>()
+            .with::<SnapshotterChanged>()
+            .with::<FlashLoan>()
+            .with::<FlashLoanConfigChanged>()
+            .with::<VestingAdminChanged>()
+            .with::<BridgeOperatorChanged>()
+            .with::<AttestorChanged>()
+            .with::<ControllerChanged>()
+            .with::<ControllerTransfer>()
+    };
+    let schemas = schemas
         .with::<Pause>()
         .with::<Unpause>()
-        .with::<NewPauser>()
-        .with::<MasterMinterChanged>()
         .with::<Blacklisted>()
         .with::<UnBlacklisted>()
-        .with::<BlacklisterChanged>()
-        .with::<OwnershipTransferred>()
         .with::<MinterConfigured>()
         .with::<MinterRemoved>()
         .with::<SetAllowance>()
+        .with::<SetAllowanceWithExpiry>()
         .with::<IncreaseAllowance>()
         .with::<DecreaseAllowance>()
-        .with::<Transfer>()
-        .with::<TransferFrom>();
+        .with::<MaxMemoLengthChanged>()
+        .with::<FeeExemptionChanged>()
+        .with::<Snapshot>()
+        .with::<EscrowCreated>()
+        .with::<EscrowReleased>()
+        .with::<EscrowRefunded>()
+        .with::<VestingScheduleCreated>()
+        .with::<VestedReleased>()
+        .with::<VestingRevoked>()
+        .with::<StreamCreated>()
+        .with::<StreamWithdrawn>()
+        .with::<StreamCancelled>()
+        .with::<BridgeMintCapChanged>()
+        .with::<BridgeBurn>()
+        .with::<BridgeMint>()
+        .with::<ReserveOracleChanged>()
+        .with::<AttestationPublished>()
+        .with::<MultisigConfigured>()
+        .with::<ProposalCreated>()
+        .with::<ProposalApproved>()
+        .with::<ProposalApprovalRevoked>()
+        .with::<ProposalExecuted>()
+        .with::<TimelockDelayChanged>()
+        .with::<ActionQueued>()
+        .with::<QueuedActionExecuted>()
+        .with::<QueuedActionCancelled>()
+        .with::<GuardiansChanged>()
+        .with::<GuardianPause>()
+        .with::<GuardianMinterAllowanceRevoked>()
+        .with::<GuardianMinterRemoved>()
+        .with::<ControllerDisabled>()
+        .with::<TokensRescued>()
+        .with::<UpgradeAuthorized>();
     casper_event_standard::init(schemas);
 }
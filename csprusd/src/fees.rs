@@ -0,0 +1,93 @@
+//! Basis-point transfer fee routed to a treasury key.
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, Key, U256};
+
+use crate::{
+    balances::transfer_balance,
+    cep18::{emit_cep18_balance_change, BalanceChange},
+    constants::{FEE_BPS, FEE_EXEMPT, FEE_RECIPIENT, MAX_FEE},
+    error::CsprUSDError,
+    event_schema::emit_fee_charged,
+    utils::{get_uref, read_from},
+};
+
+/// Denominator of `fee_bps`, i.e. 10_000 basis points make up 100%.
+pub(crate) const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Checks that `bps` basis points are at most 100%.
+pub(crate) fn check_fee_bps(bps: u32) -> Result<(), CsprUSDError> {
+    if bps > BPS_DENOMINATOR {
+        return Err(CsprUSDError::InvalidFeeBps);
+    }
+    Ok(())
+}
+
+/// Returns `bps` basis points of `amount`, rounded down.
+pub(crate) fn apply_bps(amount: U256, bps: u32) -> U256 {
+    // split the multiplication so that it can't overflow for large amounts
+    let denominator = U256::from(BPS_DENOMINATOR);
+    let bps = U256::from(bps);
+    amount / denominator * bps + amount % denominator * bps / denominator
+}
+
+pub(crate) fn is_fee_exempt_util(key: Key) -> bool {
+    let dict_seed = get_uref(FEE_EXEMPT);
+    let dict_key = hex::encode(runtime::blake2b(key.to_bytes().unwrap_or_revert()));
+
+    storage::dictionary_get(dict_seed, &dict_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+pub(crate) fn set_fee_exempt_util(key: Key, exempt: bool) {
+    let dict_seed = get_uref(FEE_EXEMPT);
+    let dict_key = hex::encode(runtime::blake2b(key.to_bytes().unwrap_or_revert()));
+
+    storage::dictionary_put(dict_seed, &dict_key, exempt);
+}
+
+/// Computes the fee owed for transferring `amount`, ignoring exemptions.
+///
+/// A `max_fee` of zero means the fee is not capped.
+pub(crate) fn quote_fee_util(amount: U256) -> U256 {
+    let fee_bps: u32 = read_from(FEE_BPS);
+    if fee_bps == 0 {
+        return U256::zero();
+    }
+
+    let fee = apply_bps(amount, fee_bps);
+
+    let max_fee: U256 = read_from(MAX_FEE);
+    if !max_fee.is_zero() && fee > max_fee {
+        return max_fee;
+    }
+    fee
+}
+
+/// Moves the fee for a transfer of `amount` from `payer` to the fee recipient and returns it.
+///
+/// No fee is charged if either side of the transfer is exempt.
+pub(crate) fn charge_fee(payer: Key, recipient: Key, amount: U256) -> Result<U256, CsprUSDError> {
+    if is_fee_exempt_util(payer) || is_fee_exempt_util(recipient) {
+        return Ok(U256::zero());
+    }
+
+    let fee = quote_fee_util(amount);
+    if fee.is_zero() {
+        return Ok(fee);
+    }
+
+    let fee_recipient: Key = read_from(FEE_RECIPIENT);
+    transfer_balance(payer, fee_recipient, fee)?;
+    emit_cep18_balance_change(BalanceChange::Transfer {
+        sender: payer,
+        recipient: fee_recipient,
+        amount: fee,
+    });
+    emit_fee_charged(payer, fee_recipient, fee);
+
+    Ok(fee)
+}
@@ -0,0 +1,120 @@
+//! ERC-3156-style flash mint.
+//!
+//! The loan is minted to the receiver package and the receiver's `on_flash_loan` entry point is
+//! called, which must return `FLASH_LOAN_CALLBACK_SUCCESS`. Afterwards the loan plus fee is pulled
+//! through the receiver's allowance to the token package: the loan is burned and the fee is moved
+//! to the fee recipient. The total supply is therefore the same before and after a flash loan.
+//!
+//! As anybody may start a flash loan towards any receiver, receivers are expected to check the
+//! `initiator` they are called with before approving the repayment. Without that approval the
+//! loan reverts, so a receiver's own balance can't be spent on fees for loans it didn't want.
+use casper_contract::{
+    contract_api::runtime::{self, revert},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, runtime_args, ContractPackageHash, Key, RuntimeArgs, U256};
+
+use crate::{
+    allowances::{
+        get_allowances_uref, read_allowance_from, write_allowance_to, UNLIMITED_ALLOWANCE,
+    },
+    balances::{
+        burn_balance, get_balances_uref, mint_balance, read_balance_from, transfer_balance,
+    },
+    cep18::{emit_cep18_balance_change, BalanceChange},
+    constants::{
+        AMOUNT, DATA, FEE, FEE_RECIPIENT, FLASH_FEE_BPS, INITIATOR, MAX_FLASH_LOAN,
+        ON_FLASH_LOAN_ENTRY_POINT_NAME, PACKAGE_HASH, TOKEN,
+    },
+    error::CsprUSDError,
+    fees::apply_bps,
+    utils::read_from,
+};
+
+/// Value `on_flash_loan` returns on success, `keccak256("ERC3156FlashBorrower.onFlashLoan")` as in
+/// ERC-3156.
+pub(crate) const FLASH_LOAN_CALLBACK_SUCCESS: [u8; 32] = [
+    0x43, 0x91, 0x48, 0xf0, 0xbb, 0xc6, 0x82, 0xca, 0x07, 0x9e, 0x46, 0xd6, 0xe2, 0xc2, 0xf0, 0xc1,
+    0xe3, 0xb8, 0x20, 0xf1, 0xa2, 0x91, 0xb0, 0x69, 0xd8, 0x88, 0x2a, 0xbf, 0x8c, 0xf1, 0x8d, 0xd9,
+];
+
+pub(crate) fn max_flash_loan_util() -> U256 {
+    read_from(MAX_FLASH_LOAN)
+}
+
+pub(crate) fn flash_fee_util(amount: U256) -> U256 {
+    apply_bps(amount, read_from(FLASH_FEE_BPS))
+}
+
+/// Lends `amount` to `receiver` for the duration of its `on_flash_loan` call and returns the fee.
+pub(crate) fn flash_loan_util(initiator: Key, receiver: Key, amount: U256, data: Bytes) -> U256 {
+    if amount.is_zero() {
+        revert(CsprUSDError::CannotMintZeroAmount);
+    }
+    if amount > max_flash_loan_util() {
+        revert(CsprUSDError::ExceedsMaxFlashLoan);
+    }
+    let receiver_package = receiver
+        .into_hash()
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert_with(CsprUSDError::PackageHashNotPackage);
+    let fee = flash_fee_util(amount);
+
+    // mint the loan
+    mint_balance(receiver, amount).unwrap_or_revert();
+    emit_cep18_balance_change(BalanceChange::Mint {
+        recipient: receiver,
+        amount,
+    });
+
+    let token: Key = runtime::get_key(PACKAGE_HASH).unwrap_or_revert();
+    let callback_result: [u8; 32] = runtime::call_versioned_contract(
+        receiver_package,
+        None,
+        ON_FLASH_LOAN_ENTRY_POINT_NAME,
+        runtime_args! {
+            INITIATOR => initiator,
+            TOKEN => token,
+            AMOUNT => amount,
+            FEE => fee,
+            DATA => data,
+        },
+    );
+    if callback_result != FLASH_LOAN_CALLBACK_SUCCESS {
+        revert(CsprUSDError::FlashLoanCallbackFailed);
+    }
+
+    // pull the loan plus fee through the receiver's allowance, burn the loan and collect the fee
+    let repayment = amount
+        .checked_add(fee)
+        .ok_or(CsprUSDError::Overflow)
+        .unwrap_or_revert();
+    let allowances_uref = get_allowances_uref();
+    let allowance = read_allowance_from(allowances_uref, receiver, token);
+    let new_allowance = allowance
+        .checked_sub(repayment)
+        .ok_or(CsprUSDError::InsufficientAllowance)
+        .unwrap_or_revert();
+    if read_balance_from(get_balances_uref(), receiver) < repayment {
+        revert(CsprUSDError::FlashLoanNotRepaid);
+    }
+    // an unlimited allowance is never spent, so there is nothing to write back
+    if allowance != UNLIMITED_ALLOWANCE {
+        write_allowance_to(allowances_uref, receiver, token, allowance, new_allowance);
+    }
+    burn_balance(receiver, amount).unwrap_or_revert();
+    emit_cep18_balance_change(BalanceChange::Burn {
+        owner: receiver,
+        amount,
+    });
+
+    let fee_recipient: Key = read_from(FEE_RECIPIENT);
+    transfer_balance(receiver, fee_recipient, fee).unwrap_or_revert();
+    emit_cep18_balance_change(BalanceChange::Transfer {
+        sender: receiver,
+        recipient: fee_recipient,
+        amount: fee,
+    });
+
+    fee
+}
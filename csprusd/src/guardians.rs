@@ -0,0 +1,47 @@
+//! Guardians hold defensive powers only: they can pause the contract and cut off minters, but
+//! never unpause or grant anything, so the keys can be spread widely for incident response.
+use alloc::vec::Vec;
+
+use casper_contract::{contract_api::runtime::revert, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+
+use crate::{
+    constants::GUARDIANS,
+    error::CsprUSDError,
+    utils::{read_from, write_to},
+};
+
+/// Maximum number of guardians.
+pub(crate) const MAX_GUARDIANS: usize = 20;
+
+pub(crate) fn guardians_util() -> Vec<Key> {
+    read_from(GUARDIANS)
+}
+
+pub(crate) fn is_guardian_util(key: Key) -> bool {
+    guardians_util().contains(&key)
+}
+
+/// Checks a guardian set, which may be empty but can't have duplicates or more than
+/// `MAX_GUARDIANS` guardians.
+pub(crate) fn check_guardians(guardians: &[Key]) -> Result<(), CsprUSDError> {
+    let has_duplicates = guardians
+        .iter()
+        .enumerate()
+        .any(|(index, guardian)| guardians[..index].contains(guardian));
+    if guardians.len() > MAX_GUARDIANS || has_duplicates {
+        return Err(CsprUSDError::InvalidGuardians);
+    }
+    Ok(())
+}
+
+pub(crate) fn set_guardians_util(guardians: Vec<Key>) {
+    check_guardians(&guardians).unwrap_or_revert();
+    write_to(GUARDIANS, guardians);
+}
+
+pub(crate) fn only_guardian(caller: Key) {
+    if !is_guardian_util(caller) {
+        revert(CsprUSDError::NotGuardian);
+    }
+}
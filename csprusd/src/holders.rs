@@ -0,0 +1,98 @@
+//! Indexed set of keys holding a non-zero balance.
+//!
+//! HOLDER_TO_INDEX maps a balance dictionary item key to a 1-based index and INDEX_TO_HOLDER maps
+//! the index back to the holder. A holder leaving the set is replaced by the holder with the last
+//! index, so indices stay dense. The registry can be turned off at install time, in which case
+//! balance writes don't touch it.
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    balances::make_dictionary_item_key,
+    constants::{
+        DICT_HOLDER_TO_INDEX, DICT_INDEX_TO_HOLDER, HOLDER_COUNT, HOLDER_REGISTRY_ENABLED,
+    },
+    error::CsprUSDError,
+    utils::{get_uref, read_from, write_to},
+};
+
+/// Maximum number of holders returned by a single `holders` call.
+pub(crate) const MAX_HOLDERS_PAGE: u32 = 100;
+
+pub(crate) fn holder_registry_enabled() -> bool {
+    read_from(HOLDER_REGISTRY_ENABLED)
+}
+
+fn read_holder_index(item_key: &str) -> u32 {
+    storage::dictionary_get(get_uref(DICT_HOLDER_TO_INDEX), item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn read_holder_at(index: u32) -> Key {
+    storage::dictionary_get(get_uref(DICT_INDEX_TO_HOLDER), &index.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+/// Adds `address` to or removes it from the set after its balance was set to `balance`.
+pub(crate) fn track_holder(address: Key, balance: U256) {
+    if !holder_registry_enabled() {
+        return;
+    }
+
+    let item_key = make_dictionary_item_key(address);
+    let index = read_holder_index(&item_key);
+    let holder_count: u32 = read_from(HOLDER_COUNT);
+
+    if !balance.is_zero() && index == 0 {
+        let new_index = holder_count + 1;
+        storage::dictionary_put(get_uref(DICT_HOLDER_TO_INDEX), &item_key, new_index);
+        storage::dictionary_put(
+            get_uref(DICT_INDEX_TO_HOLDER),
+            &new_index.to_string(),
+            address,
+        );
+        write_to(HOLDER_COUNT, new_index);
+    } else if balance.is_zero() && index != 0 {
+        if index < holder_count {
+            // move the last holder into the freed index
+            let last_holder = read_holder_at(holder_count);
+            storage::dictionary_put(
+                get_uref(DICT_INDEX_TO_HOLDER),
+                &index.to_string(),
+                last_holder,
+            );
+            storage::dictionary_put(
+                get_uref(DICT_HOLDER_TO_INDEX),
+                &make_dictionary_item_key(last_holder),
+                index,
+            );
+        }
+        storage::dictionary_put(get_uref(DICT_HOLDER_TO_INDEX), &item_key, 0u32);
+        write_to(HOLDER_COUNT, holder_count - 1);
+    }
+}
+
+pub(crate) fn holder_count_util() -> u32 {
+    if !holder_registry_enabled() {
+        revert(CsprUSDError::HolderRegistryDisabled);
+    }
+    read_from(HOLDER_COUNT)
+}
+
+/// Returns up to `limit` holders starting at the 0-based `offset`.
+pub(crate) fn holders_util(offset: u32, limit: u32) -> Vec<Key> {
+    let holder_count = holder_count_util();
+    let limit = limit.min(MAX_HOLDERS_PAGE);
+    let end = offset.saturating_add(limit).min(holder_count);
+
+    (offset..end)
+        .map(|index| read_holder_at(index + 1))
+        .collect()
+}
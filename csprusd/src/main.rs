@@ -5,20 +5,73 @@ extern crate alloc;
 
 mod allowances;
 mod assertion_utils;
+mod attestations;
 mod balances;
 mod blacklisting;
+mod bridge;
+mod cep18;
+mod checkpoints;
 pub mod constants;
+mod custody;
 pub mod entry_points;
 mod error;
+mod escrow;
+mod event_schema;
 mod events;
+mod fees;
+mod flash_mint;
+mod guardians;
+mod holders;
 mod minters;
+mod multisig;
+mod owner_actions;
+mod reserves;
+mod restrictions;
+mod snapshots;
+mod streams;
+mod timelock;
 mod utils;
+mod vesting;
 
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
-use allowances::{get_allowances_uref, read_allowance_from, write_allowance_to};
-use balances::{get_balances_uref, read_balance_from, transfer_balance, write_balance_to};
+use allowances::{
+    clear_passed_expiry, get_allowances_uref, read_allowance_amount_from, read_allowance_expiry,
+    read_allowance_from, read_stored_and_effective_allowance, revoke_all_allowances_util,
+    spenders_of_util, write_allowance_expiry, write_allowance_to, UNLIMITED_ALLOWANCE,
+};
+use attestations::{attestation_count_util, publish_attestation_util, read_attestation_record};
+use balances::{burn_balance, get_balances_uref, mint_balance, transfer_balance};
+use bridge::{
+    bridge_burn_util, bridge_mint_cap_util, bridge_mint_util, bridge_minted_util,
+    bridge_nonce_util, is_bridge_nonce_consumed_util, set_bridge_mint_cap_util,
+};
+use cep18::{
+    add_unlimited_minter, assert_supported_cep18_install_args, cep18_compatibility_enabled,
+    change_security_util, emit_burn, emit_cep18_balance_change, emit_mint, BalanceChange,
+    CES_EVENTS_MODE,
+};
+use checkpoints::{get_past_balance_util, get_past_total_supply_util};
+use custody::{assert_not_custody, custody_key, rescuable_balance_util, rescue_from_custody};
 use entry_points::generate_entry_points;
+use escrow::{
+    create_escrow_util, escrow_count_util, read_escrow, read_escrow_record, settle_escrow, Escrow,
+    EscrowStatus,
+};
+use event_schema::{
+    assert_supported_events_schema, emit_attestor_changed, emit_blacklister_changed,
+    emit_bridge_operator_changed, emit_controller_changed, emit_controller_transfer,
+    emit_fee_changed, emit_fee_recipient_changed, emit_flash_loan, emit_flash_loan_config_changed,
+    emit_master_minter_changed, emit_ownership_transferred, emit_pauser_changed,
+    emit_snapshotter_changed, emit_transfer, emit_transfer_from, emit_transfer_with_memo,
+    emit_vesting_admin_changed, events_schema_version, EVENTS_SCHEMA_V1,
+};
+use flash_mint::{flash_fee_util, flash_loan_util, max_flash_loan_util};
+use guardians::{guardians_util, is_guardian_util, only_guardian, set_guardians_util};
+use holders::{holder_count_util, holders_util};
 
 use casper_contract::{
     contract_api::{
@@ -28,33 +81,82 @@ use casper_contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    contracts::NamedKeys, runtime_args, CLValue, Key, PublicKey, RuntimeArgs, U256,
+    bytesrepr::Bytes, contracts::NamedKeys, runtime_args, CLValue, ContractHash,
+    ContractPackageHash, Key, PublicKey, RuntimeArgs, URef, U256,
 };
 
 use constants::{
-    ADDRESS, ALLOWANCES, AMOUNT, BALANCES, BLACKLISTED_ADDRESSES_COUNT, BLACKLISTER,
-    CONTRACT_ACCESS, CONTRACT_HASH, CONTRACT_PACKAGE_HASH, CONTRACT_VERSION, CURRENCY, DECIMALS,
-    DICT_BLACKLISTED_ADDR_TO_INDEX, DICT_INDEX_TO_BLACKLISTED_ADDR, INIT_ENTRY_POINT_NAME,
-    IS_PAUSED, KEY, MASTER_MINTER, MINTER, MINTERS, MINTER_ALLOWED, NAME, NEW, OWNER, PACKAGE_HASH,
-    PAUSER, RECIPIENT, SPENDER, SYMBOL, TOTAL_SUPPLY,
+    ACTION, ACTION_ID, ADDRESS, ADMIN_LIST, ALLOWANCES, ALLOWANCE_EXPIRIES, ALLOWANCE_SPENDERS,
+    ALLOWANCE_SPENDER_COUNTS, ALLOWANCE_SPENDER_INDICES, AMOUNT, ARBITER, ATTESTATIONS,
+    ATTESTATION_COUNT, ATTESTOR, BALANCES, BENEFICIARY, BLACKLISTED_ADDRESSES_COUNT, BLACKLISTER,
+    BRIDGE_MINTED, BRIDGE_MINT_CAPS, BRIDGE_NONCE, BRIDGE_OPERATOR, CAP,
+    CEP18_COMPATIBILITY_ENABLED, CHAIN_ID, CHECKPOINTS, CHECKPOINT_COUNTS, CLIFF, CODE,
+    CONSUMED_BRIDGE_NONCES, CONTRACT_ACCESS, CONTRACT_HASH, CONTRACT_PACKAGE_HASH,
+    CONTRACT_VERSION, CONTROLLER, CONTROLLER_DISABLED, CURRENCY, CURRENT_SNAPSHOT_ID,
+    CUSTODY_LOCKED, DATA, DECIMALS, DEFAULT_MAX_MEMO_LENGTH, DELAY, DEPOSIT, DESTINATION_ADDRESS,
+    DESTINATION_CHAIN_ID, DICT_BLACKLISTED_ADDR_TO_INDEX, DICT_HOLDER_TO_INDEX,
+    DICT_INDEX_TO_BLACKLISTED_ADDR, DICT_INDEX_TO_HOLDER, DURATION, ENABLE_CEP18_COMPATIBILITY,
+    ENABLE_HOLDER_REGISTRY, ESCROWS, ESCROW_COUNT, ESCROW_ID, ETA, EVENTS_MODE,
+    EVENTS_SCHEMA_VERSION, EXEMPT, EXPECTED_CURRENT, EXPIRES_AT, FEE_BPS, FEE_EXEMPT,
+    FEE_RECIPIENT, FLASH_FEE_BPS, FROM, GUARDIANS, HOLDER_COUNT, HOLDER_REGISTRY_ENABLED, INDEX,
+    INIT_ENTRY_POINT_NAME, IS_PAUSED, KEY, LEGAL_REFERENCE, LIMIT, MASTER_MINTER, MAX_AGE, MAX_FEE,
+    MAX_FLASH_LOAN, MAX_MEMO_LENGTH, MEMO, MINTER, MINTERS, MINTER_ALLOWED, MINTER_LIST,
+    MINT_REFERENCES, MULTISIG_CONFIG_NONCE, MULTISIG_SIGNERS, MULTISIG_THRESHOLD, NAME, NEW,
+    NEW_AMOUNT, NONCE, NONE_LIST, OFFSET, ORACLE, OWNER, PACKAGE_HASH, PAUSER, PERIOD, PROPOSALS,
+    PROPOSAL_APPROVALS, PROPOSAL_COUNT, PROPOSAL_ID, QUEUED_ACTIONS, QUEUED_ACTION_COUNT,
+    RATE_PER_SECOND, RECEIVER, RECIPIENT, REFERENCE, RELEASE_TIME, REPORT_HASH, RESERVES_AMOUNT,
+    RESERVE_MAX_AGE, RESERVE_ORACLE, REVOCABLE, SCHEDULE_ID, SIGNERS, SNAPSHOTS, SNAPSHOTTER,
+    SNAPSHOT_COUNTS, SNAPSHOT_ID, SOURCE_CHAIN_ID, SOURCE_NONCE, SPENDER, START, STOP, STREAMS,
+    STREAM_COUNT, STREAM_ID, SYMBOL, THRESHOLD, TIMELOCK_DELAY, TIMESTAMP, TO, TOTAL_SUPPLY,
+    TO_RECIPIENT, UPGRADER, UPGRADE_ACCESS_ENTRY_POINT_NAME, URI, VESTING_ADMIN, VESTING_SCHEDULES,
+    VESTING_SCHEDULE_COUNT,
 };
 pub use error::CsprUSDError;
 use events::{
-    init_events, Blacklisted, BlacklisterChanged, Burn, DecreaseAllowance, Event,
-    IncreaseAllowance, MasterMinterChanged, Mint, MinterConfigured, MinterRemoved, NewPauser,
-    OwnershipTransferred, Pause, SetAllowance, Transfer, TransferFrom, UnBlacklisted, Unpause,
+    init_events, ActionQueued, AttestationPublished, Blacklisted, BridgeBurn, BridgeMint,
+    BridgeMintCapChanged, ControllerDisabled, DecreaseAllowance, EscrowCreated, EscrowRefunded,
+    EscrowReleased, Event, FeeExemptionChanged, GuardianMinterAllowanceRevoked,
+    GuardianMinterRemoved, GuardianPause, GuardiansChanged, IncreaseAllowance,
+    MaxMemoLengthChanged, MinterConfigured, MinterRemoved, MultisigConfigured, Pause,
+    ProposalApprovalRevoked, ProposalApproved, ProposalCreated, ProposalExecuted,
+    QueuedActionCancelled, QueuedActionExecuted, ReserveOracleChanged, SetAllowance,
+    SetAllowanceWithExpiry, Snapshot, StreamCancelled, StreamCreated, StreamWithdrawn,
+    TimelockDelayChanged, TokensRescued, UnBlacklisted, Unpause, UpgradeAuthorized, VestedReleased,
+    VestingRevoked, VestingScheduleCreated,
+};
+use fees::{charge_fee, is_fee_exempt_util, quote_fee_util, set_fee_exempt_util};
+use multisig::{
+    approval_count_util, approve_proposal_util, assert_no_multisig, configure_multisig_util,
+    execute_proposal_util, multisig_signers_util, multisig_threshold_util, only_multisig_signer,
+    proposal_count_util, propose_util, read_proposal_record, revoke_approval_util,
+};
+use owner_actions::OwnerAction;
+use reserves::check_reserves;
+use restrictions::{detect_transfer_restriction_util, message_for_transfer_restriction_util};
+use snapshots::{balance_of_at_util, take_snapshot, total_supply_at_util};
+use streams::{
+    cancel_stream_util, create_stream_util, read_stream, stream_count_util,
+    withdraw_from_stream_util, Stream,
 };
-use utils::{
-    get_immediate_caller_address, get_total_supply_uref, get_uref, read_total_supply_from,
-    write_total_supply_to,
+use timelock::{
+    assert_no_timelock, cancel_queued_action_util, execute_queued_action_util, queue_action_util,
+    queued_action_count_util, queued_actions_util, read_queued_action_record,
+    set_timelock_delay_util, timelock_delay_util,
+};
+use utils::{get_immediate_caller_address, get_optional_named_arg, get_uref, now};
+use vesting::{
+    create_vesting_schedule_util, read_vesting_schedule, read_vesting_schedule_record,
+    release_vested_util, revoke_vesting_util, vesting_schedule_count_util, VestingSchedule,
 };
 
 use assertion_utils::{
-    only_blacklister, only_master_minter, only_minters, only_owner, only_pauser, when_not_paused,
+    only_attestor, only_blacklister, only_bridge_operator, only_controller, only_master_minter,
+    only_minters, only_owner, only_pauser, only_snapshotter, only_vesting_admin, when_not_paused,
 };
 use blacklisting::{blacklist_key, is_blacklisted_util, un_blacklist_address};
 use minters::{
-    add_minter, is_minter_util, read_minter_allowed, remove_minter_util, set_minter_allowed,
+    add_minter, consume_mint_reference, is_mint_reference_consumed_util, is_minter_util,
+    read_minter_allowed, remove_minter_util, set_minter_allowed,
 };
 
 #[no_mangle]
@@ -123,8 +225,7 @@ pub extern "C" fn update_pauser() {
     only_owner();
 
     let new_pauser: PublicKey = runtime::get_named_arg(NEW);
-    storage::write(get_uref(PAUSER), new_pauser.clone());
-    events::emit_event(Event::PauserChanged(NewPauser { new_pauser }));
+    run_owner_action(OwnerAction::UpdatePauser(new_pauser));
 }
 
 #[no_mangle]
@@ -132,10 +233,7 @@ pub extern "C" fn update_master_minter() {
     only_owner();
 
     let new_master_minter: Key = runtime::get_named_arg(NEW);
-    storage::write(get_uref(MASTER_MINTER), new_master_minter);
-    events::emit_event(Event::MasterMinterChanged(MasterMinterChanged {
-        new_master_minter,
-    }));
+    run_owner_action(OwnerAction::UpdateMasterMinter(new_master_minter));
 }
 
 #[no_mangle]
@@ -170,11 +268,7 @@ pub extern "C" fn update_blacklister() {
     only_owner();
 
     let new_blacklister: PublicKey = runtime::get_named_arg(NEW);
-    storage::write(get_uref(BLACKLISTER), new_blacklister.clone());
-
-    events::emit_event(Event::BlacklisterChanged(BlacklisterChanged {
-        new_blacklister,
-    }));
+    run_owner_action(OwnerAction::UpdateBlacklister(new_blacklister));
 }
 
 #[no_mangle]
@@ -182,10 +276,7 @@ pub extern "C" fn transfer_ownership() {
     only_owner();
 
     let new_owner: Key = runtime::get_named_arg(NEW);
-    storage::write(get_uref(OWNER), new_owner);
-    events::emit_event(Event::OwnershipTransferred(OwnershipTransferred {
-        new_owner,
-    }));
+    run_owner_action(OwnerAction::TransferOwnership(new_owner));
 }
 
 #[no_mangle]
@@ -216,6 +307,27 @@ pub extern "C" fn remove_minter() {
     events::emit_event(Event::MinterRemoved(MinterRemoved { minter }));
 }
 
+/// CEP-18's `change_security`, only exposed in CEP-18 compatibility mode. The master minter
+/// stands in for CEP-18's admins: `minter_list` members become minters with an unlimited
+/// allowance and `none_list` members stop being minters.
+#[no_mangle]
+pub extern "C" fn change_security() {
+    when_not_paused();
+    only_master_minter();
+
+    let admin: Key = get_immediate_caller_address().unwrap_or_revert();
+    let admin_list: Vec<Key> = get_optional_named_arg(ADMIN_LIST).unwrap_or_default();
+    let minter_list: Vec<Key> = get_optional_named_arg(MINTER_LIST).unwrap_or_default();
+    let none_list: Vec<Key> = get_optional_named_arg(NONE_LIST).unwrap_or_default();
+
+    let sec_change_map =
+        change_security_util(admin, admin_list, minter_list, none_list).unwrap_or_revert();
+    events::emit_event(Event::Cep18ChangeSecurity(events::cep18::ChangeSecurity {
+        admin,
+        sec_change_map,
+    }));
+}
+
 #[no_mangle]
 pub extern "C" fn minter_allowance() {
     let minter: Key = runtime::get_named_arg(MINTER);
@@ -239,6 +351,63 @@ pub extern "C" fn balance_of() {
     runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
 }
 
+#[no_mangle]
+pub extern "C" fn snapshotter() {
+    runtime::ret(CLValue::from_t(utils::read_from::<Key>(SNAPSHOTTER)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn update_snapshotter() {
+    only_owner();
+
+    let new_snapshotter: Key = runtime::get_named_arg(NEW);
+    run_owner_action(OwnerAction::UpdateSnapshotter(new_snapshotter));
+}
+
+#[no_mangle]
+pub extern "C" fn snapshot() {
+    only_snapshotter();
+
+    let snapshot_id = take_snapshot();
+    events::emit_event(Event::Snapshot(Snapshot { snapshot_id }));
+    runtime::ret(CLValue::from_t(snapshot_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn current_snapshot_id() {
+    runtime::ret(CLValue::from_t(snapshots::current_snapshot_id()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn balance_of_at() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID);
+    let balance = balance_of_at_util(address, snapshot_id);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn total_supply_at() {
+    let snapshot_id: u64 = runtime::get_named_arg(SNAPSHOT_ID);
+    let total_supply = total_supply_at_util(snapshot_id);
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_past_balance() {
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let timestamp: u64 = runtime::get_named_arg(TIMESTAMP);
+    let balance = get_past_balance_util(address, timestamp);
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_past_total_supply() {
+    let timestamp: u64 = runtime::get_named_arg(TIMESTAMP);
+    let total_supply = get_past_total_supply_util(timestamp);
+    runtime::ret(CLValue::from_t(total_supply).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn allowance() {
     let spender: Key = runtime::get_named_arg(SPENDER);
@@ -264,14 +433,116 @@ pub extern "C" fn approve() {
 
     let amount: U256 = runtime::get_named_arg(AMOUNT);
     let allowances_uref = get_allowances_uref();
-    write_allowance_to(allowances_uref, owner, spender, amount);
+    let previous = read_allowance_amount_from(allowances_uref, owner, spender);
+    write_allowance_to(allowances_uref, owner, spender, previous, amount);
+    write_allowance_expiry(owner, spender, 0);
+    events::emit_event(Event::SetAllowance(SetAllowance {
+        owner,
+        spender,
+        allowance: amount,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn spenders_of() {
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let offset: u32 = runtime::get_named_arg(OFFSET);
+    let limit: u32 = runtime::get_named_arg(LIMIT);
+    runtime::ret(CLValue::from_t(spenders_of_util(owner, offset, limit)).unwrap_or_revert());
+}
+
+/// Zeroes the allowances the caller granted, at most `limit` (capped at `MAX_SPENDERS_PAGE`) per
+/// call, and returns how many are left. The `limit` is a deliberate deviation from revoking
+/// everything at once: the spender list is unbounded, and a single call over all of it could run
+/// out of gas and leave the owner unable to revoke at all. Revoking stays possible while paused, as
+/// it only takes rights away.
+#[no_mangle]
+pub extern "C" fn revoke_all_allowances() {
+    let owner: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let limit: u32 = runtime::get_named_arg(LIMIT);
+    let (spenders, remaining) = revoke_all_allowances_util(owner, limit);
+    for spender in spenders {
+        events::emit_event(Event::SetAllowance(SetAllowance {
+            owner,
+            spender,
+            allowance: U256::zero(),
+        }));
+    }
+    runtime::ret(CLValue::from_t(remaining).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve_if() {
+    when_not_paused();
+
+    let owner: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    if spender == owner {
+        revert(CsprUSDError::CannotTargetSelfUser);
+    }
+
+    if is_blacklisted_util(owner) || is_blacklisted_util(spender) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+
+    let expected_current: U256 = runtime::get_named_arg(EXPECTED_CURRENT);
+    let new_amount: U256 = runtime::get_named_arg(NEW_AMOUNT);
+    let allowances_uref = get_allowances_uref();
+    let (stored, current) = read_stored_and_effective_allowance(allowances_uref, owner, spender);
+    if current != expected_current {
+        revert(CsprUSDError::AllowanceMismatch);
+    }
+
+    write_allowance_to(allowances_uref, owner, spender, stored, new_amount);
+    write_allowance_expiry(owner, spender, 0);
     events::emit_event(Event::SetAllowance(SetAllowance {
+        owner,
+        spender,
+        allowance: new_amount,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn approve_with_expiry() {
+    when_not_paused();
+
+    let owner: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    if spender == owner {
+        revert(CsprUSDError::CannotTargetSelfUser);
+    }
+
+    if is_blacklisted_util(owner) || is_blacklisted_util(spender) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let expires_at: u64 = runtime::get_named_arg(EXPIRES_AT);
+    if expires_at <= now() {
+        revert(CsprUSDError::InvalidExpiry);
+    }
+
+    let allowances_uref = get_allowances_uref();
+    let previous = read_allowance_amount_from(allowances_uref, owner, spender);
+    write_allowance_to(allowances_uref, owner, spender, previous, amount);
+    write_allowance_expiry(owner, spender, expires_at);
+    events::emit_event(Event::SetAllowanceWithExpiry(SetAllowanceWithExpiry {
         owner,
         spender,
         allowance: amount,
+        expires_at,
     }));
 }
 
+#[no_mangle]
+pub extern "C" fn allowance_details() {
+    let spender: Key = runtime::get_named_arg(SPENDER);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount = read_allowance_amount_from(get_allowances_uref(), owner, spender);
+    let expires_at = read_allowance_expiry(owner, spender);
+    runtime::ret(CLValue::from_t((amount, expires_at)).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn decrease_allowance() {
     when_not_paused();
@@ -288,9 +559,11 @@ pub extern "C" fn decrease_allowance() {
 
     let amount: U256 = runtime::get_named_arg(AMOUNT);
     let allowances_uref = get_allowances_uref();
-    let current_allowance = read_allowance_from(allowances_uref, owner, spender);
+    let (stored, current_allowance) =
+        read_stored_and_effective_allowance(allowances_uref, owner, spender);
     let new_allowance = current_allowance.saturating_sub(amount);
-    write_allowance_to(allowances_uref, owner, spender, new_allowance);
+    write_allowance_to(allowances_uref, owner, spender, stored, new_allowance);
+    clear_passed_expiry(owner, spender);
     events::emit_event(Event::DecreaseAllowance(DecreaseAllowance {
         owner,
         spender,
@@ -315,9 +588,11 @@ pub extern "C" fn increase_allowance() {
 
     let amount: U256 = runtime::get_named_arg(AMOUNT);
     let allowances_uref = get_allowances_uref();
-    let current_allowance = read_allowance_from(allowances_uref, owner, spender);
+    let (stored, current_allowance) =
+        read_stored_and_effective_allowance(allowances_uref, owner, spender);
     let new_allowance = current_allowance.saturating_add(amount);
-    write_allowance_to(allowances_uref, owner, spender, new_allowance);
+    write_allowance_to(allowances_uref, owner, spender, stored, new_allowance);
+    clear_passed_expiry(owner, spender);
     events::emit_event(Event::IncreaseAllowance(IncreaseAllowance {
         owner,
         spender,
@@ -326,41 +601,63 @@ pub extern "C" fn increase_allowance() {
     }));
 }
 
+/// Moves `amount` from `sender` to `recipient` after running the checks shared by all transfers
+/// and returns what `recipient` received once any fee was taken out.
+fn transfer_util(sender: Key, recipient: Key, amount: U256) -> U256 {
+    if let Some(error) = detect_transfer_restriction_util(sender, recipient, amount).error() {
+        revert(error);
+    }
+
+    let fee = charge_fee(sender, recipient, amount).unwrap_or_revert();
+    let received = amount - fee;
+    transfer_balance(sender, recipient, received).unwrap_or_revert();
+    received
+}
+
 #[no_mangle]
 pub extern "C" fn transfer() {
-    when_not_paused();
-
     let sender: Key = utils::get_immediate_caller_address().unwrap_or_revert();
     let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
 
-    if is_blacklisted_util(sender) || is_blacklisted_util(recipient) {
-        revert(CsprUSDError::BlackListedAccount);
-    }
+    let received = transfer_util(sender, recipient, amount);
+    emit_transfer(sender, recipient, received);
+}
 
-    if sender == recipient {
-        revert(CsprUSDError::CannotTargetSelfUser);
-    }
+#[no_mangle]
+pub extern "C" fn transfer_with_memo() {
+    let sender: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let memo: String = runtime::get_named_arg(MEMO);
+    check_memo_length(&memo);
+
+    let received = transfer_util(sender, recipient, amount);
+    emit_transfer(sender, recipient, received);
+    emit_transfer_with_memo(None, sender, recipient, received, memo);
+}
 
+#[no_mangle]
+pub extern "C" fn detect_transfer_restriction() {
+    let sender: Key = runtime::get_named_arg(FROM);
+    let recipient: Key = runtime::get_named_arg(TO);
     let amount: U256 = runtime::get_named_arg(AMOUNT);
-    if amount.is_zero() {
-        revert(CsprUSDError::CannotTransferZeroAmount);
-    }
 
-    transfer_balance(sender, recipient, amount).unwrap_or_revert();
-    events::emit_event(Event::Transfer(Transfer {
-        sender,
-        recipient,
-        amount,
-    }));
+    let restriction = detect_transfer_restriction_util(sender, recipient, amount);
+    runtime::ret(CLValue::from_t(restriction as u8).unwrap_or_revert());
 }
 
 #[no_mangle]
-pub extern "C" fn transfer_from() {
-    when_not_paused();
+pub extern "C" fn message_for_transfer_restriction() {
+    let code: u8 = runtime::get_named_arg(CODE);
+    let message: String = message_for_transfer_restriction_util(code);
+    runtime::ret(CLValue::from_t(message).unwrap_or_revert());
+}
 
-    let spender: Key = utils::get_immediate_caller_address().unwrap_or_revert();
-    let recipient: Key = runtime::get_named_arg(RECIPIENT);
-    let owner: Key = runtime::get_named_arg(OWNER);
+/// Moves `amount` from `owner` to `recipient` on behalf of `spender`, consuming its allowance, and
+/// returns what `recipient` received once any fee was taken out.
+fn transfer_from_util(spender: Key, owner: Key, recipient: Key, amount: U256) -> U256 {
+    when_not_paused();
 
     if is_blacklisted_util(spender) || is_blacklisted_util(recipient) || is_blacklisted_util(owner)
     {
@@ -370,8 +667,8 @@ pub extern "C" fn transfer_from() {
     if owner == recipient {
         revert(CsprUSDError::CannotTargetSelfUser);
     }
+    assert_not_custody(recipient);
 
-    let amount: U256 = runtime::get_named_arg(AMOUNT);
     if amount.is_zero() {
         revert(CsprUSDError::CannotTransferZeroAmount);
     }
@@ -383,19 +680,172 @@ pub extern "C" fn transfer_from() {
         .ok_or(CsprUSDError::InsufficientAllowance)
         .unwrap_or_revert();
 
-    transfer_balance(owner, recipient, amount).unwrap_or_revert();
-    write_allowance_to(allowances_uref, owner, spender, new_spender_allowance);
+    let fee = charge_fee(owner, recipient, amount).unwrap_or_revert();
+    let received = amount - fee;
+    transfer_balance(owner, recipient, received).unwrap_or_revert();
+    // an unlimited allowance is never spent, so there is nothing to write back
+    if spender_allowance != UNLIMITED_ALLOWANCE {
+        // the allowance covered a non-zero amount, so it hasn't expired and is the stored one
+        write_allowance_to(
+            allowances_uref,
+            owner,
+            spender,
+            spender_allowance,
+            new_spender_allowance,
+        );
+    }
+    received
+}
 
-    events::emit_event(Event::TransferFrom(TransferFrom {
-        spender,
-        owner,
-        recipient,
-        amount,
-    }));
+#[no_mangle]
+pub extern "C" fn transfer_from() {
+    let spender: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+
+    let received = transfer_from_util(spender, owner, recipient, amount);
+    emit_transfer_from(spender, owner, recipient, received);
+}
+
+#[no_mangle]
+pub extern "C" fn transfer_from_with_memo() {
+    let spender: Key = utils::get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let owner: Key = runtime::get_named_arg(OWNER);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let memo: String = runtime::get_named_arg(MEMO);
+    check_memo_length(&memo);
+
+    let received = transfer_from_util(spender, owner, recipient, amount);
+    emit_transfer_from(spender, owner, recipient, received);
+    emit_transfer_with_memo(Some(spender), owner, recipient, received, memo);
+}
+
+#[no_mangle]
+pub extern "C" fn fee_bps() {
+    runtime::ret(CLValue::from_t(utils::read_from::<u32>(FEE_BPS)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn max_fee() {
+    runtime::ret(CLValue::from_t(utils::read_from::<U256>(MAX_FEE)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn fee_recipient() {
+    runtime::ret(CLValue::from_t(utils::read_from::<Key>(FEE_RECIPIENT)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn is_fee_exempt() {
+    let key: Key = runtime::get_named_arg(KEY);
+    runtime::ret(CLValue::from_t(is_fee_exempt_util(key)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn quote_fee() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    runtime::ret(CLValue::from_t(quote_fee_util(amount)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_fee() {
+    only_owner();
+
+    let fee_bps: u32 = runtime::get_named_arg(FEE_BPS);
+    let max_fee: U256 = runtime::get_named_arg(MAX_FEE);
+    run_owner_action(OwnerAction::SetFee(fee_bps, max_fee));
+}
+
+#[no_mangle]
+pub extern "C" fn set_fee_recipient() {
+    only_owner();
+
+    let new_fee_recipient: Key = runtime::get_named_arg(NEW);
+    run_owner_action(OwnerAction::SetFeeRecipient(new_fee_recipient));
+}
+
+#[no_mangle]
+pub extern "C" fn set_fee_exempt() {
+    only_owner();
+
+    let key: Key = runtime::get_named_arg(KEY);
+    let exempt: bool = runtime::get_named_arg(EXEMPT);
+    run_owner_action(OwnerAction::SetFeeExempt(key, exempt));
+}
+
+/// Reverts if `memo` is longer (in bytes) than the configured maximum.
+fn check_memo_length(memo: &str) {
+    let max_memo_length: u32 = utils::read_from(MAX_MEMO_LENGTH);
+    if memo.len() > max_memo_length as usize {
+        revert(CsprUSDError::MemoTooLong);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn max_memo_length() {
+    runtime::ret(CLValue::from_t(utils::read_from::<u32>(MAX_MEMO_LENGTH)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_max_memo_length() {
+    only_owner();
+
+    let new_max_memo_length: u32 = runtime::get_named_arg(NEW);
+    run_owner_action(OwnerAction::SetMaxMemoLength(new_max_memo_length));
 }
 
 #[no_mangle]
 pub extern "C" fn mint() {
+    // CEP-18 names the recipient `owner`
+    let recipient: Key = if cep18_compatibility_enabled() {
+        runtime::get_named_arg(OWNER)
+    } else {
+        runtime::get_named_arg(RECIPIENT)
+    };
+    mint_util(recipient, None);
+}
+
+#[no_mangle]
+pub extern "C" fn mint_with_reference() {
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let reference: String = runtime::get_named_arg(REFERENCE);
+    mint_util(recipient, Some(reference));
+}
+
+#[no_mangle]
+pub extern "C" fn is_mint_reference_consumed() {
+    let reference: String = runtime::get_named_arg(REFERENCE);
+    runtime::ret(CLValue::from_t(is_mint_reference_consumed_util(&reference)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn reserve_oracle() {
+    runtime::ret(
+        CLValue::from_t(utils::read_from::<Option<Key>>(RESERVE_ORACLE)).unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn reserve_max_age() {
+    runtime::ret(CLValue::from_t(utils::read_from::<u64>(RESERVE_MAX_AGE)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_reserve_oracle() {
+    only_owner();
+
+    let oracle: Option<Key> = runtime::get_named_arg(ORACLE);
+    let max_age: u64 = runtime::get_named_arg(MAX_AGE);
+    run_owner_action(OwnerAction::SetReserveOracle(oracle, max_age));
+}
+
+/// Mints `amount` to `recipient` on behalf of the calling minter.
+///
+/// A `reference` can only be used once, so retrying a mint with the same reference reverts
+/// instead of issuing twice.
+fn mint_util(recipient: Key, reference: Option<String>) {
     when_not_paused();
 
     let minter: Key = get_immediate_caller_address().unwrap_or_revert();
@@ -405,11 +855,10 @@ pub extern "C" fn mint() {
         revert(CsprUSDError::BlackListedAccount);
     }
 
-    let recipient = runtime::get_named_arg(RECIPIENT);
-
     if is_blacklisted_util(recipient) {
         revert(CsprUSDError::BlackListedAccount);
     }
+    assert_not_custody(recipient);
 
     let amount: U256 = runtime::get_named_arg(AMOUNT);
     if amount == U256::zero() {
@@ -421,35 +870,19 @@ pub extern "C" fn mint() {
         revert(CsprUSDError::ExceedsMintAllowance);
     }
 
-    let balances_uref = get_balances_uref();
-    let new_balance_recipient_account = {
-        let balance = read_balance_from(balances_uref, recipient);
-        balance
-            .checked_add(amount)
-            .ok_or(CsprUSDError::Overflow)
-            .unwrap_or_revert()
-    };
-    write_balance_to(balances_uref, recipient, new_balance_recipient_account);
+    if let Some(reference) = &reference {
+        consume_mint_reference(reference).unwrap_or_revert();
+    }
+
+    check_reserves(amount);
+
+    mint_balance(recipient, amount).unwrap_or_revert();
 
     // update minter allowance
     let updated_allowance = minter_allowance.checked_sub(amount).unwrap_or_revert();
     set_minter_allowed(minter, updated_allowance);
 
-    let total_supply_uref = get_total_supply_uref();
-    let new_total_supply = {
-        let total_supply = read_total_supply_from(total_supply_uref);
-        total_supply
-            .checked_add(amount)
-            .ok_or(CsprUSDError::Overflow)
-            .unwrap_or_revert()
-    };
-    write_total_supply_to(total_supply_uref, new_total_supply);
-
-    events::emit_event(Event::Mint(Mint {
-        minter,
-        recipient,
-        amount,
-    }));
+    emit_mint(minter, recipient, amount, reference);
 }
 
 #[no_mangle]
@@ -463,43 +896,1139 @@ pub extern "C" fn burn() {
         revert(CsprUSDError::BlackListedAccount);
     }
 
+    // CEP-18 names the account to burn from `owner`, which must be the caller here
+    if cep18_compatibility_enabled() {
+        let owner: Key = runtime::get_named_arg(OWNER);
+        if owner != minter {
+            revert(CsprUSDError::InvalidBurnTarget);
+        }
+    }
+
     let amount_to_burn: U256 = runtime::get_named_arg(AMOUNT);
     if amount_to_burn == U256::zero() {
         revert(CsprUSDError::CannotBurnZeroAmount);
     }
 
-    let balances_uref = get_balances_uref();
-    let minter_current_balance = read_balance_from(balances_uref, minter);
+    burn_balance(minter, amount_to_burn).unwrap_or_revert();
+    emit_burn(minter, amount_to_burn);
+}
 
-    if minter_current_balance < amount_to_burn {
-        revert(CsprUSDError::BurnExceedsBalance);
-    }
+#[no_mangle]
+pub extern "C" fn max_flash_loan() {
+    runtime::ret(CLValue::from_t(max_flash_loan_util()).unwrap_or_revert());
+}
 
-    let total_supply_uref = get_total_supply_uref();
-    let new_total_supply = {
-        let total_supply = read_total_supply_from(total_supply_uref);
-        total_supply
-            .checked_sub(amount_to_burn)
-            .ok_or(CsprUSDError::Overflow)
-            .unwrap_or_revert()
-    };
-    write_total_supply_to(total_supply_uref, new_total_supply);
-
-    let new_minter_balance = {
-        let balance = read_balance_from(balances_uref, minter);
-        balance
-            .checked_sub(amount_to_burn)
-            .ok_or(CsprUSDError::InsufficientBalance)
-            .unwrap_or_revert()
+#[no_mangle]
+pub extern "C" fn flash_fee() {
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    runtime::ret(CLValue::from_t(flash_fee_util(amount)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_flash_loan_config() {
+    only_owner();
+
+    let flash_fee_bps: u32 = runtime::get_named_arg(FLASH_FEE_BPS);
+    let max_flash_loan: U256 = runtime::get_named_arg(MAX_FLASH_LOAN);
+    run_owner_action(OwnerAction::SetFlashLoanConfig(
+        flash_fee_bps,
+        max_flash_loan,
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn flash_loan() {
+    when_not_paused();
+
+    let initiator: Key = get_immediate_caller_address().unwrap_or_revert();
+    let receiver: Key = runtime::get_named_arg(RECEIVER);
+
+    if is_blacklisted_util(initiator) || is_blacklisted_util(receiver) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let data: Bytes = runtime::get_named_arg(DATA);
+    let fee = flash_loan_util(initiator, receiver, amount, data);
+
+    emit_flash_loan(initiator, receiver, amount, fee);
+}
+
+#[no_mangle]
+pub extern "C" fn create_escrow() {
+    when_not_paused();
+
+    let creator: Key = get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let release_time: u64 = runtime::get_named_arg(RELEASE_TIME);
+    let arbiter: Option<Key> = runtime::get_named_arg(ARBITER);
+
+    if is_blacklisted_util(creator) || is_blacklisted_util(recipient) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+    if creator == recipient {
+        revert(CsprUSDError::CannotTargetSelfUser);
+    }
+    if amount.is_zero() {
+        revert(CsprUSDError::CannotTransferZeroAmount);
+    }
+    if release_time <= now() {
+        revert(CsprUSDError::EscrowReleaseTimeNotInFuture);
+    }
+    if arbiter == Some(creator) || arbiter == Some(recipient) {
+        revert(CsprUSDError::InvalidEscrowArbiter);
+    }
+
+    let escrow_id = create_escrow_util(Escrow {
+        creator,
+        recipient,
+        arbiter,
+        amount,
+        release_time,
+        status: EscrowStatus::Pending,
+    });
+
+    events::emit_event(Event::EscrowCreated(EscrowCreated {
+        escrow_id,
+        creator,
+        recipient,
+        arbiter,
+        amount,
+        release_time,
+    }));
+    runtime::ret(CLValue::from_t(escrow_id).unwrap_or_revert());
+}
+
+/// Pays escrowed funds to the recipient or back to the creator.
+fn settle_escrow_util(escrow_id: u64, caller: Key, escrow: Escrow, status: EscrowStatus) {
+    let amount = escrow.amount;
+    let payee = escrow.payee(status);
+    if is_blacklisted_util(caller) || is_blacklisted_util(payee) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+
+    settle_escrow(escrow_id, escrow, status);
+
+    match status {
+        EscrowStatus::Released => events::emit_event(Event::EscrowReleased(EscrowReleased {
+            escrow_id,
+            caller,
+            recipient: payee,
+            amount,
+        })),
+        _ => events::emit_event(Event::EscrowRefunded(EscrowRefunded {
+            escrow_id,
+            caller,
+            creator: payee,
+            amount,
+        })),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn release_escrow() {
+    when_not_paused();
+
+    let caller: Key = get_immediate_caller_address().unwrap_or_revert();
+    let escrow_id: u64 = runtime::get_named_arg(ESCROW_ID);
+    let escrow = read_escrow(escrow_id);
+
+    if caller != escrow.creator && Some(caller) != escrow.arbiter {
+        revert(CsprUSDError::NotEscrowParty);
+    }
+
+    settle_escrow_util(escrow_id, caller, escrow, EscrowStatus::Released);
+}
+
+#[no_mangle]
+pub extern "C" fn refund_escrow() {
+    when_not_paused();
+
+    let caller: Key = get_immediate_caller_address().unwrap_or_revert();
+    let escrow_id: u64 = runtime::get_named_arg(ESCROW_ID);
+    let escrow = read_escrow(escrow_id);
+
+    // the recipient may always give the funds back, the creator only once the escrow expired
+    if caller == escrow.creator {
+        if now() < escrow.release_time {
+            revert(CsprUSDError::EscrowNotExpired);
+        }
+    } else if caller != escrow.recipient {
+        revert(CsprUSDError::NotEscrowParty);
+    }
+
+    settle_escrow_util(escrow_id, caller, escrow, EscrowStatus::Refunded);
+}
+
+#[no_mangle]
+pub extern "C" fn resolve_escrow() {
+    when_not_paused();
+
+    let caller: Key = get_immediate_caller_address().unwrap_or_revert();
+    let escrow_id: u64 = runtime::get_named_arg(ESCROW_ID);
+    let to_recipient: bool = runtime::get_named_arg(TO_RECIPIENT);
+    let escrow = read_escrow(escrow_id);
+
+    if Some(caller) != escrow.arbiter {
+        revert(CsprUSDError::NotArbiter);
+    }
+
+    let status = if to_recipient {
+        EscrowStatus::Released
+    } else {
+        EscrowStatus::Refunded
     };
+    settle_escrow_util(escrow_id, caller, escrow, status);
+}
+
+#[no_mangle]
+pub extern "C" fn escrow() {
+    let escrow_id: u64 = runtime::get_named_arg(ESCROW_ID);
+    runtime::ret(CLValue::from_t(read_escrow_record(escrow_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn escrow_status() {
+    let escrow_id: u64 = runtime::get_named_arg(ESCROW_ID);
+    runtime::ret(CLValue::from_t(read_escrow(escrow_id).status as u8).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn escrow_count() {
+    runtime::ret(CLValue::from_t(escrow_count_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn vesting_admin() {
+    runtime::ret(CLValue::from_t(utils::read_from::<Key>(VESTING_ADMIN)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn update_vesting_admin() {
+    only_owner();
+
+    let new_vesting_admin: Key = runtime::get_named_arg(NEW);
+    run_owner_action(OwnerAction::UpdateVestingAdmin(new_vesting_admin));
+}
+
+#[no_mangle]
+pub extern "C" fn create_vesting_schedule() {
+    when_not_paused();
+    only_vesting_admin();
+
+    let creator: Key = get_immediate_caller_address().unwrap_or_revert();
+    let beneficiary: Key = runtime::get_named_arg(BENEFICIARY);
+    let start: u64 = runtime::get_named_arg(START);
+    let cliff: u64 = runtime::get_named_arg(CLIFF);
+    let duration: u64 = runtime::get_named_arg(DURATION);
+    let total: U256 = runtime::get_named_arg(AMOUNT);
+    let revocable: bool = runtime::get_named_arg(REVOCABLE);
+
+    if is_blacklisted_util(creator) || is_blacklisted_util(beneficiary) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+    if creator == beneficiary {
+        revert(CsprUSDError::CannotTargetSelfUser);
+    }
+    if total.is_zero() {
+        revert(CsprUSDError::CannotTransferZeroAmount);
+    }
+
+    let schedule_id = create_vesting_schedule_util(VestingSchedule {
+        creator,
+        beneficiary,
+        revocable,
+        start,
+        cliff,
+        duration,
+        total,
+        released: U256::zero(),
+        revoked: false,
+    });
+
+    events::emit_event(Event::VestingScheduleCreated(VestingScheduleCreated {
+        schedule_id,
+        creator,
+        beneficiary,
+        start,
+        cliff,
+        duration,
+        total,
+        revocable,
+    }));
+    runtime::ret(CLValue::from_t(schedule_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn release_vested() {
+    when_not_paused();
+
+    let caller: Key = get_immediate_caller_address().unwrap_or_revert();
+    let schedule_id: u64 = runtime::get_named_arg(SCHEDULE_ID);
+    let schedule = read_vesting_schedule(schedule_id);
+
+    if caller != schedule.beneficiary {
+        revert(CsprUSDError::NotBeneficiary);
+    }
+    if is_blacklisted_util(caller) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+
+    let amount = release_vested_util(schedule_id, schedule);
+    events::emit_event(Event::VestedReleased(VestedReleased {
+        schedule_id,
+        beneficiary: caller,
+        amount,
+    }));
+    runtime::ret(CLValue::from_t(amount).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_vesting() {
+    when_not_paused();
+
+    let caller: Key = get_immediate_caller_address().unwrap_or_revert();
+    let schedule_id: u64 = runtime::get_named_arg(SCHEDULE_ID);
+    let schedule = read_vesting_schedule(schedule_id);
+
+    if caller != schedule.creator {
+        revert(CsprUSDError::NotVestingCreator);
+    }
+    if is_blacklisted_util(caller) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+
+    let unvested = revoke_vesting_util(schedule_id, schedule);
+    events::emit_event(Event::VestingRevoked(VestingRevoked {
+        schedule_id,
+        creator: caller,
+        unvested,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn vesting_schedule() {
+    let schedule_id: u64 = runtime::get_named_arg(SCHEDULE_ID);
+    runtime::ret(CLValue::from_t(read_vesting_schedule_record(schedule_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn vesting_schedule_count() {
+    runtime::ret(CLValue::from_t(vesting_schedule_count_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn vested_amount() {
+    let schedule_id: u64 = runtime::get_named_arg(SCHEDULE_ID);
+    let vested = read_vesting_schedule(schedule_id).vested_at(now());
+    runtime::ret(CLValue::from_t(vested).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn released_amount() {
+    let schedule_id: u64 = runtime::get_named_arg(SCHEDULE_ID);
+    let released = read_vesting_schedule(schedule_id).released;
+    runtime::ret(CLValue::from_t(released).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn releasable_amount() {
+    let schedule_id: u64 = runtime::get_named_arg(SCHEDULE_ID);
+    let releasable = read_vesting_schedule(schedule_id).releasable_at(now());
+    runtime::ret(CLValue::from_t(releasable).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn create_stream() {
+    when_not_paused();
+
+    let sender: Key = get_immediate_caller_address().unwrap_or_revert();
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    let rate_per_second: U256 = runtime::get_named_arg(RATE_PER_SECOND);
+    let deposit: U256 = runtime::get_named_arg(DEPOSIT);
+    let start: u64 = runtime::get_named_arg(START);
+    let stop: u64 = runtime::get_named_arg(STOP);
+
+    if is_blacklisted_util(sender) || is_blacklisted_util(recipient) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+    if sender == recipient {
+        revert(CsprUSDError::CannotTargetSelfUser);
+    }
+
+    let stream_id = create_stream_util(Stream {
+        sender,
+        recipient,
+        active: true,
+        rate_per_second,
+        deposit,
+        withdrawn: U256::zero(),
+        start,
+        stop,
+    });
+
+    events::emit_event(Event::StreamCreated(StreamCreated {
+        stream_id,
+        sender,
+        recipient,
+        rate_per_second,
+        deposit,
+        start,
+        stop,
+    }));
+    runtime::ret(CLValue::from_t(stream_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_from_stream() {
+    when_not_paused();
+
+    let caller: Key = get_immediate_caller_address().unwrap_or_revert();
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let stream = read_stream(stream_id);
+
+    if caller != stream.recipient {
+        revert(CsprUSDError::NotStreamParty);
+    }
+    if is_blacklisted_util(caller) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+    if amount.is_zero() {
+        revert(CsprUSDError::CannotTransferZeroAmount);
+    }
+
+    withdraw_from_stream_util(stream_id, stream, amount);
+    events::emit_event(Event::StreamWithdrawn(StreamWithdrawn {
+        stream_id,
+        recipient: caller,
+        amount,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_stream() {
+    when_not_paused();
+
+    let caller: Key = get_immediate_caller_address().unwrap_or_revert();
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+    let stream = read_stream(stream_id);
+    let (sender, recipient) = (stream.sender, stream.recipient);
+
+    if caller != sender && caller != recipient {
+        revert(CsprUSDError::NotStreamParty);
+    }
+    if is_blacklisted_util(sender) || is_blacklisted_util(recipient) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+
+    let (recipient_balance, sender_balance) = cancel_stream_util(stream_id, stream);
+    events::emit_event(Event::StreamCancelled(StreamCancelled {
+        stream_id,
+        sender,
+        recipient,
+        sender_balance,
+        recipient_balance,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn stream_balance() {
+    let stream_id: u64 = runtime::get_named_arg(STREAM_ID);
+    let address: Key = runtime::get_named_arg(ADDRESS);
+    let balance = read_stream(stream_id).balance_of_at(address, now());
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn stream_count() {
+    runtime::ret(CLValue::from_t(stream_count_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn holder_count() {
+    runtime::ret(CLValue::from_t(holder_count_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn holders() {
+    let offset: u32 = runtime::get_named_arg(OFFSET);
+    let limit: u32 = runtime::get_named_arg(LIMIT);
+    runtime::ret(CLValue::from_t(holders_util(offset, limit)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_operator() {
+    runtime::ret(CLValue::from_t(utils::read_from::<Key>(BRIDGE_OPERATOR)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn update_bridge_operator() {
+    only_owner();
+
+    let new_bridge_operator: Key = runtime::get_named_arg(NEW);
+    run_owner_action(OwnerAction::UpdateBridgeOperator(new_bridge_operator));
+}
+
+#[no_mangle]
+pub extern "C" fn set_bridge_mint_cap() {
+    only_owner();
+
+    let chain_id: u64 = runtime::get_named_arg(CHAIN_ID);
+    let cap: U256 = runtime::get_named_arg(CAP);
+    run_owner_action(OwnerAction::SetBridgeMintCap(chain_id, cap));
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_burn() {
+    when_not_paused();
+
+    let sender: Key = get_immediate_caller_address().unwrap_or_revert();
+    if is_blacklisted_util(sender) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    if amount.is_zero() {
+        revert(CsprUSDError::CannotBurnZeroAmount);
+    }
+    let destination_chain_id: u64 = runtime::get_named_arg(DESTINATION_CHAIN_ID);
+    let destination_address: Bytes = runtime::get_named_arg(DESTINATION_ADDRESS);
+
+    let nonce = bridge_burn_util(sender, amount, destination_chain_id);
+    emit_cep18_balance_change(BalanceChange::Burn {
+        owner: sender,
+        amount,
+    });
+    events::emit_event(Event::BridgeBurn(BridgeBurn {
+        nonce,
+        sender,
+        amount,
+        destination_chain_id,
+        destination_address,
+    }));
+    runtime::ret(CLValue::from_t(nonce).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_mint() {
+    when_not_paused();
+    only_bridge_operator();
+
+    let source_chain_id: u64 = runtime::get_named_arg(SOURCE_CHAIN_ID);
+    let source_nonce: u64 = runtime::get_named_arg(SOURCE_NONCE);
+    let recipient: Key = runtime::get_named_arg(RECIPIENT);
+    if is_blacklisted_util(recipient) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+    assert_not_custody(recipient);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    if amount.is_zero() {
+        revert(CsprUSDError::CannotMintZeroAmount);
+    }
+
+    bridge_mint_util(source_chain_id, source_nonce, recipient, amount);
+    emit_cep18_balance_change(BalanceChange::Mint { recipient, amount });
+    events::emit_event(Event::BridgeMint(BridgeMint {
+        source_chain_id,
+        source_nonce,
+        recipient,
+        amount,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_mint_cap() {
+    let chain_id: u64 = runtime::get_named_arg(CHAIN_ID);
+    runtime::ret(CLValue::from_t(bridge_mint_cap_util(chain_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_minted() {
+    let chain_id: u64 = runtime::get_named_arg(CHAIN_ID);
+    runtime::ret(CLValue::from_t(bridge_minted_util(chain_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn bridge_nonce() {
+    runtime::ret(CLValue::from_t(bridge_nonce_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn is_bridge_nonce_consumed() {
+    let chain_id: u64 = runtime::get_named_arg(CHAIN_ID);
+    let nonce: u64 = runtime::get_named_arg(NONCE);
+    runtime::ret(
+        CLValue::from_t(is_bridge_nonce_consumed_util(chain_id, nonce)).unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn attestor() {
+    runtime::ret(CLValue::from_t(utils::read_from::<Key>(ATTESTOR)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn update_attestor() {
+    only_owner();
+
+    let new_attestor: Key = runtime::get_named_arg(NEW);
+    run_owner_action(OwnerAction::UpdateAttestor(new_attestor));
+}
+
+#[no_mangle]
+pub extern "C" fn publish_attestation() {
+    only_attestor();
+
+    let attestor: Key = get_immediate_caller_address().unwrap_or_revert();
+    let period: String = runtime::get_named_arg(PERIOD);
+    let reserves_amount: U256 = runtime::get_named_arg(RESERVES_AMOUNT);
+    let report_hash: [u8; 32] = runtime::get_named_arg(REPORT_HASH);
+    let uri: String = runtime::get_named_arg(URI);
+
+    let index = publish_attestation_util((
+        (period.clone(), reserves_amount, report_hash),
+        (uri.clone(), attestor, now()),
+    ));
+    events::emit_event(Event::AttestationPublished(AttestationPublished {
+        index,
+        attestor,
+        period,
+        reserves_amount,
+        report_hash,
+        uri,
+    }));
+    runtime::ret(CLValue::from_t(index).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn attestation() {
+    let index: u64 = runtime::get_named_arg(INDEX);
+    runtime::ret(CLValue::from_t(read_attestation_record(index)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn latest_attestation() {
+    runtime::ret(
+        CLValue::from_t(read_attestation_record(attestation_count_util())).unwrap_or_revert(),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn attestation_count() {
+    runtime::ret(CLValue::from_t(attestation_count_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn multisig_signers() {
+    runtime::ret(CLValue::from_t(multisig_signers_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn multisig_threshold() {
+    runtime::ret(CLValue::from_t(multisig_threshold_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn configure_multisig() {
+    only_owner();
+
+    let signers: Vec<Key> = runtime::get_named_arg(SIGNERS);
+    let threshold: u32 = runtime::get_named_arg(THRESHOLD);
+    run_owner_action(OwnerAction::ConfigureMultisig(signers, threshold));
+}
+
+#[no_mangle]
+pub extern "C" fn propose() {
+    let proposer: Key = get_immediate_caller_address().unwrap_or_revert();
+    only_multisig_signer(proposer);
+
+    let action: u8 = runtime::get_named_arg(ACTION);
+    let data: Bytes = runtime::get_named_arg(DATA);
+    let expires_at: u64 = runtime::get_named_arg(EXPIRES_AT);
+
+    let proposal_id = propose_util(proposer, action, data, expires_at);
+    events::emit_event(Event::ProposalCreated(ProposalCreated {
+        proposal_id,
+        proposer,
+        action,
+        expires_at,
+    }));
+    events::emit_event(Event::ProposalApproved(ProposalApproved {
+        proposal_id,
+        signer: proposer,
+    }));
+    runtime::ret(CLValue::from_t(proposal_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn approve_proposal() {
+    let signer: Key = get_immediate_caller_address().unwrap_or_revert();
+    only_multisig_signer(signer);
+
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+    approve_proposal_util(proposal_id, signer);
+    events::emit_event(Event::ProposalApproved(ProposalApproved {
+        proposal_id,
+        signer,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_approval() {
+    let signer: Key = get_immediate_caller_address().unwrap_or_revert();
+    only_multisig_signer(signer);
+
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+    revoke_approval_util(proposal_id, signer);
+    events::emit_event(Event::ProposalApprovalRevoked(ProposalApprovalRevoked {
+        proposal_id,
+        signer,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn execute_proposal() {
+    let executor: Key = get_immediate_caller_address().unwrap_or_revert();
+    only_multisig_signer(executor);
+
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+    let (action, data) = execute_proposal_util(proposal_id);
+    events::emit_event(Event::ProposalExecuted(ProposalExecuted {
+        proposal_id,
+        executor,
+    }));
+
+    // While the timelock is active an approved proposal is only queued, like an owner action,
+    // except for cancellations which only withdraw a pending change.
+    let owner_action = OwnerAction::decode(action, &data).unwrap_or_revert();
+    let delay = timelock_delay_util();
+    if delay == 0 || matches!(owner_action, OwnerAction::CancelQueuedAction(_)) {
+        apply_owner_action(owner_action, executor);
+    } else {
+        let eta = now()
+            .checked_add(delay)
+            .ok_or(CsprUSDError::Overflow)
+            .unwrap_or_revert();
+        let action_id = queue_action_util(action, data, eta, true, executor);
+        events::emit_event(Event::ActionQueued(ActionQueued {
+            action_id,
+            action,
+            eta,
+        }));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn proposal() {
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+    runtime::ret(CLValue::from_t(read_proposal_record(proposal_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn proposal_approvals() {
+    let proposal_id: u64 = runtime::get_named_arg(PROPOSAL_ID);
+    // reverts for unknown proposals
+    read_proposal_record(proposal_id);
+    runtime::ret(CLValue::from_t(approval_count_util(proposal_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn proposal_count() {
+    runtime::ret(CLValue::from_t(proposal_count_util()).unwrap_or_revert());
+}
+
+/// Applies an owner action once it's been authorized by the owner, the multisig or the timelock.
+/// `authorized_by` is recorded as the caller of the version 2 events: the owner or executing signer
+/// for direct actions and proposals, and the account that queued the action for the timelock.
+fn apply_owner_action(action: OwnerAction, authorized_by: Key) {
+    action.validate().unwrap_or_revert();
+    match action {
+        OwnerAction::UpdatePauser(new_pauser) => {
+            let previous_pauser: PublicKey = utils::read_from(PAUSER);
+            storage::write(get_uref(PAUSER), new_pauser.clone());
+            emit_pauser_changed(previous_pauser, new_pauser, authorized_by);
+        }
+        OwnerAction::UpdateBlacklister(new_blacklister) => {
+            let previous_blacklister: PublicKey = utils::read_from(BLACKLISTER);
+            storage::write(get_uref(BLACKLISTER), new_blacklister.clone());
+            emit_blacklister_changed(previous_blacklister, new_blacklister, authorized_by);
+        }
+        OwnerAction::UpdateMasterMinter(new_master_minter) => {
+            let previous_master_minter: Key = utils::read_from(MASTER_MINTER);
+            storage::write(get_uref(MASTER_MINTER), new_master_minter);
+            emit_master_minter_changed(previous_master_minter, new_master_minter, authorized_by);
+        }
+        OwnerAction::TransferOwnership(new_owner) => {
+            let previous_owner: Key = utils::read_from(OWNER);
+            storage::write(get_uref(OWNER), new_owner);
+            emit_ownership_transferred(previous_owner, new_owner, authorized_by);
+        }
+        OwnerAction::ConfigureMultisig(signers, threshold) => {
+            configure_multisig_util(signers.clone(), threshold);
+            events::emit_event(Event::MultisigConfigured(MultisigConfigured {
+                signers,
+                threshold,
+            }));
+        }
+        OwnerAction::UpdateSnapshotter(new_snapshotter) => {
+            let previous_snapshotter: Key = utils::read_from(SNAPSHOTTER);
+            storage::write(get_uref(SNAPSHOTTER), new_snapshotter);
+            emit_snapshotter_changed(previous_snapshotter, new_snapshotter, authorized_by);
+        }
+        OwnerAction::SetFee(fee_bps, max_fee) => {
+            let previous = (utils::read_from(FEE_BPS), utils::read_from(MAX_FEE));
+            storage::write(get_uref(FEE_BPS), fee_bps);
+            storage::write(get_uref(MAX_FEE), max_fee);
+            emit_fee_changed(previous, fee_bps, max_fee, authorized_by);
+        }
+        OwnerAction::SetFeeRecipient(new_fee_recipient) => {
+            // fees paid to a blacklisted key or to the package itself would be stuck
+            if is_blacklisted_util(new_fee_recipient) {
+                revert(CsprUSDError::BlackListedAccount);
+            }
+            assert_not_custody(new_fee_recipient);
+            let previous_fee_recipient: Key = utils::read_from(FEE_RECIPIENT);
+            storage::write(get_uref(FEE_RECIPIENT), new_fee_recipient);
+            emit_fee_recipient_changed(previous_fee_recipient, new_fee_recipient, authorized_by);
+        }
+        OwnerAction::SetFeeExempt(key, exempt) => {
+            set_fee_exempt_util(key, exempt);
+            events::emit_event(Event::FeeExemptionChanged(FeeExemptionChanged {
+                key,
+                exempt,
+            }));
+        }
+        OwnerAction::SetFlashLoanConfig(flash_fee_bps, max_flash_loan) => {
+            let previous = (
+                utils::read_from(FLASH_FEE_BPS),
+                utils::read_from(MAX_FLASH_LOAN),
+            );
+            storage::write(get_uref(FLASH_FEE_BPS), flash_fee_bps);
+            storage::write(get_uref(MAX_FLASH_LOAN), max_flash_loan);
+            emit_flash_loan_config_changed(previous, flash_fee_bps, max_flash_loan, authorized_by);
+        }
+        OwnerAction::UpdateVestingAdmin(new_vesting_admin) => {
+            let previous_vesting_admin: Key = utils::read_from(VESTING_ADMIN);
+            storage::write(get_uref(VESTING_ADMIN), new_vesting_admin);
+            emit_vesting_admin_changed(previous_vesting_admin, new_vesting_admin, authorized_by);
+        }
+        OwnerAction::UpdateBridgeOperator(new_bridge_operator) => {
+            let previous_bridge_operator: Key = utils::read_from(BRIDGE_OPERATOR);
+            storage::write(get_uref(BRIDGE_OPERATOR), new_bridge_operator);
+            emit_bridge_operator_changed(
+                previous_bridge_operator,
+                new_bridge_operator,
+                authorized_by,
+            );
+        }
+        OwnerAction::SetBridgeMintCap(chain_id, cap) => {
+            set_bridge_mint_cap_util(chain_id, cap);
+            events::emit_event(Event::BridgeMintCapChanged(BridgeMintCapChanged {
+                chain_id,
+                cap,
+            }));
+        }
+        OwnerAction::UpdateAttestor(new_attestor) => {
+            let previous_attestor: Key = utils::read_from(ATTESTOR);
+            storage::write(get_uref(ATTESTOR), new_attestor);
+            emit_attestor_changed(previous_attestor, new_attestor, authorized_by);
+        }
+        OwnerAction::SetReserveOracle(oracle, max_age) => {
+            storage::write(get_uref(RESERVE_ORACLE), oracle);
+            storage::write(get_uref(RESERVE_MAX_AGE), max_age);
+            events::emit_event(Event::ReserveOracleChanged(ReserveOracleChanged {
+                oracle,
+                max_age,
+            }));
+        }
+        OwnerAction::SetTimelockDelay(delay) => {
+            set_timelock_delay_util(delay);
+            events::emit_event(Event::TimelockDelayChanged(TimelockDelayChanged { delay }));
+        }
+        OwnerAction::SetMaxMemoLength(new_max_memo_length) => {
+            storage::write(get_uref(MAX_MEMO_LENGTH), new_max_memo_length);
+            events::emit_event(Event::MaxMemoLengthChanged(MaxMemoLengthChanged {
+                new_max_memo_length,
+            }));
+        }
+        OwnerAction::UpdateController(new_controller) => {
+            let previous_controller: Key = utils::read_from(CONTROLLER);
+            storage::write(get_uref(CONTROLLER), new_controller);
+            emit_controller_changed(previous_controller, new_controller, authorized_by);
+        }
+        OwnerAction::RescueTokens(to, amount) => {
+            if is_blacklisted_util(to) {
+                revert(CsprUSDError::BlackListedAccount);
+            }
+            rescue_from_custody(to, amount).unwrap_or_revert();
+            events::emit_event(Event::TokensRescued(TokensRescued { to, amount }));
+        }
+        OwnerAction::SetGuardians(guardians) => {
+            set_guardians_util(guardians.clone());
+            events::emit_event(Event::GuardiansChanged(GuardiansChanged { guardians }));
+        }
+        // only reachable through a proposal, the owner cancels through `cancel_queued_action`
+        OwnerAction::CancelQueuedAction(action_id) => {
+            cancel_queued_action_util(action_id, true);
+            events::emit_event(Event::QueuedActionCancelled(QueuedActionCancelled {
+                action_id,
+            }));
+        }
+        OwnerAction::DisableController => {
+            storage::write(get_uref(CONTROLLER_DISABLED), true);
+            events::emit_event(Event::ControllerDisabled(ControllerDisabled {}));
+        }
+        OwnerAction::AuthorizeUpgrade(upgrader) => {
+            storage::write(get_uref(UPGRADER), Some(upgrader));
+            events::emit_event(Event::UpgradeAuthorized(UpgradeAuthorized { upgrader }));
+        }
+    }
+}
+
+/// Applies an owner action called directly by the owner, which is only allowed while no multisig
+/// is configured and the timelock delay is zero.
+fn run_owner_action(action: OwnerAction) {
+    assert_no_multisig();
+    assert_no_timelock();
+    apply_owner_action(action, get_immediate_caller_address().unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn timelock_delay() {
+    runtime::ret(CLValue::from_t(timelock_delay_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_timelock_delay() {
+    only_owner();
+
+    let delay: u64 = runtime::get_named_arg(DELAY);
+    run_owner_action(OwnerAction::SetTimelockDelay(delay));
+}
+
+#[no_mangle]
+pub extern "C" fn queue_action() {
+    only_owner();
+    assert_no_multisig();
+
+    let action: u8 = runtime::get_named_arg(ACTION);
+    let data: Bytes = runtime::get_named_arg(DATA);
+    let eta: u64 = runtime::get_named_arg(ETA);
+
+    let owner: Key = get_immediate_caller_address().unwrap_or_revert();
+    let action_id = queue_action_util(action, data, eta, false, owner);
+    events::emit_event(Event::ActionQueued(ActionQueued {
+        action_id,
+        action,
+        eta,
+    }));
+    runtime::ret(CLValue::from_t(action_id).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn execute_queued_action() {
+    let action_id: u64 = runtime::get_named_arg(ACTION_ID);
+    let (owner_action, queued_by) = execute_queued_action_util(action_id);
+    apply_owner_action(owner_action, queued_by);
+    events::emit_event(Event::QueuedActionExecuted(QueuedActionExecuted {
+        action_id,
+    }));
+}
+
+/// Cancels an action the owner queued. Actions queued by an approved multisig proposal revert with
+/// `QueuedByMultisig` here and can only be cancelled by a `CancelQueuedAction` proposal.
+#[no_mangle]
+pub extern "C" fn cancel_queued_action() {
+    only_owner();
+
+    let action_id: u64 = runtime::get_named_arg(ACTION_ID);
+    cancel_queued_action_util(action_id, false);
+    events::emit_event(Event::QueuedActionCancelled(QueuedActionCancelled {
+        action_id,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn queued_action() {
+    let action_id: u64 = runtime::get_named_arg(ACTION_ID);
+    runtime::ret(CLValue::from_t(read_queued_action_record(action_id)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn queued_actions() {
+    let offset: u32 = runtime::get_named_arg(OFFSET);
+    let limit: u32 = runtime::get_named_arg(LIMIT);
+    runtime::ret(CLValue::from_t(queued_actions_util(offset, limit)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn queued_action_count() {
+    runtime::ret(CLValue::from_t(queued_action_count_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn guardians() {
+    runtime::ret(CLValue::from_t(guardians_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn is_guardian() {
+    let key: Key = runtime::get_named_arg(KEY);
+    runtime::ret(CLValue::from_t(is_guardian_util(key)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_guardians() {
+    only_owner();
+
+    let guardians: Vec<Key> = runtime::get_named_arg(GUARDIANS);
+    run_owner_action(OwnerAction::SetGuardians(guardians));
+}
 
-    write_balance_to(balances_uref, minter, new_minter_balance);
-    events::emit_event(Event::Burn(Burn {
+#[no_mangle]
+pub extern "C" fn guardian_pause() {
+    let guardian: Key = get_immediate_caller_address().unwrap_or_revert();
+    only_guardian(guardian);
+
+    storage::write(get_uref(IS_PAUSED), true);
+    events::emit_event(Event::Pause(Pause {}));
+    events::emit_event(Event::GuardianPause(GuardianPause { guardian }));
+}
+
+#[no_mangle]
+pub extern "C" fn guardian_revoke_minter_allowance() {
+    let guardian: Key = get_immediate_caller_address().unwrap_or_revert();
+    only_guardian(guardian);
+
+    let minter: Key = runtime::get_named_arg(MINTER);
+    set_minter_allowed(minter, U256::zero());
+    events::emit_event(Event::GuardianMinterAllowanceRevoked(
+        GuardianMinterAllowanceRevoked { guardian, minter },
+    ));
+}
+
+#[no_mangle]
+pub extern "C" fn guardian_remove_minter() {
+    let guardian: Key = get_immediate_caller_address().unwrap_or_revert();
+    only_guardian(guardian);
+
+    let minter: Key = runtime::get_named_arg(MINTER);
+    remove_minter_util(minter);
+    set_minter_allowed(minter, U256::zero());
+    events::emit_event(Event::MinterRemoved(MinterRemoved { minter }));
+    events::emit_event(Event::GuardianMinterRemoved(GuardianMinterRemoved {
+        guardian,
         minter,
-        amount: amount_to_burn,
     }));
 }
 
+#[no_mangle]
+pub extern "C" fn controller() {
+    runtime::ret(CLValue::from_t(utils::read_from::<Key>(CONTROLLER)).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn update_controller() {
+    only_owner();
+
+    let new_controller: Key = runtime::get_named_arg(NEW);
+    run_owner_action(OwnerAction::UpdateController(new_controller));
+}
+
+#[no_mangle]
+pub extern "C" fn is_controllable() {
+    let is_controllable = !utils::read_from::<bool>(CONTROLLER_DISABLED);
+    runtime::ret(CLValue::from_t(is_controllable).unwrap_or_revert());
+}
+
+/// Permanently turns off `controller_transfer`. This can't be undone, so it is an owner action
+/// like any other and needs a proposal or the timelock once either is configured.
+#[no_mangle]
+pub extern "C" fn disable_controller() {
+    only_owner();
+
+    run_owner_action(OwnerAction::DisableController);
+}
+
+/// Moves `amount` from `from` to `to` without their approval, for court orders and recovery of
+/// lost accounts. `from` may be blacklisted, `to` may not.
+#[no_mangle]
+pub extern "C" fn controller_transfer() {
+    when_not_paused();
+    only_controller();
+    if utils::read_from::<bool>(CONTROLLER_DISABLED) {
+        revert(CsprUSDError::ControllerDisabled);
+    }
+
+    let controller: Key = get_immediate_caller_address().unwrap_or_revert();
+    let from: Key = runtime::get_named_arg(FROM);
+    let to: Key = runtime::get_named_arg(TO);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    let legal_reference: String = runtime::get_named_arg(LEGAL_REFERENCE);
+
+    if is_blacklisted_util(to) {
+        revert(CsprUSDError::BlackListedAccount);
+    }
+    assert_not_custody(to);
+    if from == to {
+        revert(CsprUSDError::CannotTargetSelfUser);
+    }
+    if amount.is_zero() {
+        revert(CsprUSDError::CannotTransferZeroAmount);
+    }
+    if from == custody_key() {
+        revert(CsprUSDError::CustodyFundsLocked);
+    }
+
+    transfer_balance(from, to, amount).unwrap_or_revert();
+    emit_cep18_balance_change(BalanceChange::Transfer {
+        sender: from,
+        recipient: to,
+        amount,
+    });
+    emit_controller_transfer(controller, from, to, amount, legal_reference);
+}
+
+#[no_mangle]
+pub extern "C" fn rescuable_balance() {
+    runtime::ret(CLValue::from_t(rescuable_balance_util()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn rescue_tokens() {
+    only_owner();
+
+    let to: Key = runtime::get_named_arg(TO);
+    let amount: U256 = runtime::get_named_arg(AMOUNT);
+    run_owner_action(OwnerAction::RescueTokens(to, amount));
+}
+
+/// Lets `upgrader` add one new version of the contract, see `upgrade_contract`.
+#[no_mangle]
+pub extern "C" fn authorize_upgrade() {
+    only_owner();
+
+    let upgrader: Key = runtime::get_named_arg(UPGRADER);
+    run_owner_action(OwnerAction::AuthorizeUpgrade(upgrader));
+}
+
+/// Hands the package access URef to the account named by the last `authorize_upgrade`, together
+/// with whether CEP-18 compatibility is enabled, and uses up the authorization. Called by
+/// `upgrade_contract` in the upgrading account's session.
+#[no_mangle]
+pub extern "C" fn upgrade_access() {
+    let caller: Key = get_immediate_caller_address().unwrap_or_revert();
+    if utils::read_from::<Option<Key>>(UPGRADER) != Some(caller) {
+        revert(CsprUSDError::UpgradeNotAuthorized);
+    }
+    storage::write(get_uref(UPGRADER), None::<Key>);
+
+    let access_uref = get_uref(CONTRACT_ACCESS);
+    runtime::ret(CLValue::from_t((access_uref, cep18_compatibility_enabled())).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn init() {
     let package_hash = runtime::get_named_arg::<Key>(PACKAGE_HASH);
@@ -507,10 +2036,53 @@ pub extern "C" fn init() {
 
     storage::new_dictionary(ALLOWANCES)
         .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(ALLOWANCE_EXPIRIES)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(ALLOWANCE_SPENDERS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(ALLOWANCE_SPENDER_COUNTS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(ALLOWANCE_SPENDER_INDICES)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
     storage::new_dictionary(BALANCES).unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
     storage::new_dictionary(MINTERS).unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
     storage::new_dictionary(MINTER_ALLOWED)
         .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(MINT_REFERENCES)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+
+    storage::new_dictionary(CHECKPOINTS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(CHECKPOINT_COUNTS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(SNAPSHOTS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(SNAPSHOT_COUNTS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(FEE_EXEMPT)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(ESCROWS).unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(VESTING_SCHEDULES)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(STREAMS).unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_HOLDER_TO_INDEX)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(DICT_INDEX_TO_HOLDER)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(BRIDGE_MINT_CAPS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(BRIDGE_MINTED)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(CONSUMED_BRIDGE_NONCES)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(ATTESTATIONS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(PROPOSALS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(PROPOSAL_APPROVALS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
+    storage::new_dictionary(QUEUED_ACTIONS)
+        .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
 
     storage::new_dictionary(DICT_INDEX_TO_BLACKLISTED_ADDR)
         .unwrap_or_revert_with(CsprUSDError::FailedToCreateDictionary);
@@ -520,7 +2092,22 @@ pub extern "C" fn init() {
     let master_minter: Key = runtime::get_named_arg(MASTER_MINTER);
     add_minter(master_minter);
 
-    init_events();
+    init_events(cep18_compatibility_enabled(), events_schema_version());
+
+    // CEP-18 install arguments, which are empty unless compatibility is enabled
+    let minter_list: Vec<Key> = runtime::get_named_arg(MINTER_LIST);
+    for minter in minter_list {
+        add_unlimited_minter(minter);
+    }
+    let initial_supply: U256 = runtime::get_named_arg(TOTAL_SUPPLY);
+    if !initial_supply.is_zero() {
+        let installer = get_immediate_caller_address().unwrap_or_revert();
+        mint_balance(installer, initial_supply).unwrap_or_revert();
+        emit_cep18_balance_change(BalanceChange::Mint {
+            recipient: installer,
+            amount: initial_supply,
+        });
+    }
 }
 
 pub fn install_contract() {
@@ -532,6 +2119,22 @@ pub fn install_contract() {
     let pauser: PublicKey = runtime::get_named_arg(PAUSER);
     let blacklister: PublicKey = runtime::get_named_arg(BLACKLISTER);
     let owner: Key = runtime::get_named_arg(OWNER);
+    let enable_holder_registry: bool =
+        get_optional_named_arg(ENABLE_HOLDER_REGISTRY).unwrap_or(true);
+    let enable_cep18_compatibility: bool =
+        get_optional_named_arg(ENABLE_CEP18_COMPATIBILITY).unwrap_or(false);
+    let events_schema_version: u8 =
+        get_optional_named_arg(EVENTS_SCHEMA_VERSION).unwrap_or(EVENTS_SCHEMA_V1);
+    assert_supported_events_schema(events_schema_version, enable_cep18_compatibility);
+    let (initial_supply, minter_list): (U256, Vec<Key>) = if enable_cep18_compatibility {
+        assert_supported_cep18_install_args();
+        (
+            get_optional_named_arg(TOTAL_SUPPLY).unwrap_or_default(),
+            get_optional_named_arg(MINTER_LIST).unwrap_or_default(),
+        )
+    } else {
+        (U256::zero(), Vec::new())
+    };
 
     let mut named_keys = NamedKeys::new();
     named_keys.insert(NAME.to_string(), storage::new_uref(name).into());
@@ -558,27 +2161,153 @@ pub fn install_contract() {
         BLACKLISTED_ADDRESSES_COUNT.to_string(),
         storage::new_uref(0u32).into(),
     );
+    named_keys.insert(SNAPSHOTTER.to_string(), storage::new_uref(owner).into());
+    named_keys.insert(
+        CURRENT_SNAPSHOT_ID.to_string(),
+        storage::new_uref(0u64).into(),
+    );
+    named_keys.insert(FEE_BPS.to_string(), storage::new_uref(0u32).into());
+    named_keys.insert(MAX_FEE.to_string(), storage::new_uref(U256::zero()).into());
+    named_keys.insert(FEE_RECIPIENT.to_string(), storage::new_uref(owner).into());
+    named_keys.insert(FLASH_FEE_BPS.to_string(), storage::new_uref(0u32).into());
+    named_keys.insert(
+        MAX_FLASH_LOAN.to_string(),
+        storage::new_uref(U256::zero()).into(),
+    );
+    named_keys.insert(
+        MAX_MEMO_LENGTH.to_string(),
+        storage::new_uref(DEFAULT_MAX_MEMO_LENGTH).into(),
+    );
+    named_keys.insert(
+        CUSTODY_LOCKED.to_string(),
+        storage::new_uref(U256::zero()).into(),
+    );
+    named_keys.insert(ESCROW_COUNT.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(VESTING_ADMIN.to_string(), storage::new_uref(owner).into());
+    named_keys.insert(
+        VESTING_SCHEDULE_COUNT.to_string(),
+        storage::new_uref(0u64).into(),
+    );
+    named_keys.insert(STREAM_COUNT.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(
+        HOLDER_REGISTRY_ENABLED.to_string(),
+        storage::new_uref(enable_holder_registry).into(),
+    );
+    named_keys.insert(HOLDER_COUNT.to_string(), storage::new_uref(0u32).into());
+    named_keys.insert(
+        CEP18_COMPATIBILITY_ENABLED.to_string(),
+        storage::new_uref(enable_cep18_compatibility).into(),
+    );
+    // CEP-18 tokens expose how they emit events
+    if enable_cep18_compatibility {
+        named_keys.insert(
+            EVENTS_MODE.to_string(),
+            storage::new_uref(CES_EVENTS_MODE).into(),
+        );
+    }
+    named_keys.insert(
+        EVENTS_SCHEMA_VERSION.to_string(),
+        storage::new_uref(events_schema_version).into(),
+    );
+    named_keys.insert(BRIDGE_OPERATOR.to_string(), storage::new_uref(owner).into());
+    named_keys.insert(BRIDGE_NONCE.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(
+        RESERVE_ORACLE.to_string(),
+        storage::new_uref(None::<Key>).into(),
+    );
+    named_keys.insert(RESERVE_MAX_AGE.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(ATTESTOR.to_string(), storage::new_uref(owner).into());
+    named_keys.insert(
+        ATTESTATION_COUNT.to_string(),
+        storage::new_uref(0u64).into(),
+    );
+    named_keys.insert(
+        MULTISIG_SIGNERS.to_string(),
+        storage::new_uref(Vec::<Key>::new()).into(),
+    );
+    named_keys.insert(
+        MULTISIG_THRESHOLD.to_string(),
+        storage::new_uref(0u32).into(),
+    );
+    named_keys.insert(PROPOSAL_COUNT.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(TIMELOCK_DELAY.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(CONTROLLER.to_string(), storage::new_uref(owner).into());
+    named_keys.insert(
+        CONTROLLER_DISABLED.to_string(),
+        storage::new_uref(false).into(),
+    );
+    named_keys.insert(
+        GUARDIANS.to_string(),
+        storage::new_uref(Vec::<Key>::new()).into(),
+    );
+    named_keys.insert(
+        QUEUED_ACTION_COUNT.to_string(),
+        storage::new_uref(0u64).into(),
+    );
+    named_keys.insert(UPGRADER.to_string(), storage::new_uref(None::<Key>).into());
+    named_keys.insert(
+        MULTISIG_CONFIG_NONCE.to_string(),
+        storage::new_uref(0u64).into(),
+    );
 
-    let entry_points = generate_entry_points();
+    let entry_points = generate_entry_points(enable_cep18_compatibility);
 
-    let (contract_hash, contract_version) = storage::new_contract(
-        entry_points,
-        Some(named_keys),
-        Some(CONTRACT_PACKAGE_HASH.to_string()),
-        Some(CONTRACT_ACCESS.to_string()),
-    );
+    // Only the contract keeps the package access URef, so that upgrades go through
+    // `upgrade_access` instead of being up to whoever holds the installing account.
+    let (package_hash, access_uref) = storage::create_contract_package_at_hash();
+    named_keys.insert(CONTRACT_ACCESS.to_string(), access_uref.into());
+    let (contract_hash, contract_version) =
+        storage::add_contract_version(package_hash, entry_points, named_keys);
 
     // Store contract_hash and contract_version under the keys CONTRACT_NAME and CONTRACT_VERSION
+    runtime::put_key(CONTRACT_PACKAGE_HASH, package_hash.into());
     runtime::put_key(CONTRACT_HASH, contract_hash.into());
     runtime::put_key(CONTRACT_VERSION, storage::new_uref(contract_version).into());
 
     // Call contract to initialize it
-    let package_hash = runtime::get_key(CONTRACT_PACKAGE_HASH).unwrap_or_revert();
-    let init_args = runtime_args! { MASTER_MINTER => master_minter, PACKAGE_HASH => package_hash};
+    let package_hash = Key::from(package_hash);
+    let init_args = runtime_args! {
+        MASTER_MINTER => master_minter,
+        PACKAGE_HASH => package_hash,
+        MINTER_LIST => minter_list,
+        TOTAL_SUPPLY => initial_supply,
+    };
     runtime::call_contract::<()>(contract_hash, INIT_ENTRY_POINT_NAME, init_args);
 }
 
+/// Adds a new version of the token installed by the calling account and disables the current
+/// one. The owner must first have named the account with `authorize_upgrade`. Named keys carry
+/// over from the current version.
+pub fn upgrade_contract() {
+    let package_hash = runtime::get_key(CONTRACT_PACKAGE_HASH)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert_with(CsprUSDError::PackageHashMissing);
+    let current_contract_hash = runtime::get_key(CONTRACT_HASH)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .unwrap_or_revert();
+
+    // grants this session the package access URef, which adding and disabling versions requires
+    let (_access_uref, enable_cep18_compatibility): (URef, bool) = runtime::call_contract(
+        current_contract_hash,
+        UPGRADE_ACCESS_ENTRY_POINT_NAME,
+        runtime_args! {},
+    );
+
+    let entry_points = generate_entry_points(enable_cep18_compatibility);
+    let (contract_hash, contract_version) =
+        storage::add_contract_version(package_hash, entry_points, NamedKeys::new());
+    storage::disable_contract_version(package_hash, current_contract_hash).unwrap_or_revert();
+
+    runtime::put_key(CONTRACT_HASH, contract_hash.into());
+    runtime::put_key(CONTRACT_VERSION, storage::new_uref(contract_version).into());
+}
+
 #[no_mangle]
 pub extern "C" fn call() {
-    install_contract()
+    match runtime::get_key(CONTRACT_VERSION) {
+        None => install_contract(),
+        Some(_) => upgrade_contract(),
+    }
 }
@@ -5,7 +5,8 @@ use casper_contract::{
 use casper_types::{bytesrepr::ToBytes, Key, U256};
 
 use crate::{
-    constants::{MINTERS, MINTER_ALLOWED},
+    constants::{MINTERS, MINTER_ALLOWED, MINT_REFERENCES},
+    error::CsprUSDError,
     utils::get_uref,
 };
 
@@ -50,3 +51,24 @@ pub(crate) fn remove_minter_util(minter: Key) {
 
     storage::dictionary_put(dict_seed, &dict_key, false);
 }
+
+pub(crate) fn is_mint_reference_consumed_util(reference: &str) -> bool {
+    let dict_seed = get_uref(MINT_REFERENCES);
+    let dict_key = hex::encode(runtime::blake2b(reference));
+
+    storage::dictionary_get(dict_seed, &dict_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Marks an off-chain deposit `reference` as minted, failing if it was minted before.
+pub(crate) fn consume_mint_reference(reference: &str) -> Result<(), CsprUSDError> {
+    if is_mint_reference_consumed_util(reference) {
+        return Err(CsprUSDError::DuplicateMintReference);
+    }
+
+    let dict_seed = get_uref(MINT_REFERENCES);
+    let dict_key = hex::encode(runtime::blake2b(reference));
+    storage::dictionary_put(dict_seed, &dict_key, true);
+    Ok(())
+}
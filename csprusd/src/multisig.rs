@@ -0,0 +1,198 @@
+//! M-of-N proposals for owner actions, see `OwnerAction`.
+//!
+//! Signers propose an owner action, approve it, and once approvals from at least `threshold`
+//! current signers are recorded any signer can execute it before it expires. Approvals are counted
+//! against the signer set at execution time, so removing a signer also drops their approvals.
+//! Approvals are also keyed by the configuration nonce, which every signer-set change bumps, so
+//! reconfiguring discards all approvals given so far and a re-added signer starts from scratch.
+//!
+//! The owner key configures the first signer set. From then on owner actions, including changes
+//! to the signer set, can only be performed through proposals, so a single leaked owner key can't
+//! bypass the threshold.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{
+        runtime::{self, revert},
+        storage,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{Bytes, ToBytes},
+    Key,
+};
+
+use crate::{
+    constants::{
+        MULTISIG_CONFIG_NONCE, MULTISIG_SIGNERS, MULTISIG_THRESHOLD, PROPOSALS, PROPOSAL_APPROVALS,
+        PROPOSAL_COUNT,
+    },
+    error::CsprUSDError,
+    owner_actions::OwnerAction,
+    utils::{get_uref, now, read_from, write_to},
+};
+
+/// Maximum number of multisig signers.
+pub(crate) const MAX_MULTISIG_SIGNERS: usize = 20;
+
+/// Stored form of a proposal: `((proposer, action, data), (expires_at, executed))`.
+pub(crate) type ProposalRecord = ((Key, u8, Bytes), (u64, bool));
+
+pub(crate) fn multisig_signers_util() -> Vec<Key> {
+    read_from(MULTISIG_SIGNERS)
+}
+
+pub(crate) fn multisig_threshold_util() -> u32 {
+    read_from(MULTISIG_THRESHOLD)
+}
+
+pub(crate) fn proposal_count_util() -> u64 {
+    read_from(PROPOSAL_COUNT)
+}
+
+/// Checks a signer set, which needs between 1 and `MAX_MULTISIG_SIGNERS` distinct signers and a
+/// threshold they can reach.
+pub(crate) fn check_multisig_config(signers: &[Key], threshold: u32) -> Result<(), CsprUSDError> {
+    let has_duplicates = signers
+        .iter()
+        .enumerate()
+        .any(|(index, signer)| signers[..index].contains(signer));
+    if signers.len() > MAX_MULTISIG_SIGNERS
+        || has_duplicates
+        || threshold == 0
+        || threshold as usize > signers.len()
+    {
+        return Err(CsprUSDError::InvalidMultisigConfig);
+    }
+    Ok(())
+}
+
+pub(crate) fn configure_multisig_util(signers: Vec<Key>, threshold: u32) {
+    check_multisig_config(&signers, threshold).unwrap_or_revert();
+    write_to(MULTISIG_SIGNERS, signers);
+    write_to(MULTISIG_THRESHOLD, threshold);
+    write_to(
+        MULTISIG_CONFIG_NONCE,
+        read_from::<u64>(MULTISIG_CONFIG_NONCE) + 1,
+    );
+}
+
+/// Reverts once a multisig is configured, after which owner actions need a proposal.
+pub(crate) fn assert_no_multisig() {
+    if multisig_threshold_util() != 0 {
+        revert(CsprUSDError::MultisigRequired);
+    }
+}
+
+pub(crate) fn only_multisig_signer(caller: Key) {
+    if !multisig_signers_util().contains(&caller) {
+        revert(CsprUSDError::NotMultisigSigner);
+    }
+}
+
+pub(crate) fn read_proposal_record(proposal_id: u64) -> ProposalRecord {
+    storage::dictionary_get(get_uref(PROPOSALS), &proposal_id.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(CsprUSDError::ProposalNotFound)
+}
+
+fn write_proposal_record(proposal_id: u64, record: ProposalRecord) {
+    storage::dictionary_put(get_uref(PROPOSALS), &proposal_id.to_string(), record);
+}
+
+fn approval_item_key(proposal_id: u64, signer: Key) -> String {
+    let config_nonce: u64 = read_from(MULTISIG_CONFIG_NONCE);
+    let mut preimage = config_nonce.to_bytes().unwrap_or_revert();
+    preimage.append(&mut proposal_id.to_bytes().unwrap_or_revert());
+    preimage.append(&mut signer.to_bytes().unwrap_or_revert());
+    hex::encode(runtime::blake2b(&preimage))
+}
+
+pub(crate) fn has_approved_util(proposal_id: u64, signer: Key) -> bool {
+    storage::dictionary_get(
+        get_uref(PROPOSAL_APPROVALS),
+        &approval_item_key(proposal_id, signer),
+    )
+    .unwrap_or_revert()
+    .unwrap_or_default()
+}
+
+fn write_approval(proposal_id: u64, signer: Key, approved: bool) {
+    storage::dictionary_put(
+        get_uref(PROPOSAL_APPROVALS),
+        &approval_item_key(proposal_id, signer),
+        approved,
+    );
+}
+
+/// Returns how many of the current signers approved the proposal.
+pub(crate) fn approval_count_util(proposal_id: u64) -> u32 {
+    multisig_signers_util()
+        .into_iter()
+        .filter(|signer| has_approved_util(proposal_id, *signer))
+        .count() as u32
+}
+
+/// Reverts unless the proposal can still be approved or executed.
+fn assert_pending(record: &ProposalRecord) {
+    let (_, (expires_at, executed)) = record;
+    if *executed {
+        revert(CsprUSDError::ProposalAlreadyExecuted);
+    }
+    if now() >= *expires_at {
+        revert(CsprUSDError::ProposalExpired);
+    }
+}
+
+/// Stores a new proposal approved by its proposer and returns its id.
+pub(crate) fn propose_util(proposer: Key, action: u8, data: Bytes, expires_at: u64) -> u64 {
+    OwnerAction::decode(action, &data)
+        .and_then(|owner_action| owner_action.validate())
+        .unwrap_or_revert();
+    if expires_at <= now() {
+        revert(CsprUSDError::ProposalExpired);
+    }
+
+    let proposal_id = proposal_count_util() + 1;
+    write_proposal_record(proposal_id, ((proposer, action, data), (expires_at, false)));
+    write_to(PROPOSAL_COUNT, proposal_id);
+    write_approval(proposal_id, proposer, true);
+    proposal_id
+}
+
+pub(crate) fn approve_proposal_util(proposal_id: u64, signer: Key) {
+    assert_pending(&read_proposal_record(proposal_id));
+    if has_approved_util(proposal_id, signer) {
+        revert(CsprUSDError::AlreadyApproved);
+    }
+    write_approval(proposal_id, signer, true);
+}
+
+pub(crate) fn revoke_approval_util(proposal_id: u64, signer: Key) {
+    assert_pending(&read_proposal_record(proposal_id));
+    if !has_approved_util(proposal_id, signer) {
+        revert(CsprUSDError::NotApproved);
+    }
+    write_approval(proposal_id, signer, false);
+}
+
+/// Marks the proposal as executed and returns its action and data, which the caller must then
+/// apply.
+pub(crate) fn execute_proposal_util(proposal_id: u64) -> (u8, Bytes) {
+    let record = read_proposal_record(proposal_id);
+    assert_pending(&record);
+    if approval_count_util(proposal_id) < multisig_threshold_util() {
+        revert(CsprUSDError::ThresholdNotReached);
+    }
+
+    let ((proposer, action, data), (expires_at, _)) = record;
+    write_proposal_record(
+        proposal_id,
+        ((proposer, action, data.clone()), (expires_at, true)),
+    );
+    (action, data)
+}
@@ -0,0 +1,116 @@
+//! Privileged configuration changes that can be proposed by the multisig or queued in the
+//! timelock.
+//!
+//! Outside of entry point arguments an action is identified by its discriminant and its arguments
+//! serialized with `ToBytes`, as a tuple when there are several.
+use alloc::vec::Vec;
+
+use casper_types::{
+    bytesrepr::{self, FromBytes},
+    Key, PublicKey, U256,
+};
+
+use crate::{
+    error::CsprUSDError, fees::check_fee_bps, guardians::check_guardians,
+    multisig::check_multisig_config, timelock::check_timelock_delay,
+};
+
+pub(crate) enum OwnerAction {
+    UpdatePauser(PublicKey),
+    UpdateBlacklister(PublicKey),
+    UpdateMasterMinter(Key),
+    TransferOwnership(Key),
+    ConfigureMultisig(Vec<Key>, u32),
+    UpdateSnapshotter(Key),
+    SetFee(u32, U256),
+    SetFeeRecipient(Key),
+    SetFeeExempt(Key, bool),
+    SetFlashLoanConfig(u32, U256),
+    UpdateVestingAdmin(Key),
+    UpdateBridgeOperator(Key),
+    SetBridgeMintCap(u64, U256),
+    UpdateAttestor(Key),
+    SetReserveOracle(Option<Key>, u64),
+    SetTimelockDelay(u64),
+    SetMaxMemoLength(u32),
+    SetGuardians(Vec<Key>),
+    UpdateController(Key),
+    RescueTokens(Key, U256),
+    CancelQueuedAction(u64),
+    DisableController,
+    AuthorizeUpgrade(Key),
+}
+
+fn deserialize_all<T: FromBytes>(data: &[u8]) -> Result<T, CsprUSDError> {
+    bytesrepr::deserialize_from_slice(data).map_err(|_| CsprUSDError::InvalidOwnerAction)
+}
+
+impl OwnerAction {
+    pub(crate) fn decode(action: u8, data: &[u8]) -> Result<Self, CsprUSDError> {
+        Ok(match action {
+            0 => OwnerAction::UpdatePauser(deserialize_all(data)?),
+            1 => OwnerAction::UpdateBlacklister(deserialize_all(data)?),
+            2 => OwnerAction::UpdateMasterMinter(deserialize_all(data)?),
+            3 => OwnerAction::TransferOwnership(deserialize_all(data)?),
+            4 => {
+                let (signers, threshold) = deserialize_all(data)?;
+                OwnerAction::ConfigureMultisig(signers, threshold)
+            }
+            5 => OwnerAction::UpdateSnapshotter(deserialize_all(data)?),
+            6 => {
+                let (fee_bps, max_fee) = deserialize_all(data)?;
+                OwnerAction::SetFee(fee_bps, max_fee)
+            }
+            7 => OwnerAction::SetFeeRecipient(deserialize_all(data)?),
+            8 => {
+                let (key, exempt) = deserialize_all(data)?;
+                OwnerAction::SetFeeExempt(key, exempt)
+            }
+            9 => {
+                let (flash_fee_bps, max_flash_loan) = deserialize_all(data)?;
+                OwnerAction::SetFlashLoanConfig(flash_fee_bps, max_flash_loan)
+            }
+            10 => OwnerAction::UpdateVestingAdmin(deserialize_all(data)?),
+            11 => OwnerAction::UpdateBridgeOperator(deserialize_all(data)?),
+            12 => {
+                let (chain_id, cap) = deserialize_all(data)?;
+                OwnerAction::SetBridgeMintCap(chain_id, cap)
+            }
+            13 => OwnerAction::UpdateAttestor(deserialize_all(data)?),
+            14 => {
+                let (oracle, max_age) = deserialize_all(data)?;
+                OwnerAction::SetReserveOracle(oracle, max_age)
+            }
+            15 => OwnerAction::SetTimelockDelay(deserialize_all(data)?),
+            16 => OwnerAction::SetMaxMemoLength(deserialize_all(data)?),
+            17 => OwnerAction::SetGuardians(deserialize_all(data)?),
+            18 => OwnerAction::UpdateController(deserialize_all(data)?),
+            19 => {
+                let (to, amount) = deserialize_all(data)?;
+                OwnerAction::RescueTokens(to, amount)
+            }
+            20 => OwnerAction::CancelQueuedAction(deserialize_all(data)?),
+            21 => {
+                deserialize_all::<()>(data)?;
+                OwnerAction::DisableController
+            }
+            22 => OwnerAction::AuthorizeUpgrade(deserialize_all(data)?),
+            _ => return Err(CsprUSDError::InvalidOwnerAction),
+        })
+    }
+
+    /// Checks the bounds that don't depend on contract state, so that an invalid action is
+    /// rejected when it is proposed or queued rather than when it is applied.
+    pub(crate) fn validate(&self) -> Result<(), CsprUSDError> {
+        match self {
+            OwnerAction::ConfigureMultisig(signers, threshold) => {
+                check_multisig_config(signers, *threshold)
+            }
+            OwnerAction::SetFee(fee_bps, _) => check_fee_bps(*fee_bps),
+            OwnerAction::SetFlashLoanConfig(flash_fee_bps, _) => check_fee_bps(*flash_fee_bps),
+            OwnerAction::SetTimelockDelay(delay) => check_timelock_delay(*delay),
+            OwnerAction::SetGuardians(guardians) => check_guardians(guardians),
+            _ => Ok(()),
+        }
+    }
+}
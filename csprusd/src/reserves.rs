@@ -0,0 +1,65 @@
+//! Proof-of-reserve check on minting.
+//!
+//! When a reserve oracle is configured, minting and bridge minting ask the oracle package's `latest_reserves` entry
+//! point for `(reserves, decimals, updated_at)` and reverts unless the report is at most
+//! `max_age` milliseconds old and covers the total supply after the mint. Reserves are converted
+//! from the oracle's decimals to the token's before comparing.
+use casper_contract::{
+    contract_api::runtime::{self, revert},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{runtime_args, ContractPackageHash, Key, RuntimeArgs, U256};
+
+use crate::{
+    constants::{DECIMALS, RESERVE_MAX_AGE, RESERVE_ORACLE},
+    error::CsprUSDError,
+    utils::{get_total_supply_uref, now, read_from, read_total_supply_from},
+};
+
+const LATEST_RESERVES_ENTRY_POINT_NAME: &str = "latest_reserves";
+
+/// Converts `amount` from `from_decimals` to `to_decimals`, rounding down.
+fn scale_decimals(amount: U256, from_decimals: u8, to_decimals: u8) -> U256 {
+    let ten = U256::from(10);
+    if from_decimals < to_decimals {
+        // reserves this large cover any supply, so saturating is fine
+        ten.checked_pow(U256::from(to_decimals - from_decimals))
+            .map(|factor| amount.saturating_mul(factor))
+            .unwrap_or(if amount.is_zero() { amount } else { U256::MAX })
+    } else {
+        ten.checked_pow(U256::from(from_decimals - to_decimals))
+            .map(|divisor| amount / divisor)
+            .unwrap_or_default()
+    }
+}
+
+/// Reverts if minting `amount` would push the total supply above the attested reserves.
+pub(crate) fn check_reserves(amount: U256) {
+    let oracle: Option<Key> = read_from(RESERVE_ORACLE);
+    let oracle_package = match oracle.and_then(|oracle| oracle.into_hash()) {
+        Some(hash) => ContractPackageHash::new(hash),
+        None => return,
+    };
+
+    let (reserves, reserve_decimals, updated_at): (U256, u8, u64) =
+        runtime::call_versioned_contract(
+            oracle_package,
+            None,
+            LATEST_RESERVES_ENTRY_POINT_NAME,
+            runtime_args! {},
+        );
+
+    let max_age: u64 = read_from(RESERVE_MAX_AGE);
+    if now().saturating_sub(updated_at) > max_age {
+        revert(CsprUSDError::StaleReserves);
+    }
+
+    let new_total_supply = read_total_supply_from(get_total_supply_uref())
+        .checked_add(amount)
+        .ok_or(CsprUSDError::Overflow)
+        .unwrap_or_revert();
+    let decimals: u8 = read_from(DECIMALS);
+    if new_total_supply > scale_decimals(reserves, reserve_decimals, decimals) {
+        revert(CsprUSDError::InsufficientReserves);
+    }
+}
@@ -0,0 +1,121 @@
+//! ERC-1404-style transfer restriction detection.
+use alloc::string::{String, ToString};
+
+use casper_types::{Key, U256};
+
+use crate::{
+    balances::{get_balances_uref, read_balance_from},
+    blacklisting::is_blacklisted_util,
+    constants::IS_PAUSED,
+    custody::custody_key,
+    error::CsprUSDError,
+    utils::read_from,
+};
+
+/// Reasons a `transfer` would be rejected, in the order `transfer` checks them.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransferRestriction {
+    /// Transfer would succeed.
+    Success = 0,
+    /// Contract is currently paused.
+    ContractPaused = 1,
+    /// Sender is blacklisted.
+    SenderBlacklisted = 2,
+    /// Recipient is blacklisted.
+    RecipientBlacklisted = 3,
+    /// Sender and recipient are the same key.
+    CannotTargetSelfUser = 4,
+    /// Amount is zero.
+    ZeroAmount = 5,
+    /// Sender does not have enough balance.
+    InsufficientBalance = 6,
+    /// Recipient is the token contract's own package, where tokens would be stranded.
+    RecipientIsContract = 7,
+}
+
+impl TransferRestriction {
+    fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(TransferRestriction::Success),
+            1 => Some(TransferRestriction::ContractPaused),
+            2 => Some(TransferRestriction::SenderBlacklisted),
+            3 => Some(TransferRestriction::RecipientBlacklisted),
+            4 => Some(TransferRestriction::CannotTargetSelfUser),
+            5 => Some(TransferRestriction::ZeroAmount),
+            6 => Some(TransferRestriction::InsufficientBalance),
+            7 => Some(TransferRestriction::RecipientIsContract),
+            _ => None,
+        }
+    }
+
+    /// Returns the error `transfer` reverts with for this restriction, if any.
+    pub(crate) fn error(self) -> Option<CsprUSDError> {
+        match self {
+            TransferRestriction::Success => None,
+            TransferRestriction::ContractPaused => Some(CsprUSDError::ContractPaused),
+            TransferRestriction::SenderBlacklisted | TransferRestriction::RecipientBlacklisted => {
+                Some(CsprUSDError::BlackListedAccount)
+            }
+            TransferRestriction::CannotTargetSelfUser => Some(CsprUSDError::CannotTargetSelfUser),
+            TransferRestriction::ZeroAmount => Some(CsprUSDError::CannotTransferZeroAmount),
+            TransferRestriction::InsufficientBalance => Some(CsprUSDError::InsufficientBalance),
+            TransferRestriction::RecipientIsContract => {
+                Some(CsprUSDError::CannotTransferToContract)
+            }
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            TransferRestriction::Success => "SUCCESS",
+            TransferRestriction::ContractPaused => "Contract is paused",
+            TransferRestriction::SenderBlacklisted => "Sender is blacklisted",
+            TransferRestriction::RecipientBlacklisted => "Recipient is blacklisted",
+            TransferRestriction::CannotTargetSelfUser => "Sender and recipient are the same",
+            TransferRestriction::ZeroAmount => "Cannot transfer zero amount",
+            TransferRestriction::InsufficientBalance => "Sender has insufficient balance",
+            TransferRestriction::RecipientIsContract => "Recipient is the token contract",
+        }
+    }
+}
+
+/// Runs the checks shared by `transfer` and `detect_transfer_restriction` without changing state
+/// and returns the first failing one.
+pub(crate) fn detect_transfer_restriction_util(
+    sender: Key,
+    recipient: Key,
+    amount: U256,
+) -> TransferRestriction {
+    if read_from::<bool>(IS_PAUSED) {
+        return TransferRestriction::ContractPaused;
+    }
+    if is_blacklisted_util(sender) {
+        return TransferRestriction::SenderBlacklisted;
+    }
+    if is_blacklisted_util(recipient) {
+        return TransferRestriction::RecipientBlacklisted;
+    }
+    if sender == recipient {
+        return TransferRestriction::CannotTargetSelfUser;
+    }
+    if amount.is_zero() {
+        return TransferRestriction::ZeroAmount;
+    }
+    // any fee is taken out of `amount`, so the sender is debited exactly `amount`
+    if read_balance_from(get_balances_uref(), sender) < amount {
+        return TransferRestriction::InsufficientBalance;
+    }
+    if recipient == custody_key() {
+        return TransferRestriction::RecipientIsContract;
+    }
+    TransferRestriction::Success
+}
+
+/// Returns a human readable message for a restriction code, or "UNKNOWN" for unassigned codes.
+pub(crate) fn message_for_transfer_restriction_util(code: u8) -> String {
+    TransferRestriction::from_code(code)
+        .map(|restriction| restriction.message())
+        .unwrap_or("UNKNOWN")
+        .to_string()
+}
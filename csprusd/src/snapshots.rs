@@ -0,0 +1,120 @@
+//! ERC20Snapshot-style historical balances and total supply.
+//!
+//! Values are checkpointed lazily: the first write to a balance (or to the total supply) after a
+//! snapshot was taken records the value it had at that snapshot. Reads for a snapshot id look up
+//! the first checkpoint taken at or after that id, falling back to the current value.
+use alloc::{format, string::String};
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    balances::{get_balances_uref, make_dictionary_item_key, read_balance_from},
+    constants::{CURRENT_SNAPSHOT_ID, SNAPSHOTS, SNAPSHOT_COUNTS, TOTAL_SUPPLY},
+    error::CsprUSDError,
+    utils::{get_uref, read_from, read_total_supply_from},
+};
+
+/// Checkpoint owner id used for the total supply.
+const TOTAL_SUPPLY_SNAPSHOT_ID: &str = TOTAL_SUPPLY;
+
+pub(crate) fn current_snapshot_id() -> u64 {
+    read_from(CURRENT_SNAPSHOT_ID)
+}
+
+/// Increments the current snapshot id and returns it.
+pub(crate) fn take_snapshot() -> u64 {
+    let new_snapshot_id = current_snapshot_id() + 1;
+    storage::write(get_uref(CURRENT_SNAPSHOT_ID), new_snapshot_id);
+    new_snapshot_id
+}
+
+fn read_checkpoint_count(owner_id: &str) -> u32 {
+    storage::dictionary_get(get_uref(SNAPSHOT_COUNTS), owner_id)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+fn read_checkpoint(owner_id: &str, index: u32) -> (u64, U256) {
+    storage::dictionary_get(get_uref(SNAPSHOTS), &checkpoint_item_key(owner_id, index))
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+fn checkpoint_item_key(owner_id: &str, index: u32) -> String {
+    format!("{}_{}", owner_id, index)
+}
+
+/// Records `value` as the value `owner_id` had at the current snapshot, unless already recorded.
+///
+/// `read_value` is only evaluated when a checkpoint is actually written.
+fn checkpoint<F: FnOnce() -> U256>(owner_id: &str, read_value: F) {
+    let snapshot_id = current_snapshot_id();
+    if snapshot_id == 0 {
+        return;
+    }
+
+    let count = read_checkpoint_count(owner_id);
+    if count > 0 && read_checkpoint(owner_id, count - 1).0 >= snapshot_id {
+        return;
+    }
+
+    storage::dictionary_put(
+        get_uref(SNAPSHOTS),
+        &checkpoint_item_key(owner_id, count),
+        (snapshot_id, read_value()),
+    );
+    storage::dictionary_put(get_uref(SNAPSHOT_COUNTS), owner_id, count + 1);
+}
+
+/// Returns the value `owner_id` had at `snapshot_id`, or `None` if it hasn't changed since.
+fn value_at(owner_id: &str, snapshot_id: u64) -> Option<U256> {
+    if snapshot_id == 0 || snapshot_id > current_snapshot_id() {
+        revert(CsprUSDError::InvalidSnapshotId);
+    }
+
+    // first checkpoint whose snapshot id is at or after the requested one
+    let mut low = 0u32;
+    let mut high = read_checkpoint_count(owner_id);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if read_checkpoint(owner_id, mid).0 < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low == read_checkpoint_count(owner_id) {
+        None
+    } else {
+        Some(read_checkpoint(owner_id, low).1)
+    }
+}
+
+/// Checkpoints the balance of `address` before it is overwritten.
+pub(crate) fn checkpoint_balance(address: Key) {
+    checkpoint(&make_dictionary_item_key(address), || {
+        read_balance_from(get_balances_uref(), address)
+    });
+}
+
+/// Checkpoints the total supply before it is overwritten.
+pub(crate) fn checkpoint_total_supply() {
+    checkpoint(TOTAL_SUPPLY_SNAPSHOT_ID, || {
+        read_total_supply_from(get_uref(TOTAL_SUPPLY))
+    });
+}
+
+pub(crate) fn balance_of_at_util(address: Key, snapshot_id: u64) -> U256 {
+    value_at(&make_dictionary_item_key(address), snapshot_id)
+        .unwrap_or_else(|| read_balance_from(get_balances_uref(), address))
+}
+
+pub(crate) fn total_supply_at_util(snapshot_id: u64) -> U256 {
+    value_at(TOTAL_SUPPLY_SNAPSHOT_ID, snapshot_id)
+        .unwrap_or_else(|| read_total_supply_from(get_uref(TOTAL_SUPPLY)))
+}
@@ -0,0 +1,181 @@
+//! Continuous payment streams paying `rate_per_second` from `start` until `stop`.
+//!
+//! `start` and `stop` are block times in milliseconds, as returned by `runtime::get_blocktime`,
+//! and must be whole seconds apart so that the deposit is exactly `rate_per_second` times the
+//! stream's length in seconds. The deposit is held in custody until it is withdrawn by the
+//! recipient or split between both parties on cancellation.
+use alloc::string::ToString;
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{STREAMS, STREAM_COUNT},
+    custody::{lock_in_custody, release_from_custody},
+    error::CsprUSDError,
+    utils::{get_uref, now, read_from, write_to},
+};
+
+const MILLIS_PER_SECOND: u64 = 1_000;
+
+/// Stored form of a stream:
+/// `((sender, recipient, active), (rate_per_second, deposit, withdrawn), (start, stop))`.
+pub(crate) type StreamRecord = ((Key, Key, bool), (U256, U256, U256), (u64, u64));
+
+pub(crate) struct Stream {
+    pub(crate) sender: Key,
+    pub(crate) recipient: Key,
+    pub(crate) active: bool,
+    pub(crate) rate_per_second: U256,
+    pub(crate) deposit: U256,
+    pub(crate) withdrawn: U256,
+    pub(crate) start: u64,
+    pub(crate) stop: u64,
+}
+
+impl Stream {
+    fn to_record(&self) -> StreamRecord {
+        (
+            (self.sender, self.recipient, self.active),
+            (self.rate_per_second, self.deposit, self.withdrawn),
+            (self.start, self.stop),
+        )
+    }
+
+    fn from_record(record: StreamRecord) -> Self {
+        let ((sender, recipient, active), (rate_per_second, deposit, withdrawn), (start, stop)) =
+            record;
+        Stream {
+            sender,
+            recipient,
+            active,
+            rate_per_second,
+            deposit,
+            withdrawn,
+            start,
+            stop,
+        }
+    }
+
+    /// Returns the amount streamed to the recipient by `timestamp`, including withdrawals.
+    fn streamed_at(&self, timestamp: u64) -> U256 {
+        if timestamp <= self.start {
+            return U256::zero();
+        }
+        if timestamp >= self.stop {
+            return self.deposit;
+        }
+
+        let elapsed = timestamp - self.start;
+        let seconds = U256::from(elapsed / MILLIS_PER_SECOND);
+        let millis = U256::from(elapsed % MILLIS_PER_SECOND);
+        let millis_per_second = U256::from(MILLIS_PER_SECOND);
+        // split the multiplication so that it can't overflow for large rates
+        self.rate_per_second * seconds
+            + self.rate_per_second / millis_per_second * millis
+            + self.rate_per_second % millis_per_second * millis / millis_per_second
+    }
+
+    /// Returns what `address` would receive if the stream was cancelled at `timestamp`.
+    pub(crate) fn balance_of_at(&self, address: Key, timestamp: u64) -> U256 {
+        if !self.active {
+            return U256::zero();
+        }
+
+        let streamed = self.streamed_at(timestamp);
+        if address == self.recipient {
+            streamed - self.withdrawn
+        } else if address == self.sender {
+            self.deposit - streamed
+        } else {
+            U256::zero()
+        }
+    }
+}
+
+pub(crate) fn stream_count_util() -> u64 {
+    read_from(STREAM_COUNT)
+}
+
+pub(crate) fn read_stream(stream_id: u64) -> Stream {
+    let record: StreamRecord = storage::dictionary_get(get_uref(STREAMS), &stream_id.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(CsprUSDError::StreamNotFound);
+    Stream::from_record(record)
+}
+
+fn write_stream(stream_id: u64, stream: &Stream) {
+    storage::dictionary_put(
+        get_uref(STREAMS),
+        &stream_id.to_string(),
+        stream.to_record(),
+    );
+}
+
+/// Moves the deposit from the sender into custody and stores the stream under a new id.
+pub(crate) fn create_stream_util(stream: Stream) -> u64 {
+    if stream.stop <= stream.start || stream.start < now() {
+        revert(CsprUSDError::InvalidStream);
+    }
+    let duration = stream.stop - stream.start;
+    if duration % MILLIS_PER_SECOND != 0 {
+        revert(CsprUSDError::InvalidStream);
+    }
+    let expected_deposit = stream
+        .rate_per_second
+        .checked_mul(U256::from(duration / MILLIS_PER_SECOND))
+        .ok_or(CsprUSDError::Overflow)
+        .unwrap_or_revert();
+    if stream.deposit.is_zero() || stream.deposit != expected_deposit {
+        revert(CsprUSDError::InvalidStream);
+    }
+
+    lock_in_custody(stream.sender, stream.deposit).unwrap_or_revert();
+
+    let stream_id = stream_count_util() + 1;
+    write_stream(stream_id, &stream);
+    write_to(STREAM_COUNT, stream_id);
+    stream_id
+}
+
+/// Pays `amount` of the streamed tokens out to the recipient.
+pub(crate) fn withdraw_from_stream_util(stream_id: u64, mut stream: Stream, amount: U256) {
+    if !stream.active {
+        revert(CsprUSDError::StreamNotActive);
+    }
+    if amount > stream.balance_of_at(stream.recipient, now()) {
+        revert(CsprUSDError::ExceedsStreamBalance);
+    }
+
+    stream.withdrawn += amount;
+    write_stream(stream_id, &stream);
+    release_from_custody(stream.recipient, amount).unwrap_or_revert();
+}
+
+/// Pays both parties what they are owed at the current block time and closes the stream.
+///
+/// Returns the amounts paid to the recipient and to the sender.
+pub(crate) fn cancel_stream_util(stream_id: u64, mut stream: Stream) -> (U256, U256) {
+    if !stream.active {
+        revert(CsprUSDError::StreamNotActive);
+    }
+
+    let timestamp = now();
+    let recipient_balance = stream.balance_of_at(stream.recipient, timestamp);
+    let sender_balance = stream.balance_of_at(stream.sender, timestamp);
+
+    stream.active = false;
+    stream.withdrawn += recipient_balance;
+    write_stream(stream_id, &stream);
+
+    if !recipient_balance.is_zero() {
+        release_from_custody(stream.recipient, recipient_balance).unwrap_or_revert();
+    }
+    if !sender_balance.is_zero() {
+        release_from_custody(stream.sender, sender_balance).unwrap_or_revert();
+    }
+    (recipient_balance, sender_balance)
+}
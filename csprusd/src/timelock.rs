@@ -0,0 +1,189 @@
+//! Timelock for owner actions.
+//!
+//! While `timelock_delay` is non-zero, owner actions can't be performed directly. They must be
+//! queued with an `eta` at least `timelock_delay` milliseconds in the future, giving holders time
+//! to react, and can be executed by anyone once the `eta` has passed unless they were cancelled.
+//! An action that isn't executed within `QUEUED_ACTION_GRACE_PERIOD` after its `eta` expires, so
+//! a stale action can't be pushed through by anyone long after it was queued.
+//! An action can only be cancelled through the path that queued it: the owner cancels what the
+//! owner queued, while actions queued by an approved multisig proposal can only be cancelled by
+//! another proposal. Pausing is not an owner action and stays instant.
+use alloc::{string::ToString, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, Key};
+
+use crate::{
+    constants::{QUEUED_ACTIONS, QUEUED_ACTION_COUNT, TIMELOCK_DELAY},
+    error::CsprUSDError,
+    owner_actions::OwnerAction,
+    utils::{get_uref, now, read_from, write_to},
+};
+
+/// Maximum timelock delay, 30 days in milliseconds.
+pub(crate) const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60 * 1_000;
+
+/// How long after its `eta` a queued action can still be executed, 14 days in milliseconds.
+pub(crate) const QUEUED_ACTION_GRACE_PERIOD: u64 = 14 * 24 * 60 * 60 * 1_000;
+
+/// Maximum number of queued actions returned by a single `queued_actions` call.
+pub(crate) const MAX_QUEUED_ACTIONS_PAGE: u32 = 100;
+
+/// Lifecycle of a queued action.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum QueuedActionStatus {
+    Queued = 0,
+    Executed = 1,
+    Cancelled = 2,
+}
+
+/// Stored form of a queued action: `((action, data, eta), (status, queued_by_multisig, queued_by))`,
+/// where `queued_by` is the account that queued it, the owner or the signer that executed the
+/// multisig proposal.
+pub(crate) type QueuedActionRecord = ((u8, Bytes, u64), (u8, bool, Key));
+
+pub(crate) fn timelock_delay_util() -> u64 {
+    read_from(TIMELOCK_DELAY)
+}
+
+pub(crate) fn check_timelock_delay(delay: u64) -> Result<(), CsprUSDError> {
+    if delay > MAX_TIMELOCK_DELAY {
+        return Err(CsprUSDError::InvalidTimelockDelay);
+    }
+    Ok(())
+}
+
+pub(crate) fn set_timelock_delay_util(delay: u64) {
+    check_timelock_delay(delay).unwrap_or_revert();
+    write_to(TIMELOCK_DELAY, delay);
+}
+
+/// Reverts if owner actions have to go through the timelock.
+pub(crate) fn assert_no_timelock() {
+    if timelock_delay_util() != 0 {
+        revert(CsprUSDError::TimelockRequired);
+    }
+}
+
+pub(crate) fn queued_action_count_util() -> u64 {
+    read_from(QUEUED_ACTION_COUNT)
+}
+
+pub(crate) fn read_queued_action_record(action_id: u64) -> QueuedActionRecord {
+    storage::dictionary_get(get_uref(QUEUED_ACTIONS), &action_id.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(CsprUSDError::QueuedActionNotFound)
+}
+
+fn write_queued_action_record(action_id: u64, record: QueuedActionRecord) {
+    storage::dictionary_put(get_uref(QUEUED_ACTIONS), &action_id.to_string(), record);
+}
+
+/// Returns up to `limit` queued actions with their ids, starting at the 0-based `offset`.
+pub(crate) fn queued_actions_util(offset: u32, limit: u32) -> Vec<(u64, QueuedActionRecord)> {
+    let offset = u64::from(offset);
+    let limit = u64::from(limit.min(MAX_QUEUED_ACTIONS_PAGE));
+    let end = offset.saturating_add(limit).min(queued_action_count_util());
+
+    (offset..end)
+        .map(|index| (index + 1, read_queued_action_record(index + 1)))
+        .collect()
+}
+
+/// Queues `action` for execution at `eta` on behalf of `queued_by` and returns its id.
+/// Cancellations are never queued, they take effect immediately.
+pub(crate) fn queue_action_util(
+    action: u8,
+    data: Bytes,
+    eta: u64,
+    queued_by_multisig: bool,
+    queued_by: Key,
+) -> u64 {
+    let owner_action = OwnerAction::decode(action, &data).unwrap_or_revert();
+    if let OwnerAction::CancelQueuedAction(_) = owner_action {
+        revert(CsprUSDError::InvalidOwnerAction);
+    }
+    owner_action.validate().unwrap_or_revert();
+    let earliest_eta = now()
+        .checked_add(timelock_delay_util())
+        .ok_or(CsprUSDError::Overflow)
+        .unwrap_or_revert();
+    if eta < earliest_eta {
+        revert(CsprUSDError::InvalidEta);
+    }
+
+    let action_id = queued_action_count_util() + 1;
+    write_queued_action_record(
+        action_id,
+        (
+            (action, data, eta),
+            (
+                QueuedActionStatus::Queued as u8,
+                queued_by_multisig,
+                queued_by,
+            ),
+        ),
+    );
+    write_to(QUEUED_ACTION_COUNT, action_id);
+    action_id
+}
+
+fn assert_queued(status: u8) {
+    if status != QueuedActionStatus::Queued as u8 {
+        revert(CsprUSDError::QueuedActionNotPending);
+    }
+}
+
+/// Marks a queued action whose eta has passed, but not by more than the grace period, as executed
+/// and returns it along with the account that queued it.
+pub(crate) fn execute_queued_action_util(action_id: u64) -> (OwnerAction, Key) {
+    let ((action, data, eta), (status, queued_by_multisig, queued_by)) =
+        read_queued_action_record(action_id);
+    assert_queued(status);
+    if now() < eta {
+        revert(CsprUSDError::QueuedActionNotReady);
+    }
+    if now() > eta.saturating_add(QUEUED_ACTION_GRACE_PERIOD) {
+        revert(CsprUSDError::QueuedActionExpired);
+    }
+
+    let owner_action = OwnerAction::decode(action, &data).unwrap_or_revert();
+    write_queued_action_record(
+        action_id,
+        (
+            (action, data, eta),
+            (
+                QueuedActionStatus::Executed as u8,
+                queued_by_multisig,
+                queued_by,
+            ),
+        ),
+    );
+    (owner_action, queued_by)
+}
+
+/// Cancels a pending action. The owner can only cancel the actions it queued itself, the multisig
+/// can cancel any.
+pub(crate) fn cancel_queued_action_util(action_id: u64, by_multisig: bool) {
+    let (queued_action, (status, queued_by_multisig, queued_by)) =
+        read_queued_action_record(action_id);
+    assert_queued(status);
+    if queued_by_multisig && !by_multisig {
+        revert(CsprUSDError::QueuedByMultisig);
+    }
+    write_queued_action_record(
+        action_id,
+        (
+            queued_action,
+            (
+                QueuedActionStatus::Cancelled as u8,
+                queued_by_multisig,
+                queued_by,
+            ),
+        ),
+    );
+}
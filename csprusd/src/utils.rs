@@ -6,13 +6,17 @@ use casper_contract::{
         runtime::{self},
         storage::{self},
     },
+    ext_ffi,
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    bytesrepr::FromBytes, system::CallStackElement, ApiError, CLTyped, Key, URef, U256,
+    api_error,
+    bytesrepr::{FromBytes, ToBytes},
+    system::CallStackElement,
+    ApiError, CLTyped, Key, URef, U256,
 };
 
-use crate::{constants::TOTAL_SUPPLY, error::CsprUSDError};
+use crate::{checkpoints, constants::TOTAL_SUPPLY, error::CsprUSDError, snapshots};
 
 /// Gets [`URef`] under a name.
 pub(crate) fn get_uref(name: &str) -> URef {
@@ -32,6 +36,39 @@ where
     value
 }
 
+/// Returns the current block time in milliseconds.
+pub(crate) fn now() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+/// Writes value to a named key.
+pub(crate) fn write_to<T>(name: &str, value: T)
+where
+    T: ToBytes + CLTyped,
+{
+    storage::write(get_uref(name), value);
+}
+
+/// Reads an optional named argument, returning `None` if the caller didn't pass it.
+pub(crate) fn get_optional_named_arg<T>(name: &str) -> Option<T>
+where
+    T: FromBytes + CLTyped,
+{
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(()) => Some(runtime::get_named_arg(name)),
+        Err(ApiError::MissingArgument) => None,
+        Err(error) => runtime::revert(error),
+    }
+}
+
 /// Returns address based on a [`CallStackElement`].
 ///
 /// For `Session` and `StoredSession` variants it will return account hash, and for `StoredContract`
@@ -74,5 +111,7 @@ pub(crate) fn read_total_supply_from(uref: URef) -> U256 {
 
 /// Writes a total supply to a specific [`URef`].
 pub(crate) fn write_total_supply_to(uref: URef, value: U256) {
+    snapshots::checkpoint_total_supply();
     storage::write(uref, value);
+    checkpoints::write_total_supply_checkpoint(value);
 }
@@ -0,0 +1,153 @@
+//! Linear vesting schedules funded from the creator's balance.
+//!
+//! Nothing vests before `start + cliff`; afterwards `total` unlocks linearly until `start +
+//! duration`. Revoking a schedule freezes it at the amount vested so far and returns the rest to
+//! the creator.
+use alloc::string::ToString;
+
+use casper_contract::{
+    contract_api::{runtime::revert, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U256};
+
+use crate::{
+    constants::{VESTING_SCHEDULES, VESTING_SCHEDULE_COUNT},
+    custody::{lock_in_custody, release_from_custody},
+    error::CsprUSDError,
+    utils::{get_uref, now, read_from, write_to},
+};
+
+/// Stored form of a schedule:
+/// `((creator, beneficiary, revocable), (start, cliff, duration), (total, released, revoked))`.
+pub(crate) type VestingScheduleRecord = ((Key, Key, bool), (u64, u64, u64), (U256, U256, bool));
+
+pub(crate) struct VestingSchedule {
+    pub(crate) creator: Key,
+    pub(crate) beneficiary: Key,
+    pub(crate) revocable: bool,
+    pub(crate) start: u64,
+    pub(crate) cliff: u64,
+    pub(crate) duration: u64,
+    pub(crate) total: U256,
+    pub(crate) released: U256,
+    pub(crate) revoked: bool,
+}
+
+impl VestingSchedule {
+    fn to_record(&self) -> VestingScheduleRecord {
+        (
+            (self.creator, self.beneficiary, self.revocable),
+            (self.start, self.cliff, self.duration),
+            (self.total, self.released, self.revoked),
+        )
+    }
+
+    fn from_record(record: VestingScheduleRecord) -> Self {
+        let (
+            (creator, beneficiary, revocable),
+            (start, cliff, duration),
+            (total, released, revoked),
+        ) = record;
+        VestingSchedule {
+            creator,
+            beneficiary,
+            revocable,
+            start,
+            cliff,
+            duration,
+            total,
+            released,
+            revoked,
+        }
+    }
+
+    /// Returns the amount vested at `timestamp`, including what was already released.
+    pub(crate) fn vested_at(&self, timestamp: u64) -> U256 {
+        if self.revoked {
+            return self.total;
+        }
+        if timestamp < self.start.saturating_add(self.cliff) {
+            return U256::zero();
+        }
+
+        let elapsed = timestamp - self.start;
+        if elapsed >= self.duration {
+            return self.total;
+        }
+        // split the multiplication so that it can't overflow for large totals
+        let duration = U256::from(self.duration);
+        let elapsed = U256::from(elapsed);
+        self.total / duration * elapsed + self.total % duration * elapsed / duration
+    }
+
+    /// Returns the amount that is vested but not released yet.
+    pub(crate) fn releasable_at(&self, timestamp: u64) -> U256 {
+        self.vested_at(timestamp) - self.released
+    }
+}
+
+pub(crate) fn vesting_schedule_count_util() -> u64 {
+    read_from(VESTING_SCHEDULE_COUNT)
+}
+
+pub(crate) fn read_vesting_schedule_record(schedule_id: u64) -> VestingScheduleRecord {
+    storage::dictionary_get(get_uref(VESTING_SCHEDULES), &schedule_id.to_string())
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(CsprUSDError::VestingScheduleNotFound)
+}
+
+pub(crate) fn read_vesting_schedule(schedule_id: u64) -> VestingSchedule {
+    VestingSchedule::from_record(read_vesting_schedule_record(schedule_id))
+}
+
+fn write_vesting_schedule(schedule_id: u64, schedule: &VestingSchedule) {
+    storage::dictionary_put(
+        get_uref(VESTING_SCHEDULES),
+        &schedule_id.to_string(),
+        schedule.to_record(),
+    );
+}
+
+/// Moves `schedule.total` from the creator into custody and stores the schedule under a new id.
+pub(crate) fn create_vesting_schedule_util(schedule: VestingSchedule) -> u64 {
+    if schedule.duration == 0 || schedule.cliff > schedule.duration {
+        revert(CsprUSDError::InvalidVestingSchedule);
+    }
+    lock_in_custody(schedule.creator, schedule.total).unwrap_or_revert();
+
+    let schedule_id = vesting_schedule_count_util() + 1;
+    write_vesting_schedule(schedule_id, &schedule);
+    write_to(VESTING_SCHEDULE_COUNT, schedule_id);
+    schedule_id
+}
+
+/// Pays out everything vested so far to the beneficiary and returns the amount.
+pub(crate) fn release_vested_util(schedule_id: u64, mut schedule: VestingSchedule) -> U256 {
+    let amount = schedule.releasable_at(now());
+    if amount.is_zero() {
+        revert(CsprUSDError::NothingToRelease);
+    }
+
+    schedule.released += amount;
+    write_vesting_schedule(schedule_id, &schedule);
+    release_from_custody(schedule.beneficiary, amount).unwrap_or_revert();
+    amount
+}
+
+/// Freezes the schedule at its vested amount and pays the unvested remainder back to the creator.
+pub(crate) fn revoke_vesting_util(schedule_id: u64, mut schedule: VestingSchedule) -> U256 {
+    if !schedule.revocable || schedule.revoked {
+        revert(CsprUSDError::VestingNotRevocable);
+    }
+
+    let vested = schedule.vested_at(now());
+    let unvested = schedule.total - vested;
+    schedule.total = vested;
+    schedule.revoked = true;
+    write_vesting_schedule(schedule_id, &schedule);
+    if !unvested.is_zero() {
+        release_from_custody(schedule.creator, unvested).unwrap_or_revert();
+    }
+    unvested
+}
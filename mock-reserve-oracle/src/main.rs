@@ -0,0 +1,90 @@
+//! Proof-of-reserve oracle used by the integration tests.
+//!
+//! Anyone can set the reported reserves, their decimals and the time they were last updated, so
+//! tests can simulate fresh, stale and insufficient reserves.
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::NamedKeys, CLType, CLTyped, CLValue, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Parameter, U256,
+};
+
+const LATEST_RESERVES_ENTRY_POINT_NAME: &str = "latest_reserves";
+const SET_RESERVES_ENTRY_POINT_NAME: &str = "set_reserves";
+const RESERVES_RUNTIME_ARG_NAME: &str = "reserves";
+const DECIMALS_RUNTIME_ARG_NAME: &str = "decimals";
+const UPDATED_AT_RUNTIME_ARG_NAME: &str = "updated_at";
+const RESERVES_KEY: &str = "reserves";
+const MOCK_RESERVE_ORACLE_KEY: &str = "mock_reserve_oracle_package_hash";
+
+fn read_reserves() -> (U256, u8, u64) {
+    let uref = runtime::get_key(RESERVES_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+/// Returns `(reserves, decimals, updated_at)`, `updated_at` being a block time in milliseconds.
+#[no_mangle]
+pub extern "C" fn latest_reserves() {
+    runtime::ret(CLValue::from_t(read_reserves()).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn set_reserves() {
+    let reserves: U256 = runtime::get_named_arg(RESERVES_RUNTIME_ARG_NAME);
+    let decimals: u8 = runtime::get_named_arg(DECIMALS_RUNTIME_ARG_NAME);
+    let updated_at: u64 = runtime::get_named_arg(UPDATED_AT_RUNTIME_ARG_NAME);
+
+    let uref = runtime::get_key(RESERVES_KEY)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::write(uref, (reserves, decimals, updated_at));
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(LATEST_RESERVES_ENTRY_POINT_NAME),
+        vec![],
+        <(U256, u8, u64)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        String::from(SET_RESERVES_ENTRY_POINT_NAME),
+        vec![
+            Parameter::new(RESERVES_RUNTIME_ARG_NAME, U256::cl_type()),
+            Parameter::new(DECIMALS_RUNTIME_ARG_NAME, u8::cl_type()),
+            Parameter::new(UPDATED_AT_RUNTIME_ARG_NAME, u64::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        String::from(RESERVES_KEY),
+        storage::new_uref((U256::zero(), 0u8, 0u64)).into(),
+    );
+
+    let (_contract_hash, _version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(String::from(MOCK_RESERVE_ORACLE_KEY)),
+        None,
+    );
+}
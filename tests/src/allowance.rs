@@ -1,15 +1,21 @@
-use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
-use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256};
 
 use crate::utility::{
     constants::{
-        ACCOUNT_1_ADDR, ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2, AMOUNT, ARG_AMOUNT, ARG_OWNER,
-        ARG_RECIPIENT, ARG_SPENDER, CONFIGURE_MINTER_ENTRY_POINT_NAME, DECREASE_ALLOWANCE,
-        ERROR_INSUFFICIENT_ALLOWANCE, INCREASE_ALLOWANCE, METHOD_APPROVE, METHOD_MINT,
-        METHOD_TRANSFER_FROM, MINTER, MINTER_ALLOWED, RECIPIENT, TOKEN_OWNER_AMOUNT_1,
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ALLOWANCE_AMOUNT_1, ALLOWANCE_AMOUNT_2, AMOUNT, ARG_AMOUNT,
+        ARG_EXPECTED_CURRENT, ARG_EXPIRES_AT, ARG_LIMIT, ARG_NEW_AMOUNT, ARG_OWNER, ARG_RECIPIENT,
+        ARG_SPENDER, CONFIGURE_MINTER_ENTRY_POINT_NAME, DECREASE_ALLOWANCE,
+        ERROR_ALLOWANCE_MISMATCH, ERROR_INSUFFICIENT_ALLOWANCE, ERROR_INVALID_EXPIRY,
+        INCREASE_ALLOWANCE, METHOD_APPROVE, METHOD_APPROVE_IF, METHOD_APPROVE_WITH_EXPIRY,
+        METHOD_MINT, METHOD_PAUSE, METHOD_REVOKE_ALL_ALLOWANCES, METHOD_TRANSFER_FROM, MINTER,
+        MINTER_ALLOWED, RECIPIENT, TOKEN_OWNER_AMOUNT_1,
     },
     installer_request_builders::{
-        csprusd_check_allowance_of, csprusd_check_balance_of, make_csprusd_approve_request, setup,
+        csprusd_check_allowance_of, csprusd_check_balance_of, csprusd_check_spenders_of,
+        csprusd_check_spenders_of_at, make_csprusd_approve_request, mint_to, setup,
         test_approve_for, TestContext,
     },
 };
@@ -229,3 +235,372 @@ fn test_decrease_allowance() {
         (allowance_amount_1 * 2) - allowance_amount_2
     );
 }
+
+#[test]
+fn should_treat_expired_allowance_as_zero() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    mint_to(&mut builder, csprusd_token, owner, U256::from(100));
+
+    // expiry must lie in the future
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_APPROVE_WITH_EXPIRY,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::from(50),
+            ARG_EXPIRES_AT => 1_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(approve_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_EXPIRY),
+        "{:?}",
+        error
+    );
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_APPROVE_WITH_EXPIRY,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::from(50),
+            ARG_EXPIRES_AT => 5_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_at = |block_time: u64| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            csprusd_token,
+            METHOD_TRANSFER_FROM,
+            runtime_args! {
+                ARG_OWNER => owner,
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => U256::from(10),
+            },
+        )
+        .with_block_time(block_time)
+        .build()
+    };
+
+    builder
+        .exec(transfer_from_at(4_999))
+        .expect_success()
+        .commit();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, recipient),
+        U256::from(10)
+    );
+
+    builder.exec(transfer_from_at(5_000)).commit();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INSUFFICIENT_ALLOWANCE),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_keep_passed_expiry_when_increasing_allowance() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    mint_to(&mut builder, csprusd_token, owner, U256::from(100));
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_APPROVE_WITH_EXPIRY,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::from(50),
+            ARG_EXPIRES_AT => 5_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    // the allowance expired, so increasing it starts over from zero without an expiry
+    let increase_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        INCREASE_ALLOWANCE,
+        runtime_args! {ARG_SPENDER => spender, ARG_AMOUNT => U256::from(20)},
+    )
+    .with_block_time(6_000)
+    .build();
+    builder.exec(increase_request).expect_success().commit();
+
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::from(20),
+        },
+    )
+    .with_block_time(7_000)
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(20)
+    );
+}
+
+fn transfer_from_account_1(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    owner: Key,
+    amount: U256,
+) {
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => owner,
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+}
+
+#[test]
+fn should_not_decrement_unlimited_allowance() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    mint_to(&mut builder, csprusd_token, owner, U256::from(100));
+
+    let approve_request = make_csprusd_approve_request(owner, &csprusd_token, spender, U256::MAX);
+    builder.exec(approve_request).expect_success().commit();
+
+    transfer_from_account_1(&mut builder, csprusd_token, owner, U256::from(10));
+    transfer_from_account_1(&mut builder, csprusd_token, owner, U256::from(20));
+
+    assert_eq!(
+        csprusd_check_allowance_of(&mut builder, owner, spender),
+        U256::MAX
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, Key::Account(*ACCOUNT_2_ADDR)),
+        U256::from(30)
+    );
+}
+
+#[test]
+fn should_treat_allowance_increased_to_max_as_unlimited() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    mint_to(&mut builder, csprusd_token, owner, U256::from(100));
+
+    let approve_request =
+        make_csprusd_approve_request(owner, &csprusd_token, spender, U256::from(50));
+    builder.exec(approve_request).expect_success().commit();
+
+    // increasing saturates at the maximum instead of overflowing
+    let increase_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        INCREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => U256::MAX,
+        },
+    )
+    .build();
+    builder
+        .exec(increase_allowance_request)
+        .expect_success()
+        .commit();
+    assert_eq!(
+        csprusd_check_allowance_of(&mut builder, owner, spender),
+        U256::MAX
+    );
+
+    transfer_from_account_1(&mut builder, csprusd_token, owner, U256::from(10));
+
+    assert_eq!(
+        csprusd_check_allowance_of(&mut builder, owner, spender),
+        U256::MAX
+    );
+}
+
+#[test]
+fn should_approve_only_if_current_allowance_matches() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender = Key::Account(*ACCOUNT_1_ADDR);
+
+    let approve_request =
+        make_csprusd_approve_request(owner, &csprusd_token, spender, U256::from(50));
+    builder.exec(approve_request).expect_success().commit();
+
+    let approve_if = |expected_current: u64, new_amount: u64| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            csprusd_token,
+            METHOD_APPROVE_IF,
+            runtime_args! {
+                ARG_SPENDER => spender,
+                ARG_EXPECTED_CURRENT => U256::from(expected_current),
+                ARG_NEW_AMOUNT => U256::from(new_amount),
+            },
+        )
+        .build()
+    };
+
+    // a stale expectation of the current allowance is rejected
+    builder.exec(approve_if(40, 80)).commit();
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ALLOWANCE_MISMATCH),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        csprusd_check_allowance_of(&mut builder, owner, spender),
+        U256::from(50)
+    );
+
+    builder.exec(approve_if(50, 80)).expect_success().commit();
+    assert_eq!(
+        csprusd_check_allowance_of(&mut builder, owner, spender),
+        U256::from(80)
+    );
+}
+
+#[test]
+fn should_list_spenders_and_revoke_all_allowances() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let spender_2 = Key::Account(*ACCOUNT_2_ADDR);
+    let spender_3 = Key::Hash([42; 32]);
+
+    for (spender, amount) in [(spender_1, 10), (spender_2, 20), (spender_3, 30)] {
+        let approve_request =
+            make_csprusd_approve_request(owner, &csprusd_token, spender, U256::from(amount));
+        builder.exec(approve_request).expect_success().commit();
+    }
+
+    // zeroing an allowance moves the last spender into its place
+    let approve_request =
+        make_csprusd_approve_request(owner, &csprusd_token, spender_1, U256::zero());
+    builder.exec(approve_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_spenders_of(&mut builder, &test_context, owner, 0, 10),
+        vec![(spender_3, U256::from(30)), (spender_2, U256::from(20))]
+    );
+    assert_eq!(
+        csprusd_check_spenders_of(&mut builder, &test_context, owner, 1, 10),
+        vec![(spender_2, U256::from(20))]
+    );
+
+    // revoking is paginated, starting from the last spender
+    let revoke_all = |limit: u32| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            csprusd_token,
+            METHOD_REVOKE_ALL_ALLOWANCES,
+            runtime_args! {ARG_LIMIT => limit},
+        )
+        .build()
+    };
+    builder.exec(revoke_all(1)).expect_success().commit();
+    assert_eq!(
+        csprusd_check_spenders_of(&mut builder, &test_context, owner, 0, 10),
+        vec![(spender_3, U256::from(30))]
+    );
+
+    // revoking stays possible while the contract is paused
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    builder.exec(revoke_all(10)).expect_success().commit();
+
+    assert!(csprusd_check_spenders_of(&mut builder, &test_context, owner, 0, 10).is_empty());
+    assert_eq!(
+        csprusd_check_allowance_of(&mut builder, owner, spender_2),
+        U256::zero()
+    );
+    assert_eq!(
+        csprusd_check_allowance_of(&mut builder, owner, spender_3),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_leave_expired_allowances_out_of_spenders() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+    let owner = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let spender_1 = Key::Account(*ACCOUNT_1_ADDR);
+    let spender_2 = Key::Account(*ACCOUNT_2_ADDR);
+
+    let approve_request =
+        make_csprusd_approve_request(owner, &csprusd_token, spender_1, U256::from(10));
+    builder.exec(approve_request).expect_success().commit();
+
+    let approve_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_APPROVE_WITH_EXPIRY,
+        runtime_args! {
+            ARG_SPENDER => spender_2,
+            ARG_AMOUNT => U256::from(20),
+            ARG_EXPIRES_AT => 5_000u64,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder.exec(approve_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_spenders_of_at(&mut builder, &test_context, owner, 4_999),
+        vec![(spender_1, U256::from(10)), (spender_2, U256::from(20))]
+    );
+    assert_eq!(
+        csprusd_check_spenders_of_at(&mut builder, &test_context, owner, 5_000),
+        vec![(spender_1, U256::from(10))]
+    );
+}
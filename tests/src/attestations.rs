@@ -0,0 +1,183 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_PERIOD, ARG_REPORT_HASH, ARG_RESERVES_AMOUNT, ARG_URI,
+        ATTESTATIONS_KEY, ATTESTATION_COUNT_KEY, ERROR_ATTESTATION_FIELD_TOO_LONG,
+        ERROR_NOT_ATTESTOR, METHOD_PUBLISH_ATTESTATION, METHOD_UPDATE_ATTESTOR, NEW,
+    },
+    installer_request_builders::setup,
+};
+
+type AttestationRecord = ((String, U256, [u8; 32]), (String, Key, u64));
+
+fn publish_attestation(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    attestor: AccountHash,
+    period: &str,
+    reserves_amount: U256,
+) {
+    let publish_request = ExecuteRequestBuilder::contract_call_by_hash(
+        attestor,
+        csprusd_token,
+        METHOD_PUBLISH_ATTESTATION,
+        runtime_args! {
+            ARG_PERIOD => period,
+            ARG_RESERVES_AMOUNT => reserves_amount,
+            ARG_REPORT_HASH => [7u8; 32],
+            ARG_URI => format!("ipfs://attestations/{}", period),
+        },
+    )
+    .with_block_time(5_000)
+    .build();
+    builder.exec(publish_request).commit();
+}
+
+fn read_attestation(
+    builder: &InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    index: u64,
+) -> AttestationRecord {
+    let dict_uref = *builder
+        .query(None, csprusd_token.into(), &[])
+        .expect("must have contract")
+        .as_contract()
+        .expect("must convert contract")
+        .named_keys()
+        .get(ATTESTATIONS_KEY)
+        .expect("must have key")
+        .as_uref()
+        .expect("must convert to seed uref");
+
+    builder
+        .query_dictionary_item(None, dict_uref, &index.to_string())
+        .expect("should have attestation")
+        .as_cl_value()
+        .expect("should be cl value")
+        .clone()
+        .into_t()
+        .expect("should be attestation record")
+}
+
+#[test]
+fn should_publish_attestations_in_order() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+
+    publish_attestation(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        "2024-05",
+        U256::from(1_000),
+    );
+    builder.expect_success();
+    publish_attestation(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        "2024-06",
+        U256::from(1_200),
+    );
+    builder.expect_success();
+
+    let attestation_count: u64 = builder.get_value(csprusd_token, ATTESTATION_COUNT_KEY);
+    assert_eq!(attestation_count, 2);
+    assert_eq!(
+        read_attestation(&builder, csprusd_token, 1),
+        (
+            ("2024-05".to_string(), U256::from(1_000), [7u8; 32]),
+            (
+                "ipfs://attestations/2024-05".to_string(),
+                Key::Account(*ACCOUNT_1_ADDR),
+                5_000
+            ),
+        )
+    );
+    let ((period, reserves_amount, _), _) = read_attestation(&builder, csprusd_token, 2);
+    assert_eq!(period, "2024-06");
+    assert_eq!(reserves_amount, U256::from(1_200));
+}
+
+#[test]
+fn should_only_let_attestor_publish() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+
+    publish_attestation(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        "2024-05",
+        U256::from(1_000),
+    );
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_ATTESTOR),
+        "{:?}",
+        error
+    );
+
+    let update_attestor_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_UPDATE_ATTESTOR,
+        runtime_args! {NEW => Key::Account(*ACCOUNT_2_ADDR)},
+    )
+    .build();
+    builder
+        .exec(update_attestor_request)
+        .expect_success()
+        .commit();
+
+    publish_attestation(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        "2024-05",
+        U256::from(1_000),
+    );
+    builder.expect_success();
+    let attestation_count: u64 = builder.get_value(csprusd_token, ATTESTATION_COUNT_KEY);
+    assert_eq!(attestation_count, 1);
+}
+
+#[test]
+fn should_not_publish_attestation_with_overlong_period() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+
+    publish_attestation(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        &"9".repeat(65),
+        U256::from(1_000),
+    );
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_ATTESTATION_FIELD_TOO_LONG),
+        "{:?}",
+        error
+    );
+
+    publish_attestation(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        &"9".repeat(64),
+        U256::from(1_000),
+    );
+    builder.expect_success();
+    let attestation_count: u64 = builder.get_value(csprusd_token, ATTESTATION_COUNT_KEY);
+    assert_eq!(attestation_count, 1);
+}
@@ -0,0 +1,197 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::Bytes, runtime_args, ApiError, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ARG_AMOUNT, ARG_CAP, ARG_CHAIN_ID, ARG_DESTINATION_ADDRESS,
+        ARG_DESTINATION_CHAIN_ID, ARG_RECIPIENT, ARG_SOURCE_CHAIN_ID, ARG_SOURCE_NONCE,
+        BRIDGE_NONCE_KEY, ERROR_BRIDGE_NONCE_CONSUMED, ERROR_EXCEEDS_BRIDGE_MINT_CAP,
+        ERROR_NOT_BRIDGE_OPERATOR, METHOD_BRIDGE_BURN, METHOD_BRIDGE_MINT,
+        METHOD_SET_BRIDGE_MINT_CAP, TOTAL_SUPPLY_KEY,
+    },
+    installer_request_builders::{csprusd_check_balance_of, mint_to, setup, TestContext},
+};
+
+const CHAIN_ID: u64 = 7;
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+fn set_bridge_mint_cap(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    chain_id: u64,
+    cap: U256,
+) {
+    let set_cap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.csprusd_token,
+        METHOD_SET_BRIDGE_MINT_CAP,
+        runtime_args! {ARG_CHAIN_ID => chain_id, ARG_CAP => cap},
+    )
+    .build();
+    builder.exec(set_cap_request).expect_success().commit();
+}
+
+fn bridge_mint(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    operator: AccountHash,
+    source_nonce: u64,
+    recipient: Key,
+    amount: U256,
+) {
+    let bridge_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        operator,
+        test_context.csprusd_token,
+        METHOD_BRIDGE_MINT,
+        runtime_args! {
+            ARG_SOURCE_CHAIN_ID => CHAIN_ID,
+            ARG_SOURCE_NONCE => source_nonce,
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(bridge_mint_request).commit();
+}
+
+fn bridge_burn(builder: &mut InMemoryWasmTestBuilder, test_context: &TestContext, amount: U256) {
+    let bridge_burn_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_token,
+        METHOD_BRIDGE_BURN,
+        runtime_args! {
+            ARG_AMOUNT => amount,
+            ARG_DESTINATION_CHAIN_ID => CHAIN_ID,
+            ARG_DESTINATION_ADDRESS => Bytes::from(vec![0xab; 20]),
+        },
+    )
+    .build();
+    builder.exec(bridge_burn_request).expect_success().commit();
+}
+
+#[test]
+fn should_bridge_burn_with_increasing_nonce() {
+    let (mut builder, test_context) = setup();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        sender,
+        U256::from(100),
+    );
+
+    bridge_burn(&mut builder, &test_context, U256::from(40));
+    let nonce: u64 = builder.get_value(test_context.csprusd_token, BRIDGE_NONCE_KEY);
+    assert_eq!(nonce, 1);
+
+    bridge_burn(&mut builder, &test_context, U256::from(40));
+    let nonce: u64 = builder.get_value(test_context.csprusd_token, BRIDGE_NONCE_KEY);
+    assert_eq!(nonce, 2);
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, sender),
+        U256::from(20)
+    );
+    let total_supply: U256 = builder.get_value(test_context.csprusd_token, TOTAL_SUPPLY_KEY);
+    assert_eq!(total_supply, U256::from(20));
+}
+
+#[test]
+fn should_bridge_mint_once_per_nonce_within_cap() {
+    let (mut builder, test_context) = setup();
+    let recipient = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    set_bridge_mint_cap(&mut builder, &test_context, CHAIN_ID, U256::from(100));
+
+    bridge_mint(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        1,
+        recipient,
+        U256::from(60),
+    );
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, recipient),
+        U256::from(60)
+    );
+
+    // the same message can't be minted twice
+    bridge_mint(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        1,
+        recipient,
+        U256::from(10),
+    );
+    assert_user_error(&builder, ERROR_BRIDGE_NONCE_CONSUMED);
+
+    bridge_mint(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        2,
+        recipient,
+        U256::from(50),
+    );
+    assert_user_error(&builder, ERROR_EXCEEDS_BRIDGE_MINT_CAP);
+
+    // burning back towards the chain frees up its cap
+    bridge_burn(&mut builder, &test_context, U256::from(60));
+    bridge_mint(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        2,
+        recipient,
+        U256::from(50),
+    );
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, recipient),
+        U256::from(50)
+    );
+}
+
+#[test]
+fn should_not_bridge_mint_without_operator_role_or_cap() {
+    let (mut builder, test_context) = setup();
+    let recipient = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    bridge_mint(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        1,
+        recipient,
+        U256::from(10),
+    );
+    assert_user_error(&builder, ERROR_NOT_BRIDGE_OPERATOR);
+
+    // chains without a cap are disabled
+    bridge_mint(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        1,
+        recipient,
+        U256::from(10),
+    );
+    assert_user_error(&builder, ERROR_EXCEEDS_BRIDGE_MINT_CAP);
+}
@@ -0,0 +1,529 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use std::collections::BTreeMap;
+
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes},
+    runtime_args, ApiError, CLValue, ContractHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, ACCOUNT_2_ADDR, ARG_ADMIN_LIST, ARG_AMOUNT,
+        ARG_ARBITER, ARG_CURRENCY, ARG_DATA, ARG_DECIMALS, ARG_DESTINATION_ADDRESS,
+        ARG_DESTINATION_CHAIN_ID, ARG_ENABLE_CEP18_COMPATIBILITY, ARG_ENABLE_MINT_BURN,
+        ARG_ESCROW_ID, ARG_EVENTS_MODE, ARG_FEE_BPS, ARG_FLASH_FEE_BPS, ARG_LEGAL_REFERENCE,
+        ARG_MASTER_MINTER, ARG_MAX_FEE, ARG_MAX_FLASH_LOAN, ARG_MINTER_LIST, ARG_NAME,
+        ARG_NONE_LIST, ARG_RECEIVER, ARG_RECIPIENT, ARG_RELEASE_TIME, ARG_SYMBOL, ARG_TOTAL_SUPPLY,
+        BLACKLISTER, CEP18_COMPATIBILITY_ENABLED_KEY, CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        CSPR_USD_CONTRACT_WASM, ERROR_INVALID_BURN_TARGET, ERROR_INVALID_ENABLE_MB_FLAG,
+        ERROR_NOT_MINTER, ERROR_UNSUPPORTED_EVENTS_MODE, ERROR_UNSUPPORTED_SECURITY_BADGE,
+        EVENTS_MODE_KEY, FROM, METHOD_BRIDGE_BURN, METHOD_BURN, METHOD_CHANGE_SECURITY,
+        METHOD_CONTROLLER_TRANSFER, METHOD_CREATE_ESCROW, METHOD_FLASH_LOAN, METHOD_MINT,
+        METHOD_RELEASE_ESCROW, METHOD_RESCUE_TOKENS, METHOD_SET_FEE, METHOD_SET_FLASH_LOAN_CONFIG,
+        METHOD_TRANSFER, MINTER, MINTER_ALLOWED, NOT_MASTER_MINTER, OWNER, PAUSER, TO,
+        TOKEN_CURRENCY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, TOTAL_SUPPLY_KEY,
+    },
+    installer_request_builders::{
+        csprusd_check_balance_of, csprusd_events, csprusd_package_key, setup, setup_with_args,
+        TestContext,
+    },
+};
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+fn cep18_install_args() -> RuntimeArgs {
+    let account_1_key = Key::Account(*ACCOUNT_1_ADDR);
+    runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_CURRENCY => TOKEN_CURRENCY,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_MASTER_MINTER => account_1_key,
+        PAUSER => ACCOUNT_1_PUBLIC_KEY.clone(),
+        BLACKLISTER => ACCOUNT_1_PUBLIC_KEY.clone(),
+        OWNER => account_1_key,
+        ARG_ENABLE_CEP18_COMPATIBILITY => true,
+    }
+}
+
+/// Installs the token in CEP-18 compatibility mode with `DEFAULT_ACCOUNT_ADDR` as a minter.
+fn setup_cep18() -> (InMemoryWasmTestBuilder, TestContext) {
+    let (mut builder, test_context) = setup_with_args(cep18_install_args());
+
+    let configure_minter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.csprusd_token,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        runtime_args! {
+            MINTER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            MINTER_ALLOWED => U256::from(1_000),
+        },
+    )
+    .build();
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    (builder, test_context)
+}
+
+fn call_as_minter(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    method: &str,
+    owner: Key,
+    amount: U256,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        method,
+        runtime_args! {OWNER => owner, ARG_AMOUNT => amount},
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+#[test]
+fn should_record_cep18_compatibility_at_install() {
+    let (builder, TestContext { csprusd_token, .. }) = setup_cep18();
+
+    let cep18_compatibility_enabled: bool =
+        builder.get_value(csprusd_token, CEP18_COMPATIBILITY_ENABLED_KEY);
+    assert!(cep18_compatibility_enabled);
+    let events_mode: u8 = builder.get_value(csprusd_token, EVENTS_MODE_KEY);
+    assert_eq!(events_mode, 1);
+}
+
+#[test]
+fn should_only_write_events_mode_in_cep18_mode() {
+    let (builder, TestContext { csprusd_token, .. }) = setup();
+
+    let contract = builder
+        .query(None, csprusd_token.into(), &[])
+        .expect("must have contract")
+        .as_contract()
+        .expect("must convert contract")
+        .clone();
+    assert!(!contract.named_keys().contains_key(EVENTS_MODE_KEY));
+}
+
+#[test]
+fn should_apply_cep18_install_args() {
+    let installer = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let minter = Key::Account(*ACCOUNT_2_ADDR);
+    let mut install_args = cep18_install_args();
+    install_args
+        .insert(ARG_TOTAL_SUPPLY, U256::from(500))
+        .unwrap();
+    install_args.insert(ARG_MINTER_LIST, vec![minter]).unwrap();
+    install_args.insert(ARG_ENABLE_MINT_BURN, 1u8).unwrap();
+    install_args.insert(ARG_EVENTS_MODE, 1u8).unwrap();
+    let (mut builder, TestContext { csprusd_token, .. }) = setup_with_args(install_args);
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, installer),
+        U256::from(500)
+    );
+    let total_supply: U256 = builder.get_value(csprusd_token, TOTAL_SUPPLY_KEY);
+    assert_eq!(total_supply, U256::from(500));
+
+    // CEP-18 minters have no allowance to run out of
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_MINT,
+        runtime_args! {OWNER => minter, ARG_AMOUNT => U256::from(1_000_000)},
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, minter),
+        U256::from(1_000_000)
+    );
+}
+
+#[test]
+fn should_reject_unsupported_cep18_install_args() {
+    let cases: Vec<(&str, CLValue, u16)> = vec![
+        (
+            ARG_ENABLE_MINT_BURN,
+            CLValue::from_t(0u8).unwrap(),
+            ERROR_INVALID_ENABLE_MB_FLAG,
+        ),
+        (
+            ARG_EVENTS_MODE,
+            CLValue::from_t(2u8).unwrap(),
+            ERROR_UNSUPPORTED_EVENTS_MODE,
+        ),
+        (
+            ARG_ADMIN_LIST,
+            CLValue::from_t(vec![Key::Account(*ACCOUNT_2_ADDR)]).unwrap(),
+            ERROR_UNSUPPORTED_SECURITY_BADGE,
+        ),
+    ];
+
+    for (name, value, expected) in cases {
+        let mut install_args = cep18_install_args();
+        install_args.insert_cl_value(name, value);
+
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+        let install_request = ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CSPR_USD_CONTRACT_WASM,
+            install_args,
+        )
+        .build();
+        builder.exec(install_request).commit();
+
+        assert_user_error(&builder, expected);
+    }
+}
+
+#[test]
+fn should_change_security_through_master_minter() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup_cep18();
+    let master_minter = Key::Account(*ACCOUNT_1_ADDR);
+    let minter = Key::Account(*ACCOUNT_2_ADDR);
+
+    let change_security =
+        |builder: &mut InMemoryWasmTestBuilder, caller: AccountHash, args: RuntimeArgs| {
+            let request = ExecuteRequestBuilder::contract_call_by_hash(
+                caller,
+                csprusd_token,
+                METHOD_CHANGE_SECURITY,
+                args,
+            )
+            .build();
+            builder.exec(request).commit();
+        };
+    let mint_as_minter = |builder: &mut InMemoryWasmTestBuilder| {
+        let request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_2_ADDR,
+            csprusd_token,
+            METHOD_MINT,
+            runtime_args! {OWNER => minter, ARG_AMOUNT => U256::from(10)},
+        )
+        .build();
+        builder.exec(request).commit();
+    };
+
+    change_security(
+        &mut builder,
+        *DEFAULT_ACCOUNT_ADDR,
+        runtime_args! {ARG_MINTER_LIST => vec![minter]},
+    );
+    assert_user_error(&builder, NOT_MASTER_MINTER);
+
+    change_security(
+        &mut builder,
+        *ACCOUNT_1_ADDR,
+        runtime_args! {ARG_ADMIN_LIST => vec![minter]},
+    );
+    assert_user_error(&builder, ERROR_UNSUPPORTED_SECURITY_BADGE);
+
+    change_security(
+        &mut builder,
+        *ACCOUNT_1_ADDR,
+        runtime_args! {ARG_MINTER_LIST => vec![minter]},
+    );
+    builder.expect_success();
+    mint_as_minter(&mut builder);
+    builder.expect_success();
+
+    change_security(
+        &mut builder,
+        *ACCOUNT_1_ADDR,
+        runtime_args! {ARG_NONE_LIST => vec![minter]},
+    );
+    builder.expect_success();
+    mint_as_minter(&mut builder);
+    assert_user_error(&builder, ERROR_NOT_MINTER);
+
+    let change_security_event = csprusd_events(&builder, csprusd_token)
+        .into_iter()
+        .rev()
+        .find_map(|event| {
+            let (name, fields) = String::from_bytes(&event).unwrap();
+            (name.trim_start_matches("event_") == "ChangeSecurity")
+                .then(|| <(Key, BTreeMap<Key, u8>)>::from_bytes(fields).unwrap().0)
+        })
+        .expect("should have event");
+    // CEP-18's `None` badge
+    let expected_changes: BTreeMap<Key, u8> = vec![(minter, 2u8)].into_iter().collect();
+    assert_eq!(change_security_event, (master_minter, expected_changes));
+}
+
+#[test]
+fn should_mint_and_burn_with_cep18_arguments() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup_cep18();
+    let minter = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let holder = Key::Account(*ACCOUNT_2_ADDR);
+
+    call_as_minter(
+        &mut builder,
+        csprusd_token,
+        METHOD_MINT,
+        holder,
+        U256::from(100),
+    );
+    builder.expect_success();
+    call_as_minter(
+        &mut builder,
+        csprusd_token,
+        METHOD_MINT,
+        minter,
+        U256::from(50),
+    );
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, holder),
+        U256::from(100)
+    );
+
+    // CEP-18 burn can't target somebody else's tokens
+    call_as_minter(
+        &mut builder,
+        csprusd_token,
+        METHOD_BURN,
+        holder,
+        U256::from(10),
+    );
+    assert_user_error(&builder, ERROR_INVALID_BURN_TARGET);
+
+    call_as_minter(
+        &mut builder,
+        csprusd_token,
+        METHOD_BURN,
+        minter,
+        U256::from(10),
+    );
+    builder.expect_success();
+
+    let total_supply: U256 = builder.get_value(csprusd_token, TOTAL_SUPPLY_KEY);
+    assert_eq!(total_supply, U256::from(140));
+}
+
+fn call(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    caller: AccountHash,
+    method: &str,
+    args: RuntimeArgs,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(caller, csprusd_token, method, args)
+        .with_block_time(1_000)
+        .build();
+    builder.exec(request).expect_success().commit();
+}
+
+/// Replays the CEP-18 `Transfer`, `TransferFrom`, `Mint` and `Burn` events into balances, the way
+/// a CEP-18 indexer would.
+fn balances_from_cep18_events(
+    builder: &InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+) -> BTreeMap<Key, U256> {
+    let mut balances: BTreeMap<Key, U256> = BTreeMap::new();
+    for event in csprusd_events(builder, csprusd_token) {
+        let (name, fields) = String::from_bytes(&event).unwrap();
+        let (from, to, amount) = match name.trim_start_matches("event_") {
+            "Transfer" => {
+                let (sender, recipient, amount) = <(Key, Key, U256)>::from_bytes(fields).unwrap().0;
+                (Some(sender), Some(recipient), amount)
+            }
+            "TransferFrom" => {
+                let (_spender, owner, recipient, amount) =
+                    <(Key, Key, Key, U256)>::from_bytes(fields).unwrap().0;
+                (Some(owner), Some(recipient), amount)
+            }
+            "Mint" => {
+                let (recipient, amount) = <(Key, U256)>::from_bytes(fields).unwrap().0;
+                (None, Some(recipient), amount)
+            }
+            "Burn" => {
+                let (owner, amount) = <(Key, U256)>::from_bytes(fields).unwrap().0;
+                (Some(owner), None, amount)
+            }
+            _ => continue,
+        };
+        if let Some(from) = from {
+            let balance = balances.entry(from).or_default();
+            *balance = balance.checked_sub(amount).expect("should not go negative");
+        }
+        if let Some(to) = to {
+            *balances.entry(to).or_default() += amount;
+        }
+    }
+    balances
+}
+
+#[test]
+fn should_emit_cep18_events_for_every_balance_change() {
+    let (mut builder, test_context) = setup_cep18();
+    let TestContext {
+        csprusd_token,
+        csprusd_test_contract_package,
+    } = test_context;
+    let holder = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let borrower = Key::from(csprusd_test_contract_package);
+    let package_key = csprusd_package_key(&builder);
+
+    call_as_minter(
+        &mut builder,
+        csprusd_token,
+        METHOD_MINT,
+        holder,
+        U256::from(500),
+    );
+    builder.expect_success();
+    call_as_minter(
+        &mut builder,
+        csprusd_token,
+        METHOD_MINT,
+        borrower,
+        U256::from(50),
+    );
+    builder.expect_success();
+
+    // a 10% fee, and tokens minted to the package and then rescued
+    call_as_minter(
+        &mut builder,
+        csprusd_token,
+        METHOD_MINT,
+        package_key,
+        U256::from(10),
+    );
+    builder.expect_success();
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_FEE,
+        runtime_args! {ARG_FEE_BPS => 1_000u32, ARG_MAX_FEE => U256::zero()},
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_TRANSFER,
+        runtime_args! {ARG_RECIPIENT => recipient, ARG_AMOUNT => U256::from(100)},
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_RESCUE_TOKENS,
+        runtime_args! {TO => recipient, ARG_AMOUNT => U256::from(10)},
+    );
+
+    // the owner is also the controller after install
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_CONTROLLER_TRANSFER,
+        runtime_args! {
+            FROM => recipient,
+            TO => holder,
+            ARG_AMOUNT => U256::from(20),
+            ARG_LEGAL_REFERENCE => "case 1".to_string(),
+        },
+    );
+
+    // escrow moves in and out of custody
+    call(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_CREATE_ESCROW,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => U256::from(40),
+            ARG_RELEASE_TIME => 10_000u64,
+            ARG_ARBITER => Option::<Key>::None,
+        },
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_RELEASE_ESCROW,
+        runtime_args! {ARG_ESCROW_ID => 1u64},
+    );
+
+    // a flash loan mints, burns and charges a 1% fee
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_FLASH_LOAN_CONFIG,
+        runtime_args! {
+            ARG_FLASH_FEE_BPS => 100u32,
+            ARG_MAX_FLASH_LOAN => U256::from(10_000),
+        },
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_FLASH_LOAN,
+        runtime_args! {
+            ARG_RECEIVER => borrower,
+            ARG_AMOUNT => U256::from(1_000),
+            ARG_DATA => Bytes::new(),
+        },
+    );
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_BRIDGE_BURN,
+        runtime_args! {
+            ARG_AMOUNT => U256::from(30),
+            ARG_DESTINATION_CHAIN_ID => 7u64,
+            ARG_DESTINATION_ADDRESS => Bytes::from(vec![0xab; 20]),
+        },
+    );
+
+    let balances = balances_from_cep18_events(&builder, csprusd_token);
+    for key in [
+        holder,
+        recipient,
+        borrower,
+        package_key,
+        Key::Account(*ACCOUNT_1_ADDR),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            balances.get(key).copied().unwrap_or_default(),
+            csprusd_check_balance_of(&mut builder, &csprusd_token, *key),
+            "{:?}",
+            key
+        );
+    }
+    let total_supply: U256 = builder.get_value(csprusd_token, TOTAL_SUPPLY_KEY);
+    assert_eq!(
+        balances
+            .values()
+            .fold(U256::zero(), |sum, balance| sum + balance),
+        total_supply
+    );
+}
@@ -0,0 +1,172 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_2_ADDR, ADDRESS, ARG_AMOUNT, ARG_RECIPIENT, ARG_TIMESTAMP, ARG_TOKEN_CONTRACT,
+        CHECK_PAST_BALANCE_ENTRYPOINT, ERROR_LOOKUP_NOT_IN_PAST, METHOD_TRANSFER,
+    },
+    installer_request_builders::{
+        csprusd_check_past_balance, csprusd_check_past_total_supply, mint_to, setup, TestContext,
+    },
+};
+
+fn transfer_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    recipient: Key,
+    amount: U256,
+    block_time: u64,
+) {
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_token,
+        METHOD_TRANSFER,
+        runtime_args! {ARG_RECIPIENT => recipient, ARG_AMOUNT => amount},
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+}
+
+#[test]
+fn should_return_past_balances_by_block_time() {
+    let (mut builder, test_context) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        sender,
+        U256::from(100),
+    );
+
+    transfer_at(
+        &mut builder,
+        &test_context,
+        recipient,
+        U256::from(10),
+        2_000,
+    );
+    transfer_at(
+        &mut builder,
+        &test_context,
+        recipient,
+        U256::from(20),
+        3_000,
+    );
+    // same block overwrites the last checkpoint
+    transfer_at(&mut builder, &test_context, recipient, U256::from(5), 3_000);
+
+    assert_eq!(
+        csprusd_check_past_balance(&mut builder, &test_context, sender, 1_999),
+        U256::from(100)
+    );
+    assert_eq!(
+        csprusd_check_past_balance(&mut builder, &test_context, sender, 2_000),
+        U256::from(90)
+    );
+    assert_eq!(
+        csprusd_check_past_balance(&mut builder, &test_context, sender, 2_999),
+        U256::from(90)
+    );
+    assert_eq!(
+        csprusd_check_past_balance(&mut builder, &test_context, sender, 3_000),
+        U256::from(65)
+    );
+    assert_eq!(
+        csprusd_check_past_balance(&mut builder, &test_context, recipient, 1_999),
+        U256::zero()
+    );
+    assert_eq!(
+        csprusd_check_past_balance(&mut builder, &test_context, recipient, 10_000),
+        U256::from(35)
+    );
+    assert_eq!(
+        csprusd_check_past_total_supply(&mut builder, &test_context, 5_000),
+        U256::from(100)
+    );
+}
+
+#[test]
+fn should_not_look_up_current_block() {
+    let (mut builder, test_context) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        sender,
+        U256::from(100),
+    );
+
+    // the checkpoint of the current block can still be overwritten
+    let check_past_balance_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_PAST_BALANCE_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            ADDRESS => sender,
+            ARG_TIMESTAMP => 2_000u64,
+        },
+    )
+    .with_block_time(2_000)
+    .build();
+    builder.exec(check_past_balance_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_LOOKUP_NOT_IN_PAST),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_bound_checkpoint_overhead_on_transfer() {
+    let (mut builder, test_context) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        sender,
+        U256::from(100),
+    );
+
+    // overwriting the checkpoint of the current block is the cheapest case
+    transfer_at(&mut builder, &test_context, recipient, U256::one(), 1_000);
+    transfer_at(&mut builder, &test_context, recipient, U256::one(), 1_000);
+    let same_block_cost = builder.last_exec_gas_cost();
+
+    transfer_at(&mut builder, &test_context, recipient, U256::one(), 2_000);
+    let first_append_cost = builder.last_exec_gas_cost();
+    assert!(same_block_cost <= first_append_cost);
+
+    // appending doesn't get more expensive as the history grows
+    for block_time in 3..20u64 {
+        transfer_at(
+            &mut builder,
+            &test_context,
+            recipient,
+            U256::one(),
+            block_time * 1_000,
+        );
+    }
+    let later_append_cost = builder.last_exec_gas_cost();
+    assert!(
+        later_append_cost.value() * 100 <= first_append_cost.value() * 101,
+        "first append {}, after 20 checkpoints {}",
+        first_append_cost,
+        later_append_cost
+    );
+}
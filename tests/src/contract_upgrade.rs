@@ -10,16 +10,18 @@ use casper_types::{
 use crate::utility::{
     constants::{
         ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, ACCOUNT_2_ADDR, AMOUNT, ARG_CURRENCY, ARG_DECIMALS,
-        ARG_MASTER_MINTER, ARG_NAME, ARG_SYMBOL, BLACKLISTER, CONFIGURE_MINTER_ENTRY_POINT_NAME,
-        CONTRACT_HASH, CSPR_USD_TEST_CONTRACT_WASM, ERROR_EXCEEDS_MINT_ALLOWANCE, METHOD_MINT,
-        MINTER, MINTER_ALLOWED, OWNER, PACKAGE_HASH, PAUSER, RECIPIENT, TOKEN_CURRENCY,
-        TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+        ARG_MASTER_MINTER, ARG_NAME, ARG_SYMBOL, ARG_UPGRADER, BLACKLISTER,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME, CONTRACT_HASH, CSPR_USD_CONTRACT_WASM,
+        CSPR_USD_TEST_CONTRACT_WASM, ERROR_EXCEEDS_MINT_ALLOWANCE, ERROR_UPGRADE_NOT_AUTHORIZED,
+        METHOD_AUTHORIZE_UPGRADE, METHOD_MINT, MINTER, MINTER_ALLOWED, OWNER, PACKAGE_HASH, PAUSER,
+        RECIPIENT, TOKEN_CURRENCY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL, UPGRADER_KEY,
     },
-    installer_request_builders::csprusd_check_total_supply,
+    installer_request_builders::{csprusd_check_total_supply, setup},
 };
 
 use casper_execution_engine::core::{
-    engine_state::Error as CoreError, execution::Error as ExecError,
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
 };
 
 /// Test assuring us that contract upgrades work as expected
@@ -230,3 +232,65 @@ fn test_contract_upgrades() {
         error
     );
 }
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+fn installed_contract_hash(builder: &InMemoryWasmTestBuilder) -> ContractHash {
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CONTRACT_HASH)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash")
+}
+
+fn upgrade_request() -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CSPR_USD_CONTRACT_WASM,
+        RuntimeArgs::default(),
+    )
+    .build()
+}
+
+#[test]
+fn should_only_upgrade_once_authorized_by_owner() {
+    let (mut builder, test_context) = setup();
+    let account_1_key: Key = Key::Account(*ACCOUNT_1_ADDR);
+
+    // the installing account no longer holds the package access URef
+    builder.exec(upgrade_request()).commit();
+    assert_user_error(&builder, ERROR_UPGRADE_NOT_AUTHORIZED);
+
+    let authorize_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.csprusd_token,
+        METHOD_AUTHORIZE_UPGRADE,
+        runtime_args! {ARG_UPGRADER => Key::Account(*DEFAULT_ACCOUNT_ADDR)},
+    )
+    .build();
+    builder.exec(authorize_request).expect_success().commit();
+
+    builder.exec(upgrade_request()).expect_success().commit();
+
+    let upgraded_token = installed_contract_hash(&builder);
+    assert_ne!(upgraded_token, test_context.csprusd_token);
+    let owner: Key = builder.get_value(upgraded_token, OWNER);
+    assert_eq!(owner, account_1_key);
+    let upgrader: Option<Key> = builder.get_value(upgraded_token, UPGRADER_KEY);
+    assert_eq!(upgrader, None);
+
+    // the authorization is used up by the upgrade
+    builder.exec(upgrade_request()).commit();
+    assert_user_error(&builder, ERROR_UPGRADE_NOT_AUTHORIZED);
+    assert_eq!(installed_contract_hash(&builder), upgraded_token);
+}
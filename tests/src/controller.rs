@@ -0,0 +1,141 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_LEGAL_REFERENCE, BLACKLIST,
+        BLACKLISTED_ACCOUNT, CONTROLLER_DISABLED_KEY, ERROR_CONTROLLER_DISABLED,
+        ERROR_NOT_CONTROLLER, FROM, KEY, METHOD_CONTROLLER_TRANSFER, METHOD_DISABLE_CONTROLLER, TO,
+    },
+    installer_request_builders::{csprusd_check_balance_of, mint_to, setup},
+};
+
+const LEGAL_REFERENCE: &str = "case 2024-CV-0042";
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+fn controller_transfer(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    controller: AccountHash,
+    from: Key,
+    to: Key,
+    amount: U256,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        controller,
+        csprusd_token,
+        METHOD_CONTROLLER_TRANSFER,
+        runtime_args! {
+            FROM => from,
+            TO => to,
+            ARG_AMOUNT => amount,
+            ARG_LEGAL_REFERENCE => LEGAL_REFERENCE.to_string(),
+        },
+    )
+    .build();
+    builder.exec(request).commit();
+}
+
+#[test]
+fn controller_can_move_funds_from_blacklisted_account() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+    let lost_account = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recovery_account = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(&mut builder, csprusd_token, lost_account, U256::from(100));
+
+    let blacklist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        BLACKLIST,
+        runtime_args! {KEY => lost_account},
+    )
+    .build();
+    builder.exec(blacklist_request).expect_success().commit();
+
+    controller_transfer(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        lost_account,
+        recovery_account,
+        U256::from(60),
+    );
+    assert_user_error(&builder, ERROR_NOT_CONTROLLER);
+
+    // the owner is the controller after install
+    controller_transfer(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        lost_account,
+        recovery_account,
+        U256::from(60),
+    );
+    builder.expect_success();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, lost_account),
+        U256::from(40)
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, recovery_account),
+        U256::from(60)
+    );
+
+    // funds can't be moved to a blacklisted account
+    controller_transfer(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        recovery_account,
+        lost_account,
+        U256::from(10),
+    );
+    assert_user_error(&builder, BLACKLISTED_ACCOUNT);
+}
+
+#[test]
+fn should_not_controller_transfer_once_disabled() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+    let holder = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    mint_to(&mut builder, csprusd_token, holder, U256::from(100));
+
+    let disable_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_DISABLE_CONTROLLER,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(disable_request).expect_success().commit();
+
+    let controller_disabled: bool = builder.get_value(csprusd_token, CONTROLLER_DISABLED_KEY);
+    assert!(controller_disabled);
+
+    controller_transfer(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        holder,
+        Key::Account(*ACCOUNT_2_ADDR),
+        U256::from(10),
+    );
+    assert_user_error(&builder, ERROR_CONTROLLER_DISABLED);
+}
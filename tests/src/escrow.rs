@@ -0,0 +1,281 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_ARBITER, ARG_ESCROW_ID, ARG_RECIPIENT,
+        ARG_RELEASE_TIME, ARG_TO_RECIPIENT, CUSTODY_LOCKED_KEY, ERROR_ESCROW_NOT_EXPIRED,
+        ERROR_ESCROW_NOT_PENDING, ERROR_ESCROW_RELEASE_TIME_NOT_IN_FUTURE,
+        ERROR_INVALID_ESCROW_ARBITER, ERROR_NOT_ARBITER, ERROR_NOT_ESCROW_PARTY, ESCROW_COUNT_KEY,
+        METHOD_CREATE_ESCROW, METHOD_REFUND_ESCROW, METHOD_RELEASE_ESCROW, METHOD_RESOLVE_ESCROW,
+    },
+    installer_request_builders::{csprusd_check_balance_of, mint_to, setup, TestContext},
+};
+
+const RELEASE_TIME: u64 = 10_000;
+
+fn create_escrow(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    recipient: Key,
+    amount: U256,
+    arbiter: Option<Key>,
+) {
+    let create_escrow_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_token,
+        METHOD_CREATE_ESCROW,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount,
+            ARG_RELEASE_TIME => RELEASE_TIME,
+            ARG_ARBITER => arbiter,
+        },
+    )
+    .with_block_time(1_000)
+    .build();
+    builder
+        .exec(create_escrow_request)
+        .expect_success()
+        .commit();
+}
+
+fn settle_escrow(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    caller: AccountHash,
+    method: &str,
+    args: RuntimeArgs,
+    block_time: u64,
+) {
+    let settle_request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        test_context.csprusd_token,
+        method,
+        args,
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(settle_request).commit();
+}
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected_error: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_lock_and_release_escrow() {
+    let (mut builder, test_context) = setup();
+    let creator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        creator,
+        U256::from(100),
+    );
+    create_escrow(&mut builder, &test_context, recipient, U256::from(40), None);
+
+    let escrow_count: u64 = builder.get_value(test_context.csprusd_token, ESCROW_COUNT_KEY);
+    assert_eq!(escrow_count, 1);
+    let custody_locked: U256 = builder.get_value(test_context.csprusd_token, CUSTODY_LOCKED_KEY);
+    assert_eq!(custody_locked, U256::from(40));
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, creator),
+        U256::from(60)
+    );
+
+    // the recipient can't release the funds to themselves, not even after the release time
+    settle_escrow(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_RELEASE_ESCROW,
+        runtime_args! {ARG_ESCROW_ID => 1u64},
+        RELEASE_TIME,
+    );
+    assert_user_error(&builder, ERROR_NOT_ESCROW_PARTY);
+
+    settle_escrow(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_RELEASE_ESCROW,
+        runtime_args! {ARG_ESCROW_ID => 1u64},
+        2_000,
+    );
+    builder.expect_success();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, recipient),
+        U256::from(40)
+    );
+    let custody_locked: U256 = builder.get_value(test_context.csprusd_token, CUSTODY_LOCKED_KEY);
+    assert_eq!(custody_locked, U256::zero());
+
+    // a settled escrow can't be settled again
+    settle_escrow(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_RELEASE_ESCROW,
+        runtime_args! {ARG_ESCROW_ID => 1u64},
+        3_000,
+    );
+    assert_user_error(&builder, ERROR_ESCROW_NOT_PENDING);
+}
+
+#[test]
+fn should_refund_escrow_only_after_release_time() {
+    let (mut builder, test_context) = setup();
+    let creator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        creator,
+        U256::from(100),
+    );
+    create_escrow(&mut builder, &test_context, recipient, U256::from(40), None);
+
+    settle_escrow(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_REFUND_ESCROW,
+        runtime_args! {ARG_ESCROW_ID => 1u64},
+        RELEASE_TIME - 1,
+    );
+    assert_user_error(&builder, ERROR_ESCROW_NOT_EXPIRED);
+
+    settle_escrow(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_REFUND_ESCROW,
+        runtime_args! {ARG_ESCROW_ID => 1u64},
+        RELEASE_TIME,
+    );
+    builder.expect_success();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, creator),
+        U256::from(100)
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, recipient),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_let_only_arbiter_resolve_escrow() {
+    let (mut builder, test_context) = setup();
+    let creator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    let arbiter = Key::Account(*ACCOUNT_1_ADDR);
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        creator,
+        U256::from(100),
+    );
+    create_escrow(
+        &mut builder,
+        &test_context,
+        recipient,
+        U256::from(40),
+        Some(arbiter),
+    );
+
+    settle_escrow(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_RESOLVE_ESCROW,
+        runtime_args! {ARG_ESCROW_ID => 1u64, ARG_TO_RECIPIENT => true},
+        2_000,
+    );
+    assert_user_error(&builder, ERROR_NOT_ARBITER);
+
+    // the arbiter may refund the creator before the release time
+    settle_escrow(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        METHOD_RESOLVE_ESCROW,
+        runtime_args! {ARG_ESCROW_ID => 1u64, ARG_TO_RECIPIENT => false},
+        2_000,
+    );
+    builder.expect_success();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, creator),
+        U256::from(100)
+    );
+}
+
+#[test]
+fn should_reject_invalid_escrow_terms() {
+    let (mut builder, test_context) = setup();
+    let creator = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        creator,
+        U256::from(100),
+    );
+
+    let invalid_terms = vec![
+        (RELEASE_TIME, None, ERROR_ESCROW_RELEASE_TIME_NOT_IN_FUTURE),
+        (
+            RELEASE_TIME - 1,
+            None,
+            ERROR_ESCROW_RELEASE_TIME_NOT_IN_FUTURE,
+        ),
+        (
+            RELEASE_TIME + 1,
+            Some(creator),
+            ERROR_INVALID_ESCROW_ARBITER,
+        ),
+        (
+            RELEASE_TIME + 1,
+            Some(recipient),
+            ERROR_INVALID_ESCROW_ARBITER,
+        ),
+    ];
+    for (release_time, arbiter, expected_error) in invalid_terms {
+        settle_escrow(
+            &mut builder,
+            &test_context,
+            *DEFAULT_ACCOUNT_ADDR,
+            METHOD_CREATE_ESCROW,
+            runtime_args! {
+                ARG_RECIPIENT => recipient,
+                ARG_AMOUNT => U256::from(40),
+                ARG_RELEASE_TIME => release_time,
+                ARG_ARBITER => arbiter,
+            },
+            RELEASE_TIME,
+        );
+        assert_user_error(&builder, expected_error);
+    }
+
+    let escrow_count: u64 = builder.get_value(test_context.csprusd_token, ESCROW_COUNT_KEY);
+    assert_eq!(escrow_count, 0);
+}
@@ -0,0 +1,306 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_ACCOUNT_PUBLIC_KEY, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    runtime_args, ApiError, ContractHash, Key, PublicKey, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, ACCOUNT_2_ADDR, ACTION_SET_FEE_RECIPIENT, AMOUNT,
+        ARG_ACTION, ARG_ACTION_ID, ARG_AMOUNT, ARG_CURRENCY, ARG_DATA, ARG_DECIMALS, ARG_DELAY,
+        ARG_ENABLE_CEP18_COMPATIBILITY, ARG_ETA, ARG_EVENTS_SCHEMA_VERSION, ARG_FEE_BPS,
+        ARG_MASTER_MINTER, ARG_MAX_FEE, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_SPENDER,
+        ARG_SYMBOL, BLACKLISTER, CONFIGURE_MINTER_ENTRY_POINT_NAME, CSPR_USD_CONTRACT_WASM,
+        ERROR_UNSUPPORTED_EVENTS_SCHEMA, EVENTS_SCHEMA_VERSION_KEY, METHOD_APPROVE,
+        METHOD_EXECUTE_QUEUED_ACTION, METHOD_MINT, METHOD_QUEUE_ACTION, METHOD_SET_FEE,
+        METHOD_SET_FEE_RECIPIENT, METHOD_SET_TIMELOCK_DELAY, METHOD_TRANSFER, METHOD_TRANSFER_FROM,
+        METHOD_TRANSFER_OWNERSHIP, METHOD_UPDATE_PAUSER, MINTER, MINTER_ALLOWED, NEW, OWNER,
+        PAUSER, RECIPIENT, TOKEN_CURRENCY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+    },
+    installer_request_builders::{
+        csprusd_check_allowance_of, csprusd_check_balance_of, csprusd_events, setup,
+        setup_with_args, TestContext,
+    },
+};
+
+fn install_args(events_schema_version: u8) -> RuntimeArgs {
+    let account_1_key = Key::Account(*ACCOUNT_1_ADDR);
+    runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_CURRENCY => TOKEN_CURRENCY,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_MASTER_MINTER => account_1_key,
+        PAUSER => ACCOUNT_1_PUBLIC_KEY.clone(),
+        BLACKLISTER => ACCOUNT_1_PUBLIC_KEY.clone(),
+        OWNER => account_1_key,
+        ARG_EVENTS_SCHEMA_VERSION => events_schema_version,
+    }
+}
+
+fn call(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    caller: AccountHash,
+    method: &str,
+    args: RuntimeArgs,
+) {
+    let request =
+        ExecuteRequestBuilder::contract_call_by_hash(caller, csprusd_token, method, args).build();
+    builder.exec(request).expect_success().commit();
+}
+
+/// Splits the most recent event named `name` into its fields.
+fn last_event(builder: &InMemoryWasmTestBuilder, csprusd_token: ContractHash, name: &str) -> Bytes {
+    csprusd_events(builder, csprusd_token)
+        .into_iter()
+        .rev()
+        .find_map(|event| {
+            let (event_name, fields) = String::from_bytes(&event).unwrap();
+            (event_name.trim_start_matches("event_") == name).then(|| Bytes::from(fields))
+        })
+        .expect("should have event")
+}
+
+#[test]
+fn should_default_to_events_schema_version_1() {
+    let (builder, TestContext { csprusd_token, .. }) = setup();
+
+    let events_schema_version: u8 = builder.get_value(csprusd_token, EVENTS_SCHEMA_VERSION_KEY);
+    assert_eq!(events_schema_version, 1);
+}
+
+#[test]
+fn should_operate_with_events_schema_version_2() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup_with_args(install_args(2));
+    let events_schema_version: u8 = builder.get_value(csprusd_token, EVENTS_SCHEMA_VERSION_KEY);
+    assert_eq!(events_schema_version, 2);
+
+    let account_1_key = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2_key = Key::Account(*ACCOUNT_2_ADDR);
+    let default_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        runtime_args! {MINTER => account_1_key, MINTER_ALLOWED => U256::from(1_000)},
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_MINT,
+        runtime_args! {RECIPIENT => account_1_key, AMOUNT => U256::from(1_000)},
+    );
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_TRANSFER,
+        runtime_args! {ARG_RECIPIENT => account_2_key, ARG_AMOUNT => U256::from(300)},
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_APPROVE,
+        runtime_args! {ARG_SPENDER => default_key, ARG_AMOUNT => U256::from(200)},
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_TRANSFER_FROM,
+        runtime_args! {
+            ARG_OWNER => account_1_key,
+            ARG_RECIPIENT => account_2_key,
+            ARG_AMOUNT => U256::from(150),
+        },
+    );
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, account_1_key),
+        U256::from(550)
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, account_2_key),
+        U256::from(450)
+    );
+    assert_eq!(
+        csprusd_check_allowance_of(&mut builder, account_1_key, default_key),
+        U256::from(50)
+    );
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_UPDATE_PAUSER,
+        runtime_args! {NEW => DEFAULT_ACCOUNT_PUBLIC_KEY.clone()},
+    );
+    let pauser: PublicKey = builder.get_value(csprusd_token, PAUSER);
+    assert_eq!(pauser, DEFAULT_ACCOUNT_PUBLIC_KEY.clone());
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_TRANSFER_OWNERSHIP,
+        runtime_args! {NEW => account_2_key},
+    );
+    let owner: Key = builder.get_value(csprusd_token, OWNER);
+    assert_eq!(owner, account_2_key);
+}
+
+#[test]
+fn should_reject_unsupported_events_schema_at_install() {
+    let mut cep18_install_args = install_args(2);
+    cep18_install_args
+        .insert(ARG_ENABLE_CEP18_COMPATIBILITY, true)
+        .unwrap();
+
+    for args in vec![install_args(0), install_args(3), cep18_install_args] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+        let install_request =
+            ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CSPR_USD_CONTRACT_WASM, args)
+                .build();
+        builder.exec(install_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_UNSUPPORTED_EVENTS_SCHEMA),
+            "{:?}",
+            error
+        );
+    }
+}
+
+#[test]
+fn should_report_previous_values_and_balances_in_version_2_events() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup_with_args(install_args(2));
+
+    let account_1_key = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2_key = Key::Account(*ACCOUNT_2_ADDR);
+    let default_key = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_FEE_RECIPIENT,
+        runtime_args! {NEW => account_2_key},
+    );
+    let event = last_event(&builder, csprusd_token, "FeeRecipientChanged");
+    let ((previous_fee_recipient, new_fee_recipient, caller), _) =
+        <(Key, Key, Key)>::from_bytes(&event).unwrap();
+    assert_eq!(previous_fee_recipient, account_1_key);
+    assert_eq!(new_fee_recipient, account_2_key);
+    assert_eq!(caller, account_1_key);
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_FEE,
+        runtime_args! {ARG_FEE_BPS => 100u32, ARG_MAX_FEE => U256::from(50)},
+    );
+    let event = last_event(&builder, csprusd_token, "FeeChanged");
+    let ((previous_fee_bps, previous_max_fee, fee_bps, max_fee), _) =
+        <(u32, U256, u32, U256)>::from_bytes(&event).unwrap();
+    assert_eq!((previous_fee_bps, previous_max_fee), (0, U256::zero()));
+    assert_eq!((fee_bps, max_fee), (100, U256::from(50)));
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        runtime_args! {MINTER => account_1_key, MINTER_ALLOWED => U256::from(1_000)},
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_MINT,
+        runtime_args! {RECIPIENT => account_1_key, AMOUNT => U256::from(1_000)},
+    );
+    let event = last_event(&builder, csprusd_token, "Mint");
+    let ((minter, recipient, amount, reference, recipient_balance, total_supply), _) =
+        <(Key, Key, U256, Option<String>, U256, U256)>::from_bytes(&event).unwrap();
+    assert_eq!((minter, recipient), (account_1_key, account_1_key));
+    assert_eq!(amount, U256::from(1_000));
+    assert_eq!(reference, None);
+    assert_eq!(recipient_balance, U256::from(1_000));
+    assert_eq!(total_supply, U256::from(1_000));
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_TRANSFER,
+        runtime_args! {ARG_RECIPIENT => default_key, ARG_AMOUNT => U256::from(500)},
+    );
+    let event = last_event(&builder, csprusd_token, "FeeCharged");
+    let ((payer, fee_recipient, fee, payer_balance, fee_recipient_balance), _) =
+        <(Key, Key, U256, U256, U256)>::from_bytes(&event).unwrap();
+    assert_eq!((payer, fee_recipient), (account_1_key, account_2_key));
+    assert_eq!(fee, U256::from(5));
+    // The fee moves before the transfer itself.
+    assert_eq!(payer_balance, U256::from(995));
+    assert_eq!(fee_recipient_balance, U256::from(5));
+}
+
+#[test]
+fn should_report_queuing_account_as_caller_of_timelocked_actions() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup_with_args(install_args(2));
+    let delay: u64 = 1_000;
+    let account_1_key = Key::Account(*ACCOUNT_1_ADDR);
+    let account_2_key = Key::Account(*ACCOUNT_2_ADDR);
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_TIMELOCK_DELAY,
+        runtime_args! {ARG_DELAY => delay},
+    );
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_QUEUE_ACTION,
+        runtime_args! {
+            ARG_ACTION => ACTION_SET_FEE_RECIPIENT,
+            ARG_DATA => Bytes::from(account_2_key.to_bytes().unwrap()),
+            ARG_ETA => delay,
+        },
+    );
+
+    // anybody may execute the queued action, but the owner authorized it
+    let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_EXECUTE_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+    )
+    .with_block_time(delay)
+    .build();
+    builder.exec(execute_request).expect_success().commit();
+
+    let event = last_event(&builder, csprusd_token, "FeeRecipientChanged");
+    let ((previous_fee_recipient, new_fee_recipient, caller), _) =
+        <(Key, Key, Key)>::from_bytes(&event).unwrap();
+    assert_eq!(previous_fee_recipient, account_1_key);
+    assert_eq!(new_fee_recipient, account_2_key);
+    assert_eq!(caller, account_1_key);
+}
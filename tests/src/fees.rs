@@ -0,0 +1,182 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_EXEMPT, ARG_FEE_BPS, ARG_MAX_FEE, BLACKLIST,
+        BLACKLISTED_ACCOUNT, ERROR_CANNOT_TRANSFER_TO_CONTRACT, ERROR_INVALID_FEE_BPS, FEE_BPS_KEY,
+        FEE_RECIPIENT_KEY, KEY, METHOD_SET_FEE, METHOD_SET_FEE_EXEMPT, METHOD_SET_FEE_RECIPIENT,
+        NEW,
+    },
+    installer_request_builders::{
+        csprusd_check_balance_of, csprusd_package_key, make_csprusd_transfer_request, mint_to,
+        setup, TestContext,
+    },
+};
+
+const TREASURY: Key = Key::Account(AccountHash::new([77; 32]));
+
+#[test]
+fn should_not_charge_fee_by_default() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+
+    let fee_bps: u32 = builder.get_value(csprusd_token, FEE_BPS_KEY);
+    assert_eq!(fee_bps, 0);
+
+    let fee_recipient: Key = builder.get_value(csprusd_token, FEE_RECIPIENT_KEY);
+    assert_eq!(fee_recipient, Key::Account(*ACCOUNT_1_ADDR));
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(&mut builder, csprusd_token, sender, U256::from(1_000));
+
+    let transfer_request =
+        make_csprusd_transfer_request(sender, &csprusd_token, recipient, U256::from(1_000));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, recipient),
+        U256::from(1_000)
+    );
+}
+
+#[test]
+fn should_charge_capped_fee_to_treasury_unless_exempt() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(&mut builder, csprusd_token, sender, U256::from(100_000));
+
+    let set_fee_recipient_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_SET_FEE_RECIPIENT,
+        runtime_args! {NEW => TREASURY},
+    )
+    .build();
+    builder
+        .exec(set_fee_recipient_request)
+        .expect_success()
+        .commit();
+
+    // 1% capped at 50
+    let set_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_SET_FEE,
+        runtime_args! {ARG_FEE_BPS => 100u32, ARG_MAX_FEE => U256::from(50)},
+    )
+    .build();
+    builder.exec(set_fee_request).expect_success().commit();
+
+    let transfer_request =
+        make_csprusd_transfer_request(sender, &csprusd_token, recipient, U256::from(1_000));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, recipient),
+        U256::from(990)
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, TREASURY),
+        U256::from(10)
+    );
+
+    let transfer_request =
+        make_csprusd_transfer_request(sender, &csprusd_token, recipient, U256::from(10_000));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, TREASURY),
+        U256::from(60)
+    );
+
+    let set_fee_exempt_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_SET_FEE_EXEMPT,
+        runtime_args! {KEY => sender, ARG_EXEMPT => true},
+    )
+    .build();
+    builder
+        .exec(set_fee_exempt_request)
+        .expect_success()
+        .commit();
+
+    let transfer_request =
+        make_csprusd_transfer_request(sender, &csprusd_token, recipient, U256::from(1_000));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, TREASURY),
+        U256::from(60)
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, sender),
+        U256::from(88_000)
+    );
+}
+
+#[test]
+fn should_not_set_fee_above_100_percent() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+
+    let set_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_SET_FEE,
+        runtime_args! {ARG_FEE_BPS => 10_001u32, ARG_MAX_FEE => U256::zero()},
+    )
+    .build();
+    builder.exec(set_fee_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_INVALID_FEE_BPS),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_set_blacklisted_or_custody_fee_recipient() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+
+    let blacklist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        BLACKLIST,
+        runtime_args! {KEY => TREASURY},
+    )
+    .build();
+    builder.exec(blacklist_request).expect_success().commit();
+
+    let custody = csprusd_package_key(&builder);
+    for (new_fee_recipient, expected_error) in vec![
+        (TREASURY, BLACKLISTED_ACCOUNT),
+        (custody, ERROR_CANNOT_TRANSFER_TO_CONTRACT),
+    ] {
+        let set_fee_recipient_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            csprusd_token,
+            METHOD_SET_FEE_RECIPIENT,
+            runtime_args! {NEW => new_fee_recipient},
+        )
+        .build();
+        builder.exec(set_fee_recipient_request).commit();
+
+        let error = builder.get_error().expect("should have error");
+        assert!(
+            matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+            "{:?}",
+            error
+        );
+    }
+
+    let fee_recipient: Key = builder.get_value(csprusd_token, FEE_RECIPIENT_KEY);
+    assert_eq!(fee_recipient, Key::Account(*ACCOUNT_1_ADDR));
+}
@@ -0,0 +1,192 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash, bytesrepr::Bytes, runtime_args, ApiError, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DATA, ARG_FLASH_FEE_BPS,
+        ARG_MAX_FLASH_LOAN, ARG_RECEIVER, ERROR_EXCEEDS_MAX_FLASH_LOAN,
+        ERROR_FLASH_LOAN_CALLBACK_FAILED, ERROR_FLASH_LOAN_NOT_REPAID,
+        ERROR_INSUFFICIENT_ALLOWANCE, METHOD_FLASH_LOAN, METHOD_SET_FLASH_LOAN_CONFIG,
+    },
+    installer_request_builders::{
+        csprusd_check_balance_of, csprusd_check_total_supply, get_test_result, mint_to, setup,
+        TestContext,
+    },
+};
+
+fn set_flash_loan_config(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    flash_fee_bps: u32,
+    max_flash_loan: U256,
+) {
+    let set_flash_loan_config_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.csprusd_token,
+        METHOD_SET_FLASH_LOAN_CONFIG,
+        runtime_args! {
+            ARG_FLASH_FEE_BPS => flash_fee_bps,
+            ARG_MAX_FLASH_LOAN => max_flash_loan,
+        },
+    )
+    .build();
+    builder
+        .exec(set_flash_loan_config_request)
+        .expect_success()
+        .commit();
+}
+
+fn make_flash_loan_request(
+    initiator: AccountHash,
+    test_context: &TestContext,
+    amount: U256,
+    data: Vec<u8>,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        initiator,
+        test_context.csprusd_token,
+        METHOD_FLASH_LOAN,
+        runtime_args! {
+            ARG_RECEIVER => Key::from(test_context.csprusd_test_contract_package),
+            ARG_AMOUNT => amount,
+            ARG_DATA => Bytes::from(data),
+        },
+    )
+    .build()
+}
+
+#[test]
+fn should_flash_loan_and_collect_fee_without_changing_total_supply() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        csprusd_token,
+        csprusd_test_contract_package,
+    } = test_context;
+    let borrower = Key::from(csprusd_test_contract_package);
+    let fee_recipient = Key::Account(*ACCOUNT_1_ADDR);
+
+    // 1% fee, borrower holds enough to pay it
+    set_flash_loan_config(&mut builder, &test_context, 100, U256::from(10_000));
+    mint_to(&mut builder, csprusd_token, borrower, U256::from(50));
+
+    let flash_loan_request = make_flash_loan_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        U256::from(1_000),
+        vec![],
+    );
+    builder.exec(flash_loan_request).expect_success().commit();
+
+    // the borrower recorded its balance while holding the loan
+    let balance_during_loan: U256 = get_test_result(&mut builder, csprusd_test_contract_package);
+    assert_eq!(balance_during_loan, U256::from(1_050));
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, borrower),
+        U256::from(40)
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, fee_recipient),
+        U256::from(10)
+    );
+    assert_eq!(
+        csprusd_check_total_supply(&mut builder, &csprusd_token),
+        U256::from(50)
+    );
+}
+
+#[test]
+fn should_not_flash_loan_above_max_or_without_repayment() {
+    let (mut builder, test_context) = setup();
+
+    // flash loans are disabled until a maximum is configured
+    let flash_loan_request =
+        make_flash_loan_request(*DEFAULT_ACCOUNT_ADDR, &test_context, U256::one(), vec![]);
+    builder.exec(flash_loan_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_EXCEEDS_MAX_FLASH_LOAN),
+        "{:?}",
+        error
+    );
+
+    set_flash_loan_config(&mut builder, &test_context, 0, U256::from(10_000));
+
+    // borrower is asked to send the loan away instead of repaying it
+    let flash_loan_request = make_flash_loan_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        U256::from(1_000),
+        vec![1],
+    );
+    builder.exec(flash_loan_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_FLASH_LOAN_NOT_REPAID),
+        "{:?}",
+        error
+    );
+}
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_flash_loan_on_failed_callback() {
+    let (mut builder, test_context) = setup();
+    set_flash_loan_config(&mut builder, &test_context, 0, U256::from(10_000));
+
+    // borrower is asked to not return the ERC-3156 success value
+    let flash_loan_request = make_flash_loan_request(
+        *DEFAULT_ACCOUNT_ADDR,
+        &test_context,
+        U256::from(1_000),
+        vec![2],
+    );
+    builder.exec(flash_loan_request).commit();
+    assert_user_error(&builder, ERROR_FLASH_LOAN_CALLBACK_FAILED);
+}
+
+#[test]
+fn should_not_charge_funded_receiver_for_third_party_flash_loan() {
+    let (mut builder, test_context) = setup();
+    let TestContext {
+        csprusd_token,
+        csprusd_test_contract_package,
+    } = test_context;
+    let borrower = Key::from(csprusd_test_contract_package);
+
+    set_flash_loan_config(&mut builder, &test_context, 100, U256::from(10_000));
+    mint_to(&mut builder, csprusd_token, borrower, U256::from(50));
+
+    // the borrower only approves repayment of loans its installer initiated
+    let flash_loan_request =
+        make_flash_loan_request(*ACCOUNT_2_ADDR, &test_context, U256::from(1_000), vec![]);
+    builder.exec(flash_loan_request).commit();
+    assert_user_error(&builder, ERROR_INSUFFICIENT_ALLOWANCE);
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, borrower),
+        U256::from(50)
+    );
+    assert_eq!(
+        csprusd_check_total_supply(&mut builder, &csprusd_token),
+        U256::from(50)
+    );
+}
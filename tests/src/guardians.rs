@@ -0,0 +1,169 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash, runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, AMOUNT, ARG_GUARDIANS, CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        ERROR_EXCEEDS_MINT_ALLOWANCE, ERROR_INVALID_GUARDIANS, ERROR_NOT_GUARDIAN,
+        ERROR_NOT_MINTER, GUARDIANS_KEY, IS_PAUSED, METHOD_GUARDIAN_PAUSE,
+        METHOD_GUARDIAN_REMOVE_MINTER, METHOD_GUARDIAN_REVOKE_MINTER_ALLOWANCE, METHOD_MINT,
+        METHOD_SET_GUARDIANS, METHOD_UNPAUSE, MINTER, MINTER_ALLOWED, NON_PAUSER_ERROR_CODE,
+        RECIPIENT,
+    },
+    installer_request_builders::setup,
+};
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+fn call(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    caller: AccountHash,
+    method: &str,
+    args: RuntimeArgs,
+) {
+    let request =
+        ExecuteRequestBuilder::contract_call_by_hash(caller, csprusd_token, method, args).build();
+    builder.exec(request).commit();
+}
+
+/// Installs the token with `ACCOUNT_2_ADDR` as the only guardian and `DEFAULT_ACCOUNT_ADDR` as a
+/// minter, and returns the token hash.
+fn setup_guardian() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_GUARDIANS,
+        runtime_args! {ARG_GUARDIANS => vec![Key::Account(*ACCOUNT_2_ADDR)]},
+    );
+    builder.expect_success();
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        runtime_args! {
+            MINTER => Key::Account(*DEFAULT_ACCOUNT_ADDR),
+            MINTER_ALLOWED => U256::from(1_000),
+        },
+    );
+    builder.expect_success();
+
+    let guardians: Vec<Key> = builder.get_value(csprusd_token, GUARDIANS_KEY);
+    assert_eq!(guardians, vec![Key::Account(*ACCOUNT_2_ADDR)]);
+    (builder, csprusd_token)
+}
+
+fn mint_one(builder: &mut InMemoryWasmTestBuilder, csprusd_token: ContractHash) {
+    call(
+        builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_MINT,
+        runtime_args! {RECIPIENT => Key::Account(*ACCOUNT_2_ADDR), AMOUNT => U256::one()},
+    );
+}
+
+#[test]
+fn guardian_can_pause_but_not_unpause() {
+    let (mut builder, csprusd_token) = setup_guardian();
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_GUARDIAN_PAUSE,
+        runtime_args! {},
+    );
+    assert_user_error(&builder, ERROR_NOT_GUARDIAN);
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_GUARDIAN_PAUSE,
+        runtime_args! {},
+    );
+    builder.expect_success();
+    let is_paused: bool = builder.get_value(csprusd_token, IS_PAUSED);
+    assert!(is_paused);
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_UNPAUSE,
+        runtime_args! {},
+    );
+    assert_user_error(&builder, NON_PAUSER_ERROR_CODE);
+}
+
+#[test]
+fn guardian_can_revoke_minter_allowance() {
+    let (mut builder, csprusd_token) = setup_guardian();
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_GUARDIAN_REVOKE_MINTER_ALLOWANCE,
+        runtime_args! {MINTER => Key::Account(*DEFAULT_ACCOUNT_ADDR)},
+    );
+    builder.expect_success();
+
+    mint_one(&mut builder, csprusd_token);
+    assert_user_error(&builder, ERROR_EXCEEDS_MINT_ALLOWANCE);
+}
+
+#[test]
+fn guardian_can_remove_minter() {
+    let (mut builder, csprusd_token) = setup_guardian();
+
+    mint_one(&mut builder, csprusd_token);
+    builder.expect_success();
+
+    call(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_GUARDIAN_REMOVE_MINTER,
+        runtime_args! {MINTER => Key::Account(*DEFAULT_ACCOUNT_ADDR)},
+    );
+    builder.expect_success();
+
+    mint_one(&mut builder, csprusd_token);
+    assert_user_error(&builder, ERROR_NOT_MINTER);
+}
+
+#[test]
+fn should_reject_duplicate_guardians() {
+    let (mut builder, test_context) = setup();
+    let guardian = Key::Account(*ACCOUNT_2_ADDR);
+
+    call(
+        &mut builder,
+        test_context.csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_GUARDIANS,
+        runtime_args! {ARG_GUARDIANS => vec![guardian, guardian]},
+    );
+    assert_user_error(&builder, ERROR_INVALID_GUARDIANS);
+}
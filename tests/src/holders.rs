@@ -0,0 +1,110 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_CURRENCY,
+        ARG_DECIMALS, ARG_ENABLE_HOLDER_REGISTRY, ARG_MASTER_MINTER, ARG_NAME, ARG_RECIPIENT,
+        ARG_SYMBOL, BLACKLISTER, ERROR_HOLDER_REGISTRY_DISABLED, HOLDER_COUNT_KEY,
+        METHOD_HOLDER_COUNT, METHOD_TRANSFER, OWNER, PAUSER, TOKEN_CURRENCY, TOKEN_DECIMALS,
+        TOKEN_NAME, TOKEN_SYMBOL,
+    },
+    installer_request_builders::{
+        csprusd_check_holders, mint_to, setup, setup_with_args, TestContext,
+    },
+};
+
+#[test]
+fn should_track_holders_entering_and_leaving() {
+    let (mut builder, test_context) = setup();
+    let holder_1 = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let holder_2 = Key::Account(*ACCOUNT_1_ADDR);
+    let holder_3 = Key::Account(*ACCOUNT_2_ADDR);
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        holder_1,
+        U256::from(100),
+    );
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        holder_2,
+        U256::from(100),
+    );
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        holder_3,
+        U256::from(100),
+    );
+
+    let holder_count: u32 = builder.get_value(test_context.csprusd_token, HOLDER_COUNT_KEY);
+    assert_eq!(holder_count, 3);
+    assert_eq!(
+        csprusd_check_holders(&mut builder, &test_context, 0, 10),
+        vec![holder_1, holder_2, holder_3]
+    );
+    assert_eq!(
+        csprusd_check_holders(&mut builder, &test_context, 1, 1),
+        vec![holder_2]
+    );
+
+    // emptying a balance moves the last holder into the freed index
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_token,
+        METHOD_TRANSFER,
+        runtime_args! {ARG_RECIPIENT => holder_2, ARG_AMOUNT => U256::from(100)},
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    let holder_count: u32 = builder.get_value(test_context.csprusd_token, HOLDER_COUNT_KEY);
+    assert_eq!(holder_count, 2);
+    assert_eq!(
+        csprusd_check_holders(&mut builder, &test_context, 0, 10),
+        vec![holder_3, holder_2]
+    );
+}
+
+#[test]
+fn should_not_track_holders_when_disabled() {
+    let account_1_key = Key::Account(*ACCOUNT_1_ADDR);
+    let (mut builder, TestContext { csprusd_token, .. }) = setup_with_args(runtime_args! {
+        ARG_NAME => TOKEN_NAME,
+        ARG_SYMBOL => TOKEN_SYMBOL,
+        ARG_CURRENCY => TOKEN_CURRENCY,
+        ARG_DECIMALS => TOKEN_DECIMALS,
+        ARG_MASTER_MINTER => account_1_key,
+        PAUSER => ACCOUNT_1_PUBLIC_KEY.clone(),
+        BLACKLISTER => ACCOUNT_1_PUBLIC_KEY.clone(),
+        OWNER => account_1_key,
+        ARG_ENABLE_HOLDER_REGISTRY => false,
+    });
+
+    mint_to(&mut builder, csprusd_token, account_1_key, U256::from(100));
+
+    let holder_count: u32 = builder.get_value(csprusd_token, HOLDER_COUNT_KEY);
+    assert_eq!(holder_count, 0);
+
+    let holder_count_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_HOLDER_COUNT,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(holder_count_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_HOLDER_REGISTRY_DISABLED),
+        "{:?}",
+        error
+    );
+}
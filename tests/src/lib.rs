@@ -1,20 +1,60 @@
 #[cfg(test)]
 mod allowance;
 #[cfg(test)]
+mod attestations;
+#[cfg(test)]
 mod blacklisting;
 #[cfg(test)]
+mod bridge;
+#[cfg(test)]
+mod cep18;
+#[cfg(test)]
+mod checkpoints;
+#[cfg(test)]
 mod contract_upgrade;
 #[cfg(test)]
+mod controller;
+#[cfg(test)]
+mod escrow;
+#[cfg(test)]
+mod events_schema;
+#[cfg(test)]
+mod fees;
+#[cfg(test)]
+mod flash_mint;
+#[cfg(test)]
+mod guardians;
+#[cfg(test)]
+mod holders;
+#[cfg(test)]
 mod install;
 #[cfg(test)]
 mod master_minter;
 #[cfg(test)]
+mod memo;
+#[cfg(test)]
 mod mint_and_burn;
 #[cfg(test)]
+mod multisig;
+#[cfg(test)]
 mod ownership;
 #[cfg(test)]
+mod rescue;
+#[cfg(test)]
+mod reserves;
+#[cfg(test)]
+mod snapshots;
+#[cfg(test)]
+mod streams;
+#[cfg(test)]
 mod take_a_pause;
 #[cfg(test)]
+mod timelock;
+#[cfg(test)]
 mod transfer;
 #[cfg(test)]
+mod transfer_restrictions;
+#[cfg(test)]
 mod utility;
+#[cfg(test)]
+mod vesting;
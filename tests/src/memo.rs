@@ -0,0 +1,112 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_MEMO, ARG_OWNER, ARG_RECIPIENT,
+        ERROR_MEMO_TOO_LONG, MAX_MEMO_LENGTH_KEY, METHOD_SET_MAX_MEMO_LENGTH,
+        METHOD_TRANSFER_FROM_WITH_MEMO, METHOD_TRANSFER_WITH_MEMO, NEW,
+    },
+    installer_request_builders::{
+        csprusd_check_balance_of, make_csprusd_approve_request, mint_to, setup, TestContext,
+    },
+};
+
+#[test]
+fn should_transfer_with_memo() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(&mut builder, csprusd_token, sender, U256::from(100));
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_TRANSFER_WITH_MEMO,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => U256::from(40),
+            ARG_MEMO => "invoice 42",
+        },
+    )
+    .build();
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, recipient),
+        U256::from(40)
+    );
+
+    let approve_request =
+        make_csprusd_approve_request(sender, &csprusd_token, recipient, U256::from(10));
+    builder.exec(approve_request).expect_success().commit();
+
+    let transfer_from_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        csprusd_token,
+        METHOD_TRANSFER_FROM_WITH_MEMO,
+        runtime_args! {
+            ARG_OWNER => sender,
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_1_ADDR),
+            ARG_AMOUNT => U256::from(10),
+            ARG_MEMO => "tag 7",
+        },
+    )
+    .build();
+    builder
+        .exec(transfer_from_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, sender),
+        U256::from(50)
+    );
+}
+
+#[test]
+fn should_not_transfer_with_memo_above_max_length() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    mint_to(&mut builder, csprusd_token, sender, U256::from(100));
+
+    let set_max_memo_length_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_SET_MAX_MEMO_LENGTH,
+        runtime_args! {NEW => 4u32},
+    )
+    .build();
+    builder
+        .exec(set_max_memo_length_request)
+        .expect_success()
+        .commit();
+
+    let max_memo_length: u32 = builder.get_value(csprusd_token, MAX_MEMO_LENGTH_KEY);
+    assert_eq!(max_memo_length, 4);
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_TRANSFER_WITH_MEMO,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_AMOUNT => U256::one(),
+            ARG_MEMO => "too long",
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_MEMO_TOO_LONG),
+        "{:?}",
+        error
+    );
+}
@@ -4,14 +4,15 @@ use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
 use crate::utility::{
     constants::{
         ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, AMOUNT, ARG_CURRENCY, ARG_DECIMALS,
-        ARG_MASTER_MINTER, ARG_NAME, ARG_SYMBOL, BLACKLISTER, CONFIGURE_MINTER_ENTRY_POINT_NAME,
-        ERROR_EXCEEDS_MINT_ALLOWANCE, ERROR_OVERFLOW, METHOD_BURN, METHOD_MINT, MINTER,
-        MINTER_ALLOWED, OWNER, PAUSER, RECIPIENT, TOKEN_CURRENCY, TOKEN_DECIMALS, TOKEN_NAME,
-        TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2, TOKEN_OWNER_AMOUNT_1, TOKEN_OWNER_AMOUNT_2,
-        TOKEN_SYMBOL,
+        ARG_MASTER_MINTER, ARG_NAME, ARG_REFERENCE, ARG_SYMBOL, BLACKLISTER,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME, ERROR_DUPLICATE_MINT_REFERENCE,
+        ERROR_EXCEEDS_MINT_ALLOWANCE, ERROR_OVERFLOW, METHOD_BURN, METHOD_MINT,
+        METHOD_MINT_WITH_REFERENCE, MINTER, MINTER_ALLOWED, OWNER, PAUSER, RECIPIENT,
+        TOKEN_CURRENCY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_OWNER_ADDRESS_1, TOKEN_OWNER_ADDRESS_2,
+        TOKEN_OWNER_AMOUNT_1, TOKEN_OWNER_AMOUNT_2, TOKEN_SYMBOL,
     },
     installer_request_builders::{
-        csprusd_check_balance_of, csprusd_check_total_supply, setup_with_args, TestContext,
+        csprusd_check_balance_of, csprusd_check_total_supply, setup, setup_with_args, TestContext,
     },
 };
 
@@ -233,3 +234,75 @@ fn test_should_not_mint_or_burn_above_limits() {
         error
     );
 }
+
+#[test]
+fn should_mint_each_reference_only_once() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+
+    let configure_minter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        runtime_args! {MINTER => Key::Account(*DEFAULT_ACCOUNT_ADDR), MINTER_ALLOWED => U256::from(1_000)},
+    )
+    .build();
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_MINT_WITH_REFERENCE,
+        runtime_args! {
+            RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+            AMOUNT => U256::from(100),
+            ARG_REFERENCE => "wire-2024-0001",
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+
+    // a retried deploy with the same reference must not issue again
+    let retried_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_MINT_WITH_REFERENCE,
+        runtime_args! {
+            RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+            AMOUNT => U256::from(100),
+            ARG_REFERENCE => "wire-2024-0001",
+        },
+    )
+    .build();
+    builder.exec(retried_mint_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_DUPLICATE_MINT_REFERENCE),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(100)
+    );
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_MINT_WITH_REFERENCE,
+        runtime_args! {
+            RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+            AMOUNT => U256::from(50),
+            ARG_REFERENCE => "wire-2024-0002",
+        },
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, TOKEN_OWNER_ADDRESS_1),
+        U256::from(150)
+    );
+}
@@ -0,0 +1,481 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, ApiError, ContractHash, Key, RuntimeArgs,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ACTION_AUTHORIZE_UPGRADE, ACTION_CANCEL_QUEUED_ACTION,
+        ACTION_CONFIGURE_MULTISIG, ACTION_DISABLE_CONTROLLER, ACTION_SET_TIMELOCK_DELAY,
+        ACTION_TRANSFER_OWNERSHIP, ACTION_UPDATE_MASTER_MINTER, ARG_ACTION, ARG_ACTION_ID,
+        ARG_DATA, ARG_ETA, ARG_EXPIRES_AT, ARG_PROPOSAL_ID, ARG_SIGNERS, ARG_THRESHOLD,
+        ARG_UPGRADER, CONTROLLER_DISABLED_KEY, ERROR_INVALID_MULTISIG_CONFIG,
+        ERROR_MULTISIG_REQUIRED, ERROR_PROPOSAL_ALREADY_EXECUTED, ERROR_PROPOSAL_EXPIRED,
+        ERROR_QUEUED_ACTION_NOT_PENDING, ERROR_QUEUED_BY_MULTISIG, ERROR_THRESHOLD_NOT_REACHED,
+        MASTER_MINTER, METHOD_APPROVE_PROPOSAL, METHOD_AUTHORIZE_UPGRADE,
+        METHOD_CANCEL_QUEUED_ACTION, METHOD_CONFIGURE_MULTISIG, METHOD_DISABLE_CONTROLLER,
+        METHOD_EXECUTE_PROPOSAL, METHOD_EXECUTE_QUEUED_ACTION, METHOD_PROPOSE, METHOD_QUEUE_ACTION,
+        METHOD_REVOKE_APPROVAL, NEW, OWNER, UPDATE_MASTER_MINTER_ENTRY_POINT_NAME, UPGRADER_KEY,
+    },
+    installer_request_builders::setup,
+};
+
+const EXPIRES_AT: u64 = 100_000;
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+fn call_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    caller: AccountHash,
+    method: &str,
+    args: RuntimeArgs,
+    block_time: u64,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(caller, csprusd_token, method, args)
+        .with_block_time(block_time)
+        .build();
+    builder.exec(request).commit();
+}
+
+/// Installs the token with a 2-of-3 multisig and returns the token hash.
+fn setup_multisig() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let (mut builder, test_context) = setup();
+    let signers = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+    ];
+    call_at(
+        &mut builder,
+        test_context.csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_CONFIGURE_MULTISIG,
+        runtime_args! {ARG_SIGNERS => signers, ARG_THRESHOLD => 2u32},
+        0,
+    );
+    builder.expect_success();
+    (builder, test_context.csprusd_token)
+}
+
+fn propose(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    proposer: AccountHash,
+    action: u8,
+    new: Key,
+) {
+    propose_data(
+        builder,
+        csprusd_token,
+        proposer,
+        action,
+        Bytes::from(new.to_bytes().unwrap()),
+    );
+}
+
+fn propose_data(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    proposer: AccountHash,
+    action: u8,
+    data: Bytes,
+) {
+    call_at(
+        builder,
+        csprusd_token,
+        proposer,
+        METHOD_PROPOSE,
+        runtime_args! {
+            ARG_ACTION => action,
+            ARG_DATA => data,
+            ARG_EXPIRES_AT => EXPIRES_AT,
+        },
+        1_000,
+    );
+    builder.expect_success();
+}
+
+#[test]
+fn should_execute_proposal_once_threshold_is_reached() {
+    let (mut builder, csprusd_token) = setup_multisig();
+    let new_owner = Key::Account(*ACCOUNT_2_ADDR);
+
+    propose(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        ACTION_TRANSFER_OWNERSHIP,
+        new_owner,
+    );
+
+    // the proposer's approval alone doesn't reach the threshold
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    assert_user_error(&builder, ERROR_THRESHOLD_NOT_REACHED);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    builder.expect_success();
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    builder.expect_success();
+
+    let owner: Key = builder.get_value(csprusd_token, OWNER);
+    assert_eq!(owner, new_owner);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    assert_user_error(&builder, ERROR_PROPOSAL_ALREADY_EXECUTED);
+}
+
+#[test]
+fn should_not_execute_revoked_or_expired_proposals() {
+    let (mut builder, csprusd_token) = setup_multisig();
+    let new_master_minter = Key::Account(*ACCOUNT_2_ADDR);
+
+    propose(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        ACTION_UPDATE_MASTER_MINTER,
+        new_master_minter,
+    );
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    builder.expect_success();
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_REVOKE_APPROVAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    builder.expect_success();
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    assert_user_error(&builder, ERROR_THRESHOLD_NOT_REACHED);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        EXPIRES_AT,
+    );
+    assert_user_error(&builder, ERROR_PROPOSAL_EXPIRED);
+
+    let master_minter: Key = builder.get_value(csprusd_token, MASTER_MINTER);
+    assert_eq!(master_minter, Key::Account(*ACCOUNT_1_ADDR));
+}
+
+#[test]
+fn should_not_configure_unreachable_threshold() {
+    let (mut builder, test_context) = setup();
+
+    call_at(
+        &mut builder,
+        test_context.csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_CONFIGURE_MULTISIG,
+        runtime_args! {
+            ARG_SIGNERS => vec![Key::Account(*ACCOUNT_1_ADDR)],
+            ARG_THRESHOLD => 2u32,
+        },
+        0,
+    );
+    assert_user_error(&builder, ERROR_INVALID_MULTISIG_CONFIG);
+}
+
+#[test]
+fn should_require_proposals_once_multisig_is_configured() {
+    let (mut builder, csprusd_token) = setup_multisig();
+    let new_master_minter = Key::Account(*ACCOUNT_2_ADDR);
+
+    // the owner key alone can neither act directly nor queue an action
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        UPDATE_MASTER_MINTER_ENTRY_POINT_NAME,
+        runtime_args! {NEW => new_master_minter},
+        0,
+    );
+    assert_user_error(&builder, ERROR_MULTISIG_REQUIRED);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_QUEUE_ACTION,
+        runtime_args! {
+            ARG_ACTION => ACTION_UPDATE_MASTER_MINTER,
+            ARG_DATA => Bytes::from(new_master_minter.to_bytes().unwrap()),
+            ARG_ETA => 1_000u64,
+        },
+        0,
+    );
+    assert_user_error(&builder, ERROR_MULTISIG_REQUIRED);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_CONFIGURE_MULTISIG,
+        runtime_args! {
+            ARG_SIGNERS => vec![Key::Account(*ACCOUNT_1_ADDR)],
+            ARG_THRESHOLD => 1u32,
+        },
+        0,
+    );
+    assert_user_error(&builder, ERROR_MULTISIG_REQUIRED);
+
+    let master_minter: Key = builder.get_value(csprusd_token, MASTER_MINTER);
+    assert_ne!(master_minter, new_master_minter);
+}
+
+/// Approves a proposal made by `ACCOUNT_2` with a second signer and executes it.
+fn approve_and_execute(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    proposal_id: u64,
+) {
+    call_at(
+        builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => proposal_id},
+        2_000,
+    );
+    builder.expect_success();
+    call_at(
+        builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => proposal_id},
+        2_000,
+    );
+    builder.expect_success();
+}
+
+#[test]
+fn should_only_cancel_multisig_queued_actions_by_proposal() {
+    let (mut builder, csprusd_token) = setup_multisig();
+    let delay: u64 = 86_400_000;
+    let new_master_minter = Key::Account(*ACCOUNT_2_ADDR);
+
+    propose_data(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        ACTION_SET_TIMELOCK_DELAY,
+        Bytes::from(delay.to_bytes().unwrap()),
+    );
+    approve_and_execute(&mut builder, csprusd_token, 1);
+
+    // with the timelock active the approved proposal is queued
+    propose(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        ACTION_UPDATE_MASTER_MINTER,
+        new_master_minter,
+    );
+    approve_and_execute(&mut builder, csprusd_token, 2);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_CANCEL_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        3_000,
+    );
+    assert_user_error(&builder, ERROR_QUEUED_BY_MULTISIG);
+
+    // a cancellation proposal takes effect without waiting for the timelock
+    propose_data(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        ACTION_CANCEL_QUEUED_ACTION,
+        Bytes::from(1u64.to_bytes().unwrap()),
+    );
+    approve_and_execute(&mut builder, csprusd_token, 3);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        2_000 + delay,
+    );
+    assert_user_error(&builder, ERROR_QUEUED_ACTION_NOT_PENDING);
+
+    let master_minter: Key = builder.get_value(csprusd_token, MASTER_MINTER);
+    assert_ne!(master_minter, new_master_minter);
+}
+
+#[test]
+fn should_disable_controller_only_through_proposal() {
+    let (mut builder, csprusd_token) = setup_multisig();
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_DISABLE_CONTROLLER,
+        runtime_args! {},
+        0,
+    );
+    assert_user_error(&builder, ERROR_MULTISIG_REQUIRED);
+    let controller_disabled: bool = builder.get_value(csprusd_token, CONTROLLER_DISABLED_KEY);
+    assert!(!controller_disabled);
+
+    propose_data(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        ACTION_DISABLE_CONTROLLER,
+        Bytes::new(),
+    );
+    approve_and_execute(&mut builder, csprusd_token, 1);
+
+    let controller_disabled: bool = builder.get_value(csprusd_token, CONTROLLER_DISABLED_KEY);
+    assert!(controller_disabled);
+}
+
+#[test]
+fn should_authorize_upgrades_only_through_proposal() {
+    let (mut builder, csprusd_token) = setup_multisig();
+    let upgrader = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_AUTHORIZE_UPGRADE,
+        runtime_args! {ARG_UPGRADER => upgrader},
+        0,
+    );
+    assert_user_error(&builder, ERROR_MULTISIG_REQUIRED);
+
+    propose(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        ACTION_AUTHORIZE_UPGRADE,
+        upgrader,
+    );
+    approve_and_execute(&mut builder, csprusd_token, 1);
+
+    let authorized: Option<Key> = builder.get_value(csprusd_token, UPGRADER_KEY);
+    assert_eq!(authorized, Some(upgrader));
+}
+
+#[test]
+fn should_discard_approvals_when_signers_are_reconfigured() {
+    let (mut builder, csprusd_token) = setup_multisig();
+    let new_owner = Key::Account(*ACCOUNT_2_ADDR);
+
+    propose(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        ACTION_TRANSFER_OWNERSHIP,
+        new_owner,
+    );
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_APPROVE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    builder.expect_success();
+
+    // reconfiguring, even to the same signer set, starts a new approval epoch
+    let signers = vec![
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        Key::Account(*ACCOUNT_1_ADDR),
+        Key::Account(*ACCOUNT_2_ADDR),
+    ];
+    propose_data(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        ACTION_CONFIGURE_MULTISIG,
+        Bytes::from((signers, 2u32).to_bytes().unwrap()),
+    );
+    approve_and_execute(&mut builder, csprusd_token, 2);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_2_ADDR,
+        METHOD_EXECUTE_PROPOSAL,
+        runtime_args! {ARG_PROPOSAL_ID => 1u64},
+        2_000,
+    );
+    assert_user_error(&builder, ERROR_THRESHOLD_NOT_REACHED);
+
+    let owner: Key = builder.get_value(csprusd_token, OWNER);
+    assert_ne!(owner, new_owner);
+}
@@ -0,0 +1,109 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_LEGAL_REFERENCE, ARG_RECIPIENT,
+        ERROR_CANNOT_TRANSFER_TO_CONTRACT, ERROR_EXCEEDS_RESCUABLE_BALANCE, FROM,
+        METHOD_CONTROLLER_TRANSFER, METHOD_RESCUE_TOKENS, METHOD_TRANSFER, TO,
+    },
+    installer_request_builders::{csprusd_check_balance_of, csprusd_package_key, mint_to, setup},
+};
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_not_transfer_to_token_package() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+    let holder = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    mint_to(&mut builder, csprusd_token, holder, U256::from(100));
+
+    let transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => csprusd_package_key(&builder),
+            ARG_AMOUNT => U256::from(10),
+        },
+    )
+    .build();
+    builder.exec(transfer_request).commit();
+    assert_user_error(&builder, ERROR_CANNOT_TRANSFER_TO_CONTRACT);
+}
+
+#[test]
+fn owner_can_rescue_tokens_stranded_at_token_package() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+    let package_key = csprusd_package_key(&builder);
+    let rescued_to = Key::Account(*ACCOUNT_2_ADDR);
+
+    // transfers to the package are rejected, but a mint can still strand tokens there
+    mint_to(&mut builder, csprusd_token, package_key, U256::from(60));
+
+    let rescue_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_RESCUE_TOKENS,
+        runtime_args! {TO => rescued_to, ARG_AMOUNT => U256::from(61)},
+    )
+    .build();
+    builder.exec(rescue_request).commit();
+    assert_user_error(&builder, ERROR_EXCEEDS_RESCUABLE_BALANCE);
+
+    let rescue_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_RESCUE_TOKENS,
+        runtime_args! {TO => rescued_to, ARG_AMOUNT => U256::from(60)},
+    )
+    .build();
+    builder.exec(rescue_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, package_key),
+        U256::zero()
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, rescued_to),
+        U256::from(60)
+    );
+}
+
+#[test]
+fn should_not_controller_transfer_to_token_package() {
+    let (mut builder, test_context) = setup();
+    let csprusd_token = test_context.csprusd_token;
+    let holder = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    mint_to(&mut builder, csprusd_token, holder, U256::from(100));
+
+    // the owner is also the controller after install
+    let controller_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_CONTROLLER_TRANSFER,
+        runtime_args! {
+            FROM => holder,
+            TO => csprusd_package_key(&builder),
+            ARG_AMOUNT => U256::from(60),
+            ARG_LEGAL_REFERENCE => "stranded".to_string(),
+        },
+    )
+    .build();
+    builder.exec(controller_transfer_request).commit();
+    assert_user_error(&builder, ERROR_CANNOT_TRANSFER_TO_CONTRACT);
+}
@@ -0,0 +1,249 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{runtime_args, ApiError, ContractPackageHash, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, AMOUNT, ARG_AMOUNT, ARG_CAP, ARG_CHAIN_ID, ARG_DECIMALS, ARG_MAX_AGE,
+        ARG_ORACLE, ARG_RECIPIENT, ARG_RESERVES, ARG_SOURCE_CHAIN_ID, ARG_SOURCE_NONCE,
+        ARG_UPDATED_AT, CONFIGURE_MINTER_ENTRY_POINT_NAME, ERROR_INSUFFICIENT_RESERVES,
+        ERROR_STALE_RESERVES, METHOD_BRIDGE_MINT, METHOD_MINT, METHOD_SET_BRIDGE_MINT_CAP,
+        METHOD_SET_RESERVES, METHOD_SET_RESERVE_ORACLE, MINTER, MINTER_ALLOWED,
+        MOCK_RESERVE_ORACLE_PACKAGE_HASH, MOCK_RESERVE_ORACLE_WASM, RECIPIENT, TOKEN_DECIMALS,
+        TOKEN_OWNER_ADDRESS_1,
+    },
+    installer_request_builders::{csprusd_check_balance_of, setup, TestContext},
+};
+
+const MAX_AGE: u64 = 60_000;
+const UPDATED_AT: u64 = 1_000;
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+/// Installs the mock oracle, points the token at it and lets the default account mint.
+fn setup_with_oracle() -> (InMemoryWasmTestBuilder, TestContext, ContractPackageHash) {
+    let (mut builder, test_context) = setup();
+
+    let install_oracle_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        MOCK_RESERVE_ORACLE_WASM,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder
+        .exec(install_oracle_request)
+        .expect_success()
+        .commit();
+
+    let oracle_package = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(MOCK_RESERVE_ORACLE_PACKAGE_HASH)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have oracle package hash");
+
+    let set_oracle_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.csprusd_token,
+        METHOD_SET_RESERVE_ORACLE,
+        runtime_args! {
+            ARG_ORACLE => Some(Key::from(oracle_package)),
+            ARG_MAX_AGE => MAX_AGE,
+        },
+    )
+    .build();
+    builder.exec(set_oracle_request).expect_success().commit();
+
+    let configure_minter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.csprusd_token,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        runtime_args! {MINTER => Key::Account(*DEFAULT_ACCOUNT_ADDR), MINTER_ALLOWED => U256::MAX},
+    )
+    .build();
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    (builder, test_context, oracle_package)
+}
+
+fn set_reserves(
+    builder: &mut InMemoryWasmTestBuilder,
+    oracle_package: ContractPackageHash,
+    reserves: U256,
+    decimals: u8,
+) {
+    let set_reserves_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        oracle_package,
+        None,
+        METHOD_SET_RESERVES,
+        runtime_args! {
+            ARG_RESERVES => reserves,
+            ARG_DECIMALS => decimals,
+            ARG_UPDATED_AT => UPDATED_AT,
+        },
+    )
+    .build();
+    builder.exec(set_reserves_request).expect_success().commit();
+}
+
+fn mint_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    amount: U256,
+    block_time: u64,
+) {
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_token,
+        METHOD_MINT,
+        runtime_args! {RECIPIENT => TOKEN_OWNER_ADDRESS_1, AMOUNT => amount},
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(mint_request).commit();
+}
+
+#[test]
+fn should_not_mint_above_reserves() {
+    let (mut builder, test_context, oracle_package) = setup_with_oracle();
+    set_reserves(
+        &mut builder,
+        oracle_package,
+        U256::from(1_000),
+        TOKEN_DECIMALS,
+    );
+
+    mint_at(&mut builder, &test_context, U256::from(600), UPDATED_AT);
+    builder.expect_success();
+
+    mint_at(&mut builder, &test_context, U256::from(500), UPDATED_AT);
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RESERVES);
+
+    mint_at(&mut builder, &test_context, U256::from(400), UPDATED_AT);
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(
+            &mut builder,
+            &test_context.csprusd_token,
+            TOKEN_OWNER_ADDRESS_1
+        ),
+        U256::from(1_000)
+    );
+}
+
+#[test]
+fn should_not_mint_on_stale_reserves() {
+    let (mut builder, test_context, oracle_package) = setup_with_oracle();
+    set_reserves(
+        &mut builder,
+        oracle_package,
+        U256::from(1_000),
+        TOKEN_DECIMALS,
+    );
+
+    mint_at(
+        &mut builder,
+        &test_context,
+        U256::from(100),
+        UPDATED_AT + MAX_AGE + 1,
+    );
+    assert_user_error(&builder, ERROR_STALE_RESERVES);
+
+    mint_at(
+        &mut builder,
+        &test_context,
+        U256::from(100),
+        UPDATED_AT + MAX_AGE,
+    );
+    builder.expect_success();
+}
+
+#[test]
+fn should_scale_reserves_to_token_decimals() {
+    let (mut builder, test_context, oracle_package) = setup_with_oracle();
+    // 2 units with two decimals less than the token are worth 200 token units
+    set_reserves(
+        &mut builder,
+        oracle_package,
+        U256::from(2),
+        TOKEN_DECIMALS - 2,
+    );
+
+    mint_at(&mut builder, &test_context, U256::from(200), UPDATED_AT);
+    builder.expect_success();
+
+    mint_at(&mut builder, &test_context, U256::one(), UPDATED_AT);
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RESERVES);
+}
+
+#[test]
+fn should_not_bridge_mint_above_reserves() {
+    let (mut builder, test_context, oracle_package) = setup_with_oracle();
+    set_reserves(
+        &mut builder,
+        oracle_package,
+        U256::from(1_000),
+        TOKEN_DECIMALS,
+    );
+
+    let chain_id = 7u64;
+    let set_cap_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        test_context.csprusd_token,
+        METHOD_SET_BRIDGE_MINT_CAP,
+        runtime_args! {ARG_CHAIN_ID => chain_id, ARG_CAP => U256::MAX},
+    )
+    .build();
+    builder.exec(set_cap_request).expect_success().commit();
+
+    mint_at(&mut builder, &test_context, U256::from(600), UPDATED_AT);
+    builder.expect_success();
+
+    let bridge_mint_at = |builder: &mut InMemoryWasmTestBuilder, nonce: u64, amount: U256| {
+        let bridge_mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *ACCOUNT_1_ADDR,
+            test_context.csprusd_token,
+            METHOD_BRIDGE_MINT,
+            runtime_args! {
+                ARG_SOURCE_CHAIN_ID => chain_id,
+                ARG_SOURCE_NONCE => nonce,
+                ARG_RECIPIENT => TOKEN_OWNER_ADDRESS_1,
+                ARG_AMOUNT => amount,
+            },
+        )
+        .with_block_time(UPDATED_AT)
+        .build();
+        builder.exec(bridge_mint_request).commit();
+    };
+
+    bridge_mint_at(&mut builder, 1, U256::from(500));
+    assert_user_error(&builder, ERROR_INSUFFICIENT_RESERVES);
+
+    bridge_mint_at(&mut builder, 1, U256::from(400));
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(
+            &mut builder,
+            &test_context.csprusd_token,
+            TOKEN_OWNER_ADDRESS_1
+        ),
+        U256::from(1_000)
+    );
+}
@@ -0,0 +1,103 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, CURRENT_SNAPSHOT_ID_KEY, ERROR_NOT_SNAPSHOTTER,
+        METHOD_SNAPSHOT,
+    },
+    installer_request_builders::{
+        csprusd_check_balance_of_at, csprusd_check_total_supply_at, make_csprusd_transfer_request,
+        mint_to, setup, TestContext,
+    },
+};
+
+#[test]
+fn should_return_balances_and_total_supply_at_snapshot() {
+    let (mut builder, test_context) = setup();
+    let TestContext { csprusd_token, .. } = test_context;
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(&mut builder, csprusd_token, sender, U256::from(100));
+
+    // the owner is the snapshotter after install
+    let snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_SNAPSHOT,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(snapshot_request).expect_success().commit();
+
+    let snapshot_id: u64 = builder.get_value(csprusd_token, CURRENT_SNAPSHOT_ID_KEY);
+    assert_eq!(snapshot_id, 1);
+
+    let transfer_request =
+        make_csprusd_transfer_request(sender, &csprusd_token, recipient, U256::from(30));
+    builder.exec(transfer_request).expect_success().commit();
+    mint_to(&mut builder, csprusd_token, recipient, U256::from(5));
+
+    let snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_SNAPSHOT,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(snapshot_request).expect_success().commit();
+
+    let transfer_request =
+        make_csprusd_transfer_request(recipient, &csprusd_token, sender, U256::from(35));
+    builder.exec(transfer_request).expect_success().commit();
+
+    assert_eq!(
+        csprusd_check_balance_of_at(&mut builder, &test_context, sender, 1),
+        U256::from(100)
+    );
+    assert_eq!(
+        csprusd_check_balance_of_at(&mut builder, &test_context, recipient, 1),
+        U256::zero()
+    );
+    assert_eq!(
+        csprusd_check_balance_of_at(&mut builder, &test_context, sender, 2),
+        U256::from(70)
+    );
+    assert_eq!(
+        csprusd_check_balance_of_at(&mut builder, &test_context, recipient, 2),
+        U256::from(35)
+    );
+    assert_eq!(
+        csprusd_check_total_supply_at(&mut builder, &test_context, 1),
+        U256::from(100)
+    );
+    assert_eq!(
+        csprusd_check_total_supply_at(&mut builder, &test_context, 2),
+        U256::from(105)
+    );
+}
+
+#[test]
+fn should_not_snapshot_if_not_snapshotter() {
+    let (mut builder, TestContext { csprusd_token, .. }) = setup();
+
+    let snapshot_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_2_ADDR,
+        csprusd_token,
+        METHOD_SNAPSHOT,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(snapshot_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == ERROR_NOT_SNAPSHOTTER),
+        "{:?}",
+        error
+    );
+}
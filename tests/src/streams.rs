@@ -0,0 +1,158 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
+use casper_types::{account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_DEPOSIT, ARG_RATE_PER_SECOND, ARG_RECIPIENT, ARG_START,
+        ARG_STOP, ARG_STREAM_ID, CUSTODY_LOCKED_KEY, ERROR_EXCEEDS_STREAM_BALANCE,
+        ERROR_INVALID_STREAM, ERROR_STREAM_NOT_ACTIVE, METHOD_CANCEL_STREAM, METHOD_CREATE_STREAM,
+        METHOD_WITHDRAW_FROM_STREAM,
+    },
+    installer_request_builders::{csprusd_check_balance_of, mint_to, setup, TestContext},
+};
+
+const START: u64 = 10_000;
+const STOP: u64 = 20_000;
+
+fn make_create_stream_request(
+    test_context: &TestContext,
+    rate_per_second: U256,
+    deposit: U256,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_token,
+        METHOD_CREATE_STREAM,
+        runtime_args! {
+            ARG_RECIPIENT => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_RATE_PER_SECOND => rate_per_second,
+            ARG_DEPOSIT => deposit,
+            ARG_START => START,
+            ARG_STOP => STOP,
+        },
+    )
+    .with_block_time(1_000)
+    .build()
+}
+
+fn call_stream_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    caller: AccountHash,
+    method: &str,
+    args: RuntimeArgs,
+    block_time: u64,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        test_context.csprusd_token,
+        method,
+        args,
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(request).commit();
+}
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected_error: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_stream_and_split_balance_on_cancel() {
+    let (mut builder, test_context) = setup();
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        sender,
+        U256::from(100),
+    );
+    // 10 tokens per second over 10 seconds
+    let create_request = make_create_stream_request(&test_context, U256::from(10), U256::from(100));
+    builder.exec(create_request).expect_success().commit();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, sender),
+        U256::zero()
+    );
+
+    // halfway through 50 tokens were streamed
+    call_stream_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {ARG_STREAM_ID => 1u64, ARG_AMOUNT => U256::from(30)},
+        15_000,
+    );
+    builder.expect_success();
+
+    call_stream_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {ARG_STREAM_ID => 1u64, ARG_AMOUNT => U256::from(30)},
+        15_000,
+    );
+    assert_user_error(&builder, ERROR_EXCEEDS_STREAM_BALANCE);
+
+    call_stream_at(
+        &mut builder,
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_CANCEL_STREAM,
+        runtime_args! {ARG_STREAM_ID => 1u64},
+        17_000,
+    );
+    builder.expect_success();
+
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, recipient),
+        U256::from(70)
+    );
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, sender),
+        U256::from(30)
+    );
+    let custody_locked: U256 = builder.get_value(test_context.csprusd_token, CUSTODY_LOCKED_KEY);
+    assert_eq!(custody_locked, U256::zero());
+
+    call_stream_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_WITHDRAW_FROM_STREAM,
+        runtime_args! {ARG_STREAM_ID => 1u64, ARG_AMOUNT => U256::one()},
+        18_000,
+    );
+    assert_user_error(&builder, ERROR_STREAM_NOT_ACTIVE);
+}
+
+#[test]
+fn should_not_create_stream_with_mismatched_deposit() {
+    let (mut builder, test_context) = setup();
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        U256::from(100),
+    );
+    let create_request = make_create_stream_request(&test_context, U256::from(10), U256::from(99));
+    builder.exec(create_request).commit();
+    assert_user_error(&builder, ERROR_INVALID_STREAM);
+}
@@ -0,0 +1,289 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::Error as CoreError, execution::Error as ExecError,
+};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{Bytes, ToBytes},
+    runtime_args, ApiError, ContractHash, Key, RuntimeArgs, U256,
+};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ACTION_SET_FEE, ACTION_TRANSFER_OWNERSHIP,
+        ACTION_UPDATE_MASTER_MINTER, ARG_ACTION, ARG_ACTION_ID, ARG_DATA, ARG_DELAY, ARG_ETA,
+        ERROR_INVALID_ETA, ERROR_INVALID_FEE_BPS, ERROR_INVALID_TIMELOCK_DELAY,
+        ERROR_QUEUED_ACTION_EXPIRED, ERROR_QUEUED_ACTION_NOT_PENDING,
+        ERROR_QUEUED_ACTION_NOT_READY, ERROR_TIMELOCK_REQUIRED, IS_PAUSED, MASTER_MINTER,
+        METHOD_CANCEL_QUEUED_ACTION, METHOD_EXECUTE_QUEUED_ACTION, METHOD_PAUSE,
+        METHOD_QUEUE_ACTION, METHOD_SET_TIMELOCK_DELAY, METHOD_TRANSFER_OWNERSHIP, NEW, OWNER,
+        TIMELOCK_DELAY_KEY,
+    },
+    installer_request_builders::setup,
+};
+
+const DELAY: u64 = 86_400_000;
+const GRACE_PERIOD: u64 = 14 * DELAY;
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected),
+        "{:?}",
+        error
+    );
+}
+
+fn call_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    caller: AccountHash,
+    method: &str,
+    args: RuntimeArgs,
+    block_time: u64,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(caller, csprusd_token, method, args)
+        .with_block_time(block_time)
+        .build();
+    builder.exec(request).commit();
+}
+
+/// Installs the token with a `DELAY` timelock and returns the token hash.
+fn setup_timelock() -> (InMemoryWasmTestBuilder, ContractHash) {
+    let (mut builder, test_context) = setup();
+    call_at(
+        &mut builder,
+        test_context.csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_TIMELOCK_DELAY,
+        runtime_args! {ARG_DELAY => DELAY},
+        0,
+    );
+    builder.expect_success();
+
+    let delay: u64 = builder.get_value(test_context.csprusd_token, TIMELOCK_DELAY_KEY);
+    assert_eq!(delay, DELAY);
+    (builder, test_context.csprusd_token)
+}
+
+fn queue_action(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    action: u8,
+    new: Key,
+    eta: u64,
+) {
+    call_at(
+        builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_QUEUE_ACTION,
+        runtime_args! {
+            ARG_ACTION => action,
+            ARG_DATA => Bytes::from(new.to_bytes().unwrap()),
+            ARG_ETA => eta,
+        },
+        1_000,
+    );
+}
+
+#[test]
+fn should_require_timelock_for_owner_actions_but_not_pausing() {
+    let (mut builder, csprusd_token) = setup_timelock();
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_TRANSFER_OWNERSHIP,
+        runtime_args! {NEW => Key::Account(*ACCOUNT_2_ADDR)},
+        1_000,
+    );
+    assert_user_error(&builder, ERROR_TIMELOCK_REQUIRED);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_PAUSE,
+        runtime_args! {},
+        1_000,
+    );
+    builder.expect_success();
+    let is_paused: bool = builder.get_value(csprusd_token, IS_PAUSED);
+    assert!(is_paused);
+}
+
+#[test]
+fn should_execute_queued_action_after_eta() {
+    let (mut builder, csprusd_token) = setup_timelock();
+    let new_owner = Key::Account(*ACCOUNT_2_ADDR);
+
+    // eta must leave at least the delay
+    queue_action(
+        &mut builder,
+        csprusd_token,
+        ACTION_TRANSFER_OWNERSHIP,
+        new_owner,
+        DELAY,
+    );
+    assert_user_error(&builder, ERROR_INVALID_ETA);
+
+    let eta = 1_000 + DELAY;
+    queue_action(
+        &mut builder,
+        csprusd_token,
+        ACTION_TRANSFER_OWNERSHIP,
+        new_owner,
+        eta,
+    );
+    builder.expect_success();
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        eta - 1,
+    );
+    assert_user_error(&builder, ERROR_QUEUED_ACTION_NOT_READY);
+
+    // anyone can execute once the eta has passed
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        eta,
+    );
+    builder.expect_success();
+    let owner: Key = builder.get_value(csprusd_token, OWNER);
+    assert_eq!(owner, new_owner);
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        eta,
+    );
+    assert_user_error(&builder, ERROR_QUEUED_ACTION_NOT_PENDING);
+}
+
+#[test]
+fn should_not_execute_cancelled_action() {
+    let (mut builder, csprusd_token) = setup_timelock();
+    let eta = 1_000 + DELAY;
+
+    queue_action(
+        &mut builder,
+        csprusd_token,
+        ACTION_UPDATE_MASTER_MINTER,
+        Key::Account(*ACCOUNT_2_ADDR),
+        eta,
+    );
+    builder.expect_success();
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_CANCEL_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        2_000,
+    );
+    builder.expect_success();
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        eta,
+    );
+    assert_user_error(&builder, ERROR_QUEUED_ACTION_NOT_PENDING);
+
+    let master_minter: Key = builder.get_value(csprusd_token, MASTER_MINTER);
+    assert_ne!(master_minter, Key::Account(*ACCOUNT_2_ADDR));
+}
+
+#[test]
+fn should_not_execute_action_after_grace_period() {
+    let (mut builder, csprusd_token) = setup_timelock();
+    let eta = 1_000 + DELAY;
+
+    queue_action(
+        &mut builder,
+        csprusd_token,
+        ACTION_UPDATE_MASTER_MINTER,
+        Key::Account(*ACCOUNT_2_ADDR),
+        eta,
+    );
+    builder.expect_success();
+
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        eta + GRACE_PERIOD + 1,
+    );
+    assert_user_error(&builder, ERROR_QUEUED_ACTION_EXPIRED);
+
+    let master_minter: Key = builder.get_value(csprusd_token, MASTER_MINTER);
+    assert_ne!(master_minter, Key::Account(*ACCOUNT_2_ADDR));
+
+    // the last moment of the grace period still counts
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *DEFAULT_ACCOUNT_ADDR,
+        METHOD_EXECUTE_QUEUED_ACTION,
+        runtime_args! {ARG_ACTION_ID => 1u64},
+        eta + GRACE_PERIOD,
+    );
+    builder.expect_success();
+    let master_minter: Key = builder.get_value(csprusd_token, MASTER_MINTER);
+    assert_eq!(master_minter, Key::Account(*ACCOUNT_2_ADDR));
+}
+
+#[test]
+fn should_reject_too_long_timelock_delay() {
+    let (mut builder, test_context) = setup();
+    call_at(
+        &mut builder,
+        test_context.csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_SET_TIMELOCK_DELAY,
+        runtime_args! {ARG_DELAY => 31 * DELAY},
+        0,
+    );
+    assert_user_error(&builder, ERROR_INVALID_TIMELOCK_DELAY);
+}
+
+#[test]
+fn should_reject_invalid_action_when_queued() {
+    let (mut builder, csprusd_token) = setup_timelock();
+
+    // fee above 100% must not sit in the queue until its eta
+    call_at(
+        &mut builder,
+        csprusd_token,
+        *ACCOUNT_1_ADDR,
+        METHOD_QUEUE_ACTION,
+        runtime_args! {
+            ARG_ACTION => ACTION_SET_FEE,
+            ARG_DATA => Bytes::from((10_001u32, U256::from(1_000u64)).to_bytes().unwrap()),
+            ARG_ETA => 1_000 + DELAY,
+        },
+        1_000,
+    );
+    assert_user_error(&builder, ERROR_INVALID_FEE_BPS);
+}
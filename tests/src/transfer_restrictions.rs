@@ -0,0 +1,167 @@
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_FEE_BPS, ARG_MAX_FEE, BLACKLIST, KEY, METHOD_PAUSE,
+        METHOD_SET_FEE,
+    },
+    installer_request_builders::{
+        csprusd_check_balance_of, csprusd_check_restriction_message,
+        csprusd_check_transfer_restriction, csprusd_package_key, make_csprusd_transfer_request,
+        mint_to, setup, TestContext,
+    },
+};
+
+#[test]
+fn should_detect_transfer_restrictions() {
+    let (mut builder, test_context) = setup();
+    let TestContext { csprusd_token, .. } = test_context;
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(&mut builder, csprusd_token, sender, U256::from(100));
+
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        recipient,
+        U256::from(100),
+    );
+    assert_eq!(code, 0);
+
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        sender,
+        U256::from(100),
+    );
+    assert_eq!(code, 4);
+
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        recipient,
+        U256::zero(),
+    );
+    assert_eq!(code, 5);
+
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        recipient,
+        U256::from(101),
+    );
+    assert_eq!(code, 6);
+
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        csprusd_package_key(&builder),
+        U256::from(100),
+    );
+    assert_eq!(code, 7);
+
+    let blacklist_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        BLACKLIST,
+        runtime_args! {KEY => recipient},
+    )
+    .build();
+    builder.exec(blacklist_request).expect_success().commit();
+
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        recipient,
+        U256::from(100),
+    );
+    assert_eq!(code, 3);
+
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_PAUSE,
+        runtime_args! {},
+    )
+    .build();
+    builder.exec(pause_request).expect_success().commit();
+
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        recipient,
+        U256::from(100),
+    );
+    assert_eq!(code, 1);
+}
+
+#[test]
+fn should_agree_with_transfer_when_a_fee_is_charged() {
+    let (mut builder, test_context) = setup();
+    let TestContext { csprusd_token, .. } = test_context;
+
+    let sender = Key::Account(*DEFAULT_ACCOUNT_ADDR);
+    let recipient = Key::Account(*ACCOUNT_2_ADDR);
+    mint_to(&mut builder, csprusd_token, sender, U256::from(1_000));
+
+    let set_fee_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        METHOD_SET_FEE,
+        runtime_args! {ARG_FEE_BPS => 100u32, ARG_MAX_FEE => U256::zero()},
+    )
+    .build();
+    builder.exec(set_fee_request).expect_success().commit();
+
+    // the fee comes out of the amount, so the whole balance can still be sent
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        recipient,
+        U256::from(1_001),
+    );
+    assert_eq!(code, 6);
+    let code = csprusd_check_transfer_restriction(
+        &mut builder,
+        &test_context,
+        sender,
+        recipient,
+        U256::from(1_000),
+    );
+    assert_eq!(code, 0);
+
+    let transfer_request =
+        make_csprusd_transfer_request(sender, &csprusd_token, recipient, U256::from(1_000));
+    builder.exec(transfer_request).expect_success().commit();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &csprusd_token, sender),
+        U256::zero()
+    );
+}
+
+#[test]
+fn should_describe_transfer_restrictions() {
+    let (mut builder, test_context) = setup();
+
+    let message = csprusd_check_restriction_message(&mut builder, &test_context, 0);
+    assert_eq!(message, "SUCCESS");
+
+    let message = csprusd_check_restriction_message(&mut builder, &test_context, 6);
+    assert_eq!(message, "Sender has insufficient balance");
+
+    let message = csprusd_check_restriction_message(&mut builder, &test_context, 7);
+    assert_eq!(message, "Recipient is the token contract");
+
+    let message = csprusd_check_restriction_message(&mut builder, &test_context, 200);
+    assert_eq!(message, "UNKNOWN");
+}
@@ -49,6 +49,8 @@ pub const METHOD_TRANSFER_FROM: &str = "transfer_from";
 pub const CHECK_TOTAL_SUPPLY_ENTRYPOINT: &str = "check_total_supply";
 pub const CHECK_BALANCE_OF_ENTRYPOINT: &str = "check_balance_of";
 pub const CHECK_ALLOWANCE_OF_ENTRYPOINT: &str = "check_allowance_of";
+pub const CHECK_TRANSFER_RESTRICTION_ENTRYPOINT: &str = "check_transfer_restriction";
+pub const CHECK_RESTRICTION_MESSAGE_ENTRYPOINT: &str = "check_restriction_message";
 pub const ARG_TOKEN_CONTRACT: &str = "token_contract";
 pub const KEY: &str = "key";
 pub const ADDRESS: &str = "address";
@@ -113,3 +115,211 @@ pub const MINTER: &str = "minter";
 pub const SPENDER: &str = "spender";
 pub const MINTER_ALLOWED: &str = "minter_allowed";
 pub const NEW: &str = "new";
+pub const FROM: &str = "from";
+pub const TO: &str = "to";
+pub const CODE: &str = "code";
+pub const METHOD_TRANSFER_WITH_MEMO: &str = "transfer_with_memo";
+pub const METHOD_TRANSFER_FROM_WITH_MEMO: &str = "transfer_from_with_memo";
+pub const METHOD_SET_MAX_MEMO_LENGTH: &str = "set_max_memo_length";
+pub const ARG_MEMO: &str = "memo";
+pub const MAX_MEMO_LENGTH_KEY: &str = "max_memo_length";
+pub const ERROR_MEMO_TOO_LONG: u16 = 65020;
+pub const METHOD_SET_FEE: &str = "set_fee";
+pub const METHOD_SET_FEE_RECIPIENT: &str = "set_fee_recipient";
+pub const METHOD_SET_FEE_EXEMPT: &str = "set_fee_exempt";
+pub const ARG_FEE_BPS: &str = "fee_bps";
+pub const ARG_MAX_FEE: &str = "max_fee";
+pub const ARG_EXEMPT: &str = "exempt";
+pub const FEE_BPS_KEY: &str = "fee_bps";
+pub const FEE_RECIPIENT_KEY: &str = "fee_recipient";
+pub const ERROR_INVALID_FEE_BPS: u16 = 65021;
+pub const CHECK_BALANCE_OF_AT_ENTRYPOINT: &str = "check_balance_of_at";
+pub const CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT: &str = "check_total_supply_at";
+pub const METHOD_SNAPSHOT: &str = "snapshot";
+pub const METHOD_UPDATE_SNAPSHOTTER: &str = "update_snapshotter";
+pub const ARG_SNAPSHOT_ID: &str = "snapshot_id";
+pub const CURRENT_SNAPSHOT_ID_KEY: &str = "current_snapshot_id";
+pub const ERROR_NOT_SNAPSHOTTER: u16 = 65022;
+pub const CHECK_PAST_BALANCE_ENTRYPOINT: &str = "check_past_balance";
+pub const CHECK_PAST_TOTAL_SUPPLY_ENTRYPOINT: &str = "check_past_total_supply";
+pub const ARG_TIMESTAMP: &str = "timestamp";
+pub const METHOD_FLASH_LOAN: &str = "flash_loan";
+pub const METHOD_SET_FLASH_LOAN_CONFIG: &str = "set_flash_loan_config";
+pub const ARG_RECEIVER: &str = "receiver";
+pub const ARG_DATA: &str = "data";
+pub const ARG_FLASH_FEE_BPS: &str = "flash_fee_bps";
+pub const ARG_MAX_FLASH_LOAN: &str = "max_flash_loan";
+pub const ERROR_EXCEEDS_MAX_FLASH_LOAN: u16 = 65024;
+pub const ERROR_FLASH_LOAN_NOT_REPAID: u16 = 65025;
+pub const METHOD_CREATE_ESCROW: &str = "create_escrow";
+pub const METHOD_RELEASE_ESCROW: &str = "release_escrow";
+pub const METHOD_REFUND_ESCROW: &str = "refund_escrow";
+pub const METHOD_RESOLVE_ESCROW: &str = "resolve_escrow";
+pub const ARG_RELEASE_TIME: &str = "release_time";
+pub const ARG_ARBITER: &str = "arbiter";
+pub const ARG_ESCROW_ID: &str = "escrow_id";
+pub const ARG_TO_RECIPIENT: &str = "to_recipient";
+pub const CUSTODY_LOCKED_KEY: &str = "custody_locked";
+pub const ESCROW_COUNT_KEY: &str = "escrow_count";
+pub const ERROR_ESCROW_NOT_PENDING: u16 = 65027;
+pub const ERROR_NOT_ESCROW_PARTY: u16 = 65028;
+pub const ERROR_ESCROW_NOT_EXPIRED: u16 = 65029;
+pub const ERROR_NOT_ARBITER: u16 = 65030;
+pub const METHOD_CREATE_VESTING_SCHEDULE: &str = "create_vesting_schedule";
+pub const METHOD_RELEASE_VESTED: &str = "release_vested";
+pub const METHOD_REVOKE_VESTING: &str = "revoke_vesting";
+pub const ARG_BENEFICIARY: &str = "beneficiary";
+pub const ARG_START: &str = "start";
+pub const ARG_CLIFF: &str = "cliff";
+pub const ARG_DURATION: &str = "duration";
+pub const ARG_REVOCABLE: &str = "revocable";
+pub const ARG_SCHEDULE_ID: &str = "schedule_id";
+pub const VESTING_SCHEDULE_COUNT_KEY: &str = "vesting_schedule_count";
+pub const ERROR_NOT_VESTING_ADMIN: u16 = 65031;
+pub const ERROR_NOTHING_TO_RELEASE: u16 = 65034;
+pub const ERROR_VESTING_NOT_REVOCABLE: u16 = 65037;
+pub const METHOD_CREATE_STREAM: &str = "create_stream";
+pub const METHOD_WITHDRAW_FROM_STREAM: &str = "withdraw_from_stream";
+pub const METHOD_CANCEL_STREAM: &str = "cancel_stream";
+pub const ARG_RATE_PER_SECOND: &str = "rate_per_second";
+pub const ARG_DEPOSIT: &str = "deposit";
+pub const ARG_STOP: &str = "stop";
+pub const ARG_STREAM_ID: &str = "stream_id";
+pub const ERROR_INVALID_STREAM: u16 = 65038;
+pub const ERROR_STREAM_NOT_ACTIVE: u16 = 65040;
+pub const ERROR_EXCEEDS_STREAM_BALANCE: u16 = 65042;
+pub const METHOD_APPROVE_WITH_EXPIRY: &str = "approve_with_expiry";
+pub const ARG_EXPIRES_AT: &str = "expires_at";
+pub const ERROR_INVALID_EXPIRY: u16 = 65043;
+pub const METHOD_APPROVE_IF: &str = "approve_if";
+pub const ARG_EXPECTED_CURRENT: &str = "expected_current";
+pub const ARG_NEW_AMOUNT: &str = "new_amount";
+pub const ERROR_ALLOWANCE_MISMATCH: u16 = 65044;
+pub const CHECK_HOLDERS_ENTRYPOINT: &str = "check_holders";
+pub const METHOD_HOLDER_COUNT: &str = "holder_count";
+pub const ARG_OFFSET: &str = "offset";
+pub const ARG_LIMIT: &str = "limit";
+pub const ARG_ENABLE_HOLDER_REGISTRY: &str = "enable_holder_registry";
+pub const HOLDER_COUNT_KEY: &str = "holder_count";
+pub const ERROR_HOLDER_REGISTRY_DISABLED: u16 = 65045;
+pub const CHECK_SPENDERS_OF_ENTRYPOINT: &str = "check_spenders_of";
+pub const METHOD_REVOKE_ALL_ALLOWANCES: &str = "revoke_all_allowances";
+pub const METHOD_SET_BRIDGE_MINT_CAP: &str = "set_bridge_mint_cap";
+pub const METHOD_BRIDGE_BURN: &str = "bridge_burn";
+pub const METHOD_BRIDGE_MINT: &str = "bridge_mint";
+pub const ARG_CHAIN_ID: &str = "chain_id";
+pub const ARG_CAP: &str = "cap";
+pub const ARG_DESTINATION_CHAIN_ID: &str = "destination_chain_id";
+pub const ARG_DESTINATION_ADDRESS: &str = "destination_address";
+pub const ARG_SOURCE_CHAIN_ID: &str = "source_chain_id";
+pub const ARG_SOURCE_NONCE: &str = "source_nonce";
+pub const BRIDGE_NONCE_KEY: &str = "bridge_nonce";
+pub const ERROR_NOT_BRIDGE_OPERATOR: u16 = 65046;
+pub const ERROR_BRIDGE_NONCE_CONSUMED: u16 = 65047;
+pub const ERROR_EXCEEDS_BRIDGE_MINT_CAP: u16 = 65048;
+pub const METHOD_MINT_WITH_REFERENCE: &str = "mint_with_reference";
+pub const ARG_REFERENCE: &str = "reference";
+pub const ERROR_DUPLICATE_MINT_REFERENCE: u16 = 65049;
+pub const MOCK_RESERVE_ORACLE_WASM: &str = "mock_reserve_oracle.wasm";
+pub const MOCK_RESERVE_ORACLE_PACKAGE_HASH: &str = "mock_reserve_oracle_package_hash";
+pub const METHOD_SET_RESERVES: &str = "set_reserves";
+pub const METHOD_SET_RESERVE_ORACLE: &str = "set_reserve_oracle";
+pub const ARG_RESERVES: &str = "reserves";
+pub const ARG_UPDATED_AT: &str = "updated_at";
+pub const ARG_ORACLE: &str = "oracle";
+pub const ARG_MAX_AGE: &str = "max_age";
+pub const ERROR_STALE_RESERVES: u16 = 65050;
+pub const ERROR_INSUFFICIENT_RESERVES: u16 = 65051;
+pub const METHOD_PUBLISH_ATTESTATION: &str = "publish_attestation";
+pub const METHOD_UPDATE_ATTESTOR: &str = "update_attestor";
+pub const ARG_PERIOD: &str = "period";
+pub const ARG_RESERVES_AMOUNT: &str = "reserves_amount";
+pub const ARG_REPORT_HASH: &str = "report_hash";
+pub const ARG_URI: &str = "uri";
+pub const ATTESTATIONS_KEY: &str = "attestations";
+pub const ATTESTATION_COUNT_KEY: &str = "attestation_count";
+pub const ERROR_NOT_ATTESTOR: u16 = 65052;
+pub const METHOD_CONFIGURE_MULTISIG: &str = "configure_multisig";
+pub const METHOD_PROPOSE: &str = "propose";
+pub const METHOD_APPROVE_PROPOSAL: &str = "approve_proposal";
+pub const METHOD_REVOKE_APPROVAL: &str = "revoke_approval";
+pub const METHOD_EXECUTE_PROPOSAL: &str = "execute_proposal";
+pub const ARG_SIGNERS: &str = "signers";
+pub const ARG_THRESHOLD: &str = "threshold";
+pub const ARG_ACTION: &str = "action";
+pub const ARG_PROPOSAL_ID: &str = "proposal_id";
+pub const ACTION_UPDATE_MASTER_MINTER: u8 = 2;
+pub const ACTION_TRANSFER_OWNERSHIP: u8 = 3;
+pub const ERROR_INVALID_MULTISIG_CONFIG: u16 = 65055;
+pub const ERROR_PROPOSAL_EXPIRED: u16 = 65057;
+pub const ERROR_PROPOSAL_ALREADY_EXECUTED: u16 = 65058;
+pub const ERROR_THRESHOLD_NOT_REACHED: u16 = 65061;
+pub const METHOD_SET_TIMELOCK_DELAY: &str = "set_timelock_delay";
+pub const METHOD_QUEUE_ACTION: &str = "queue_action";
+pub const METHOD_EXECUTE_QUEUED_ACTION: &str = "execute_queued_action";
+pub const METHOD_CANCEL_QUEUED_ACTION: &str = "cancel_queued_action";
+pub const ARG_DELAY: &str = "delay";
+pub const ARG_ETA: &str = "eta";
+pub const ARG_ACTION_ID: &str = "action_id";
+pub const TIMELOCK_DELAY_KEY: &str = "timelock_delay";
+pub const ERROR_TIMELOCK_REQUIRED: u16 = 65063;
+pub const ERROR_INVALID_TIMELOCK_DELAY: u16 = 65064;
+pub const ERROR_INVALID_ETA: u16 = 65065;
+pub const ERROR_QUEUED_ACTION_NOT_PENDING: u16 = 65067;
+pub const ERROR_QUEUED_ACTION_NOT_READY: u16 = 65068;
+pub const METHOD_SET_GUARDIANS: &str = "set_guardians";
+pub const METHOD_GUARDIAN_PAUSE: &str = "guardian_pause";
+pub const METHOD_GUARDIAN_REVOKE_MINTER_ALLOWANCE: &str = "guardian_revoke_minter_allowance";
+pub const METHOD_GUARDIAN_REMOVE_MINTER: &str = "guardian_remove_minter";
+pub const ARG_GUARDIANS: &str = "guardians";
+pub const GUARDIANS_KEY: &str = "guardians";
+pub const ERROR_NOT_GUARDIAN: u16 = 65069;
+pub const ERROR_INVALID_GUARDIANS: u16 = 65070;
+pub const ERROR_NOT_MINTER: u16 = 65005;
+pub const METHOD_CONTROLLER_TRANSFER: &str = "controller_transfer";
+pub const METHOD_DISABLE_CONTROLLER: &str = "disable_controller";
+pub const ARG_LEGAL_REFERENCE: &str = "legal_reference";
+pub const CONTROLLER_DISABLED_KEY: &str = "controller_disabled";
+pub const ERROR_NOT_CONTROLLER: u16 = 65071;
+pub const ERROR_CONTROLLER_DISABLED: u16 = 65072;
+pub const METHOD_RESCUE_TOKENS: &str = "rescue_tokens";
+pub const ERROR_EXCEEDS_RESCUABLE_BALANCE: u16 = 65074;
+pub const ERROR_CANNOT_TRANSFER_TO_CONTRACT: u16 = 65075;
+pub const ARG_ENABLE_CEP18_COMPATIBILITY: &str = "enable_cep18_compatibility";
+pub const CEP18_COMPATIBILITY_ENABLED_KEY: &str = "cep18_compatibility_enabled";
+pub const EVENTS_MODE_KEY: &str = "events_mode";
+pub const ERROR_INVALID_BURN_TARGET: u16 = 65076;
+pub const ARG_EVENTS_SCHEMA_VERSION: &str = "events_schema_version";
+pub const EVENTS_SCHEMA_VERSION_KEY: &str = "events_schema_version";
+pub const ERROR_UNSUPPORTED_EVENTS_SCHEMA: u16 = 65077;
+pub const ERROR_MULTISIG_REQUIRED: u16 = 65078;
+pub const ERROR_QUEUED_BY_MULTISIG: u16 = 65079;
+pub const EVENTS_KEY: &str = "__events";
+pub const EVENTS_LENGTH_KEY: &str = "__events_length";
+pub const ACTION_SET_FEE: u8 = 6;
+pub const ACTION_SET_TIMELOCK_DELAY: u8 = 15;
+pub const ACTION_CANCEL_QUEUED_ACTION: u8 = 20;
+pub const ACTION_DISABLE_CONTROLLER: u8 = 21;
+pub const ERROR_LOOKUP_NOT_IN_PAST: u16 = 65080;
+pub const ERROR_UNSUPPORTED_SECURITY_BADGE: u16 = 65081;
+pub const ERROR_UNSUPPORTED_EVENTS_MODE: u16 = 65082;
+pub const ERROR_INVALID_ENABLE_MB_FLAG: u16 = 60014;
+pub const METHOD_CHANGE_SECURITY: &str = "change_security";
+pub const ARG_TOTAL_SUPPLY: &str = "total_supply";
+pub const ARG_ENABLE_MINT_BURN: &str = "enable_mint_burn";
+pub const ARG_EVENTS_MODE: &str = "events_mode";
+pub const ARG_ADMIN_LIST: &str = "admin_list";
+pub const ARG_MINTER_LIST: &str = "minter_list";
+pub const ARG_NONE_LIST: &str = "none_list";
+pub const ERROR_UPGRADE_NOT_AUTHORIZED: u16 = 65083;
+pub const METHOD_AUTHORIZE_UPGRADE: &str = "authorize_upgrade";
+pub const ARG_UPGRADER: &str = "upgrader";
+pub const UPGRADER_KEY: &str = "upgrader";
+pub const ACTION_CONFIGURE_MULTISIG: u8 = 4;
+pub const ACTION_AUTHORIZE_UPGRADE: u8 = 22;
+pub const ERROR_QUEUED_ACTION_EXPIRED: u16 = 65084;
+pub const ERROR_FLASH_LOAN_CALLBACK_FAILED: u16 = 65085;
+pub const ERROR_ESCROW_RELEASE_TIME_NOT_IN_FUTURE: u16 = 65086;
+pub const ERROR_INVALID_ESCROW_ARBITER: u16 = 65087;
+pub const ACTION_SET_FEE_RECIPIENT: u8 = 7;
+pub const ERROR_ATTESTATION_FIELD_TOO_LONG: u16 = 65088;
@@ -4,8 +4,11 @@ use casper_engine_test_support::{
 };
 use casper_execution_engine::core::engine_state::ExecuteRequest;
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, runtime_args, system::mint, CLTyped, ContractHash,
-    ContractPackageHash, Key, RuntimeArgs, U256,
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes},
+    runtime_args,
+    system::mint,
+    CLTyped, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
 };
 
 use crate::utility::constants::{
@@ -14,12 +17,18 @@ use crate::utility::constants::{
 
 use super::constants::{
     ACCOUNT_1_ADDR, ACCOUNT_1_PUBLIC_KEY, ACCOUNT_2_ADDR, ADDRESS, ARG_AMOUNT, ARG_CURRENCY,
-    ARG_DECIMALS, ARG_MASTER_MINTER, ARG_NAME, ARG_OWNER, ARG_RECIPIENT, ARG_SPENDER, ARG_SYMBOL,
-    ARG_TOKEN_CONTRACT, BLACKLISTER, CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BALANCE_OF_ENTRYPOINT,
-    CHECK_TOTAL_SUPPLY_ENTRYPOINT, CONTRACT_HASH, CSPR_USD_CONTRACT_WASM,
-    CSPR_USD_TEST_CONTRACT_WASM, METHOD_APPROVE, METHOD_APPROVE_AS_STORED_CONTRACT,
-    METHOD_TRANSFER, METHOD_TRANSFER_AS_STORED_CONTRACT, OWNER, PAUSER, RESULT_KEY,
-    TEST_CONTRACT_PACKAGE_HASH, TOKEN_CURRENCY, TOKEN_DECIMALS, TOKEN_NAME, TOKEN_SYMBOL,
+    ARG_DECIMALS, ARG_LIMIT, ARG_MASTER_MINTER, ARG_NAME, ARG_OFFSET, ARG_OWNER, ARG_RECIPIENT,
+    ARG_SNAPSHOT_ID, ARG_SPENDER, ARG_SYMBOL, ARG_TIMESTAMP, ARG_TOKEN_CONTRACT, BLACKLISTER,
+    CHECK_ALLOWANCE_OF_ENTRYPOINT, CHECK_BALANCE_OF_AT_ENTRYPOINT, CHECK_BALANCE_OF_ENTRYPOINT,
+    CHECK_HOLDERS_ENTRYPOINT, CHECK_PAST_BALANCE_ENTRYPOINT, CHECK_PAST_TOTAL_SUPPLY_ENTRYPOINT,
+    CHECK_RESTRICTION_MESSAGE_ENTRYPOINT, CHECK_SPENDERS_OF_ENTRYPOINT,
+    CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT, CHECK_TOTAL_SUPPLY_ENTRYPOINT,
+    CHECK_TRANSFER_RESTRICTION_ENTRYPOINT, CODE, CONFIGURE_MINTER_ENTRY_POINT_NAME, CONTRACT_HASH,
+    CSPR_USD_CONTRACT_WASM, CSPR_USD_TEST_CONTRACT_WASM, EVENTS_KEY, EVENTS_LENGTH_KEY, FROM,
+    METHOD_APPROVE, METHOD_APPROVE_AS_STORED_CONTRACT, METHOD_MINT, METHOD_TRANSFER,
+    METHOD_TRANSFER_AS_STORED_CONTRACT, MINTER, MINTER_ALLOWED, OWNER, PACKAGE_HASH, PAUSER,
+    RESULT_KEY, TEST_CONTRACT_PACKAGE_HASH, TO, TOKEN_CURRENCY, TOKEN_DECIMALS, TOKEN_NAME,
+    TOKEN_SYMBOL,
 };
 
 /// Converts hash addr of Account into Hash, and Hash into Account
@@ -409,3 +418,288 @@ pub(crate) fn test_approve_for(
         csprusd_check_allowance_of(builder, owner, inverted_spender_key);
     assert_eq!(inverted_spender_allowance, U256::zero());
 }
+
+/// Configures the default account as a minter for `amount` and mints it to `recipient`.
+pub(crate) fn mint_to(
+    builder: &mut InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+    recipient: Key,
+    amount: U256,
+) {
+    let configure_minter_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *ACCOUNT_1_ADDR,
+        csprusd_token,
+        CONFIGURE_MINTER_ENTRY_POINT_NAME,
+        runtime_args! {MINTER => Key::Account(*DEFAULT_ACCOUNT_ADDR), MINTER_ALLOWED => amount},
+    )
+    .build();
+    builder
+        .exec(configure_minter_request)
+        .expect_success()
+        .commit();
+
+    let mint_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        csprusd_token,
+        METHOD_MINT,
+        runtime_args! {ARG_RECIPIENT => recipient, ARG_AMOUNT => amount},
+    )
+    .build();
+    builder.exec(mint_request).expect_success().commit();
+}
+
+/// Returns the key of the token's own package, under which it holds tokens in custody.
+pub(crate) fn csprusd_package_key(builder: &InMemoryWasmTestBuilder) -> Key {
+    let package_hash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(PACKAGE_HASH)
+        .and_then(|key| key.into_hash())
+        .map(ContractPackageHash::new)
+        .expect("should have package hash");
+    Key::from(package_hash)
+}
+
+pub(crate) fn csprusd_check_transfer_restriction(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    from: Key,
+    to: Key,
+    amount: U256,
+) -> u8 {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_TRANSFER_RESTRICTION_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            FROM => from,
+            TO => to,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+pub(crate) fn csprusd_check_restriction_message(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    code: u8,
+) -> String {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_RESTRICTION_MESSAGE_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            CODE => code,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+pub(crate) fn csprusd_check_balance_of_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    address: Key,
+    snapshot_id: u64,
+) -> U256 {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_BALANCE_OF_AT_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            ADDRESS => address,
+            ARG_SNAPSHOT_ID => snapshot_id,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+pub(crate) fn csprusd_check_total_supply_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    snapshot_id: u64,
+) -> U256 {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_TOTAL_SUPPLY_AT_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            ARG_SNAPSHOT_ID => snapshot_id,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+pub(crate) fn csprusd_check_past_balance(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    address: Key,
+    timestamp: u64,
+) -> U256 {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_PAST_BALANCE_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            ADDRESS => address,
+            ARG_TIMESTAMP => timestamp,
+        },
+    )
+    // the earliest block in which `timestamp` is in the past
+    .with_block_time(timestamp + 1)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+pub(crate) fn csprusd_check_past_total_supply(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    timestamp: u64,
+) -> U256 {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_PAST_TOTAL_SUPPLY_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            ARG_TIMESTAMP => timestamp,
+        },
+    )
+    // the earliest block in which `timestamp` is in the past
+    .with_block_time(timestamp + 1)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+pub(crate) fn csprusd_check_holders(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    offset: u32,
+    limit: u32,
+) -> Vec<Key> {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_HOLDERS_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            ARG_OFFSET => offset,
+            ARG_LIMIT => limit,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+pub(crate) fn csprusd_check_spenders_of(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    owner: Key,
+    offset: u32,
+    limit: u32,
+) -> Vec<(Key, U256)> {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_SPENDERS_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            ARG_OWNER => owner,
+            ARG_OFFSET => offset,
+            ARG_LIMIT => limit,
+        },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+/// Like `csprusd_check_spenders_of`, but evaluated at `block_time`.
+pub(crate) fn csprusd_check_spenders_of_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    owner: Key,
+    block_time: u64,
+) -> Vec<(Key, U256)> {
+    let exec_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        test_context.csprusd_test_contract_package,
+        None,
+        CHECK_SPENDERS_OF_ENTRYPOINT,
+        runtime_args! {
+            ARG_TOKEN_CONTRACT => Key::from(test_context.csprusd_token),
+            ARG_OWNER => owner,
+            ARG_OFFSET => 0u32,
+            ARG_LIMIT => 100u32,
+        },
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    get_test_result(builder, test_context.csprusd_test_contract_package)
+}
+
+/// Returns the serialized events emitted by the token so far, in order.
+pub(crate) fn csprusd_events(
+    builder: &InMemoryWasmTestBuilder,
+    csprusd_token: ContractHash,
+) -> Vec<Bytes> {
+    let events_uref = *builder
+        .query(None, csprusd_token.into(), &[])
+        .expect("must have contract")
+        .as_contract()
+        .expect("must convert contract")
+        .named_keys()
+        .get(EVENTS_KEY)
+        .expect("must have key")
+        .as_uref()
+        .expect("must convert to seed uref");
+    let events_length: u32 = builder.get_value(csprusd_token, EVENTS_LENGTH_KEY);
+
+    (0..events_length)
+        .map(|index| {
+            builder
+                .query_dictionary_item(None, events_uref, &index.to_string())
+                .expect("should have event")
+                .as_cl_value()
+                .expect("should be cl value")
+                .clone()
+                .into_t()
+                .expect("should be event bytes")
+        })
+        .collect()
+}
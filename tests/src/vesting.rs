@@ -0,0 +1,214 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{
+    engine_state::{Error as CoreError, ExecuteRequest},
+    execution::Error as ExecError,
+};
+use casper_types::{account::AccountHash, runtime_args, ApiError, Key, RuntimeArgs, U256};
+
+use crate::utility::{
+    constants::{
+        ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, ARG_AMOUNT, ARG_BENEFICIARY, ARG_CLIFF, ARG_DURATION,
+        ARG_REVOCABLE, ARG_SCHEDULE_ID, ARG_START, CUSTODY_LOCKED_KEY, ERROR_NOTHING_TO_RELEASE,
+        ERROR_NOT_VESTING_ADMIN, ERROR_VESTING_NOT_REVOCABLE, METHOD_CREATE_VESTING_SCHEDULE,
+        METHOD_RELEASE_VESTED, METHOD_REVOKE_VESTING, VESTING_SCHEDULE_COUNT_KEY,
+    },
+    installer_request_builders::{csprusd_check_balance_of, mint_to, setup, TestContext},
+};
+
+const START: u64 = 10_000;
+const CLIFF: u64 = 1_000;
+const DURATION: u64 = 4_000;
+
+fn make_create_vesting_schedule_request(
+    test_context: &TestContext,
+    creator: AccountHash,
+    total: U256,
+    revocable: bool,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        creator,
+        test_context.csprusd_token,
+        METHOD_CREATE_VESTING_SCHEDULE,
+        runtime_args! {
+            ARG_BENEFICIARY => Key::Account(*ACCOUNT_2_ADDR),
+            ARG_START => START,
+            ARG_CLIFF => CLIFF,
+            ARG_DURATION => DURATION,
+            ARG_AMOUNT => total,
+            ARG_REVOCABLE => revocable,
+        },
+    )
+    .build()
+}
+
+fn call_schedule_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    test_context: &TestContext,
+    caller: AccountHash,
+    method: &str,
+    block_time: u64,
+) {
+    let request = ExecuteRequestBuilder::contract_call_by_hash(
+        caller,
+        test_context.csprusd_token,
+        method,
+        runtime_args! {ARG_SCHEDULE_ID => 1u64},
+    )
+    .with_block_time(block_time)
+    .build();
+    builder.exec(request).commit();
+}
+
+fn assert_user_error(builder: &InMemoryWasmTestBuilder, expected_error: u16) {
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(error, CoreError::Exec(ExecError::Revert(ApiError::User(user_error))) if user_error == expected_error),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn should_release_vested_tokens_linearly_after_cliff() {
+    let (mut builder, test_context) = setup();
+    let creator = Key::Account(*ACCOUNT_1_ADDR);
+    let beneficiary = Key::Account(*ACCOUNT_2_ADDR);
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        creator,
+        U256::from(1_000),
+    );
+    let create_request = make_create_vesting_schedule_request(
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        U256::from(800),
+        false,
+    );
+    builder.exec(create_request).expect_success().commit();
+
+    let schedule_count: u64 =
+        builder.get_value(test_context.csprusd_token, VESTING_SCHEDULE_COUNT_KEY);
+    assert_eq!(schedule_count, 1);
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, creator),
+        U256::from(200)
+    );
+
+    // nothing is vested before the cliff
+    call_schedule_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_RELEASE_VESTED,
+        START + CLIFF - 1,
+    );
+    assert_user_error(&builder, ERROR_NOTHING_TO_RELEASE);
+
+    // a quarter of the duration passed at the cliff
+    call_schedule_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_RELEASE_VESTED,
+        START + CLIFF,
+    );
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, beneficiary),
+        U256::from(200)
+    );
+
+    call_schedule_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_RELEASE_VESTED,
+        START + DURATION + 1,
+    );
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, beneficiary),
+        U256::from(800)
+    );
+    let custody_locked: U256 = builder.get_value(test_context.csprusd_token, CUSTODY_LOCKED_KEY);
+    assert_eq!(custody_locked, U256::zero());
+
+    // irrevocable schedules can't be revoked
+    call_schedule_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        METHOD_REVOKE_VESTING,
+        START + DURATION + 1,
+    );
+    assert_user_error(&builder, ERROR_VESTING_NOT_REVOCABLE);
+}
+
+#[test]
+fn should_return_unvested_remainder_on_revoke() {
+    let (mut builder, test_context) = setup();
+    let creator = Key::Account(*ACCOUNT_1_ADDR);
+    let beneficiary = Key::Account(*ACCOUNT_2_ADDR);
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        creator,
+        U256::from(800),
+    );
+    let create_request =
+        make_create_vesting_schedule_request(&test_context, *ACCOUNT_1_ADDR, U256::from(800), true);
+    builder.exec(create_request).expect_success().commit();
+
+    // half of the schedule vested
+    call_schedule_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_1_ADDR,
+        METHOD_REVOKE_VESTING,
+        START + DURATION / 2,
+    );
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, creator),
+        U256::from(400)
+    );
+
+    // the vested half stays claimable after the revocation
+    call_schedule_at(
+        &mut builder,
+        &test_context,
+        *ACCOUNT_2_ADDR,
+        METHOD_RELEASE_VESTED,
+        START + DURATION,
+    );
+    builder.expect_success();
+    assert_eq!(
+        csprusd_check_balance_of(&mut builder, &test_context.csprusd_token, beneficiary),
+        U256::from(400)
+    );
+}
+
+#[test]
+fn should_only_let_vesting_admin_create_schedules() {
+    let (mut builder, test_context) = setup();
+
+    mint_to(
+        &mut builder,
+        test_context.csprusd_token,
+        Key::Account(*DEFAULT_ACCOUNT_ADDR),
+        U256::from(800),
+    );
+    let create_request = make_create_vesting_schedule_request(
+        &test_context,
+        *DEFAULT_ACCOUNT_ADDR,
+        U256::from(800),
+        true,
+    );
+    builder.exec(create_request).commit();
+    assert_user_error(&builder, ERROR_NOT_VESTING_ADMIN);
+}